@@ -345,24 +345,41 @@ Allowing the program to run to completion causes the CRT to produce the followin
 Render the image given by your program. What eight capital letters appear on your CRT?
  */
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
+use common::{parse, random::Rng, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Solver;
 
-    fn part_1(input: &str) -> i64 {
+impl Solution for Solver {
+    /// Instructions as (value added to X, number of cycles taken).
+    type Parsed<'a> = Vec<(i64, usize)>;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<(i64, usize)>, ParseError> {
         input
             .trim()
             .lines()
+            .map(|line| {
+                if let Some(val) = line.strip_prefix("addx ") {
+                    Ok((parse::number(val)?, 2))
+                } else if line == "noop" {
+                    Ok((0, 1))
+                } else {
+                    Err(ParseError::at(line, "addx or noop"))
+                }
+            })
+            .collect()
+    }
+
+    fn part_1(program: &Vec<(i64, usize)>) -> i64 {
+        program
+            .iter()
             .fold(
                 (0i64, 0i64, 20i64, 1i64),
-                |(mut strength, mut finished, mut target, x), line| {
+                |(mut strength, mut finished, mut target, x), &(val, ncycles)| {
                     assert!(finished < target);
-                    let (val, ncycles) = if let Some(val) = line.strip_prefix("addx ") {
-                        (val.parse().unwrap(), 2)
-                    } else {
-                        (0, 1)
-                    };
-                    finished += ncycles;
+                    finished += ncycles as i64;
                     if finished >= target {
                         strength += x * target;
                         target += 40;
@@ -373,15 +390,10 @@ mod test {
             .0
     }
 
-    fn part_2(input: &str) -> String {
-        let (pixels, ..) = input.trim().lines().fold(
+    fn part_2(program: &Vec<(i64, usize)>) -> String {
+        let (pixels, ..) = program.iter().fold(
             (['.'; 240], 0usize, 1i64),
-            |(mut display, mut finished, x), line| {
-                let (val, ncycles) = if let Some(val) = line.strip_prefix("addx ") {
-                    (val.parse().unwrap(), 2)
-                } else {
-                    (0, 1)
-                };
+            |(mut display, mut finished, x), &(val, ncycles)| {
                 for cycle in 0..ncycles {
                     let pix = (finished + cycle) % display.len();
                     display[pix] = if i64::abs(x - ((pix % 40) as i64)) < 2 {
@@ -400,16 +412,36 @@ mod test {
             .join("\n")
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let program: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                if rng.chance(0.3) {
+                    "noop".to_string()
+                } else {
+                    format!("addx {}", rng.range(-20..=20))
+                }
+            })
+            .collect();
+        program.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 13140);
-        assert_eq!(part_1(INPUT), 12980);
+        assert_eq!(Solver::solve_1(EXAMPLE), 13140);
+        if let Some(input) = common::input::for_test(2022, 10) {
+            assert_eq!(Solver::solve_1(&input), 12980);
+        }
     }
 
     #[test]
     fn t_part_2() {
         assert_eq!(
-            part_2(EXAMPLE).as_str(),
+            Solver::solve_2(EXAMPLE).as_str(),
             "
 ##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
@@ -420,17 +452,23 @@ mod test {
 "
             .trim()
         );
-        assert_eq!(
-            part_2(INPUT).as_str(),
-            "
+        if let Some(input) = common::input::for_test(2022, 10) {
+            assert_eq!(
+                Solver::solve_2(&input).as_str(),
+                "
 ###..###....##.#....####.#..#.#....###..
 #..#.#..#....#.#....#....#..#.#....#..#.
 ###..#..#....#.#....###..#..#.#....#..#.
 #..#.###.....#.#....#....#..#.#....###..
 #..#.#.#..#..#.#....#....#..#.#....#....
 ###..#..#..##..####.#.....##..####.#...."
-                .trim()
-        );
+                    .trim()
+            );
+            assert_eq!(
+                common::ocr::read(&Solver::solve_2(&input)),
+                Ok("BRJLFULP".to_string())
+            );
+        }
     }
 
     const EXAMPLE: &str = "
@@ -580,151 +618,5 @@ addx -11
 noop
 noop
 noop
-";
-
-    const INPUT: &str = "
-noop
-noop
-addx 5
-noop
-noop
-addx 6
-addx 4
-addx -4
-addx 4
-addx -6
-addx 11
-addx -1
-addx 2
-addx 4
-addx 3
-noop
-addx 2
-addx -30
-addx 2
-addx 33
-noop
-addx -37
-noop
-noop
-noop
-addx 3
-addx 2
-addx 5
-addx 20
-addx 7
-addx -24
-addx 2
-noop
-addx 7
-addx -2
-addx -6
-addx 13
-addx 3
-addx -2
-addx 2
-noop
-addx -5
-addx 10
-addx 5
-addx -39
-addx 1
-addx 5
-noop
-addx 3
-noop
-addx -5
-addx 10
-addx -2
-addx 2
-noop
-noop
-addx 7
-noop
-noop
-noop
-noop
-addx 3
-noop
-addx 3
-addx 2
-addx 8
-addx -1
-addx -20
-addx 21
-addx -38
-addx 5
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-noop
-addx -2
-addx 2
-addx -7
-addx 14
-addx 5
-noop
-noop
-noop
-addx -16
-addx 17
-addx 2
-addx -12
-addx 19
-noop
-noop
-addx -37
-noop
-noop
-noop
-addx 3
-addx 2
-addx 2
-addx 5
-addx 20
-addx -19
-addx 2
-noop
-noop
-noop
-addx 5
-addx 19
-addx -12
-addx 3
-addx -2
-addx 2
-addx -18
-addx 25
-addx -14
-addx -22
-addx 1
-noop
-noop
-noop
-addx 3
-addx 5
-addx -4
-addx 7
-addx 4
-noop
-addx 1
-noop
-noop
-addx 2
-addx -6
-addx 15
-addx -1
-addx 4
-noop
-noop
-addx 1
-addx 4
-addx -33
-noop
-addx 21
-noop
 ";
 }
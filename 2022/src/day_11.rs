@@ -331,84 +331,113 @@ monkey business after 10000 rounds?
 
 */
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
+use common::{
+    combinator::{
+        choice, delimited, eol, map, or, pair, parse_all, preceded, sections, separated, tag,
+        take_while, terminated, unsigned, PResult, Parser,
+    },
+    math,
+    random::Rng,
+    ParseError, Solution,
+};
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Multiply(usize),
+    Add(usize),
+    Square,
+}
 
-    #[derive(Clone, Copy)]
-    enum Operation {
-        Multiply(usize),
-        Add(usize),
-        Square,
-    }
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    op: Operation,
+    divtest: usize,
+    targets: (usize, usize),
+}
 
-    struct Monkey {
-        items: Vec<usize>,
-        op: Operation,
-        divtest: usize,
-        targets: (usize, usize),
-    }
+/// A line of a monkey's notes, such as `  Test: divisible by 23`, starting
+/// with the given label.
+fn field<'a, T>(label: &'static str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    delimited(pair(take_while(|c| c == ' '), tag(label)), value, eol())
+}
 
-    fn parse(input: &str) -> Vec<Monkey> {
-        input
-            .trim()
-            .split("\n\n")
-            .map(|mstr| {
-                let mut lines = mstr.lines().skip(1);
-                let items: Vec<usize> = lines
-                    .next()
-                    .unwrap()
-                    .trim()
-                    .trim_start_matches("Starting items: ")
-                    .split(", ")
-                    .map(|nstr| nstr.parse::<usize>().unwrap())
-                    .collect();
-                let op = {
-                    let (op, val) = lines
-                        .next()
-                        .unwrap()
-                        .trim()
-                        .trim_start_matches("Operation: new = old ")
-                        .split_whitespace()
-                        .collect_tuple()
-                        .unwrap();
-                    match op {
-                        "*" if val == "old" => Operation::Square,
-                        "*" => Operation::Multiply(val.parse().unwrap()),
-                        "+" => Operation::Add(val.parse().unwrap()),
-                        _ => panic!("Unrecognized operation"),
-                    }
+fn monkey(input: &str) -> PResult<'_, Monkey> {
+    let (_, rest) = field("Monkey ", terminated(unsigned::<usize>(), tag(":")))(input)?;
+    let (items, rest) = field("Starting items: ", separated(unsigned(), ", "))(rest)?;
+    let operation = or(
+        map(tag("* old"), |_| Operation::Square),
+        map(
+            pair(
+                choice([
+                    ("*", Operation::Multiply as fn(usize) -> Operation),
+                    ("+", Operation::Add),
+                ]),
+                preceded(tag(" "), unsigned()),
+            ),
+            |(op, n)| op(n),
+        ),
+    );
+    let (op, rest) = field("Operation: new = old ", operation)(rest)?;
+    let (divtest, rest) = field("Test: divisible by ", unsigned())(rest)?;
+    let (if_true, rest) = field("If true: throw to monkey ", unsigned())(rest)?;
+    let (if_false, rest) = field("If false: throw to monkey ", unsigned())(rest)?;
+    let monkey = Monkey {
+        items,
+        op,
+        divtest,
+        targets: (if_true, if_false),
+    };
+    Ok((monkey, rest))
+}
+
+/// Whether the worry levels stay small enough in the 20 rounds of part 1,
+/// where they are not kept in check by a modulus, to not overflow.
+fn worries_fit(monkeys: &[Monkey]) -> bool {
+    let mut monkeys = monkeys.to_vec();
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            let Monkey {
+                items,
+                op,
+                divtest,
+                targets,
+            } = monkeys[i].clone();
+            monkeys[i].items.clear();
+            for worry in items {
+                let worry = match op {
+                    Operation::Multiply(val) => worry.checked_mul(val),
+                    Operation::Add(val) => worry.checked_add(val),
+                    Operation::Square => worry.checked_mul(worry),
                 };
-                let divtest = lines
-                    .next()
-                    .unwrap()
-                    .trim()
-                    .trim_start_matches("Test: divisible by ")
-                    .parse::<usize>()
-                    .unwrap();
-                let targets: (usize, usize) = lines
-                    .take(2)
-                    .map(|ln| {
-                        ln.split_whitespace()
-                            .last()
-                            .unwrap()
-                            .parse::<usize>()
-                            .unwrap()
-                    })
-                    .collect_tuple()
-                    .unwrap();
-                Monkey {
-                    items,
-                    op,
-                    divtest,
-                    targets,
-                }
-            })
-            .collect()
+                let Some(worry) = worry.map(|w| w / 3) else {
+                    return false;
+                };
+                let target = if worry % divtest == 0 {
+                    targets.0
+                } else {
+                    targets.1
+                };
+                monkeys[target].items.push(worry);
+            }
+        }
+    }
+    true
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Monkey>;
+    type Answer1 = usize;
+    type Answer2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        parse_all(sections(monkey), input)
     }
 
-    fn part_1(input: &str) -> usize {
-        let monkeys = parse(input);
+    fn part_1(monkeys: &Vec<Monkey>) -> usize {
+        let monkeys = monkeys.clone();
         let (mut nchecks, _monkeys, _throws) = (0..20).fold(
             (
                 vec![0usize; monkeys.len()],
@@ -448,22 +477,11 @@ mod test {
         nchecks.iter().rev().take(2).product()
     }
 
-    fn part_2(input: &str) -> usize {
-        let monkeys = parse(input);
-        let lcm = monkeys.iter().map(|m| m.divtest).fold(1usize, |acc, n| {
-            let (mut min, mut max) = if acc < n { (acc, n) } else { (n, acc) };
-            let mut rem = max % min;
-            while rem != 0 {
-                // Compute GCD using Euclid algo.
-                max = rem;
-                if max < min {
-                    (min, max) = (max, min);
-                }
-                rem = max % min;
-            }
-            // min is now the gcd, compute lcm using that.
-            acc * n / min
-        });
+    fn part_2(monkeys: &Vec<Monkey>) -> Option<usize> {
+        let monkeys = monkeys.clone();
+        // Worry levels are kept below this, which must fit for them to be
+        // kept down at all.
+        let lcm = math::lcm_all(monkeys.iter().map(|m| m.divtest))?;
         let (mut nchecks, _monkeys, _throws) = (0..10000).fold(
             (
                 vec![0usize; monkeys.len()],
@@ -501,19 +519,88 @@ mod test {
             },
         );
         nchecks.sort();
-        nchecks.iter().rev().take(2).product()
+        Some(nchecks.iter().rev().take(2).product())
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // The tests are distinct primes, whose product is small enough for
+        // the worry levels of part 2 to be squared without overflowing.
+        let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        let count = size.clamp(2, primes.len());
+        let monkeys = loop {
+            rng.shuffle(&mut primes);
+            let monkeys: Vec<Monkey> = (0..count)
+                .map(|i| {
+                    let op = match rng.range(0..10) {
+                        0 => Operation::Square,
+                        1..=4 => Operation::Multiply(rng.range(2..=19)),
+                        _ => Operation::Add(rng.range(1..=8)),
+                    };
+                    let other = |rng: &mut Rng| (i + rng.range(1..count)) % count;
+                    let if_true = other(rng);
+                    let if_false = loop {
+                        let target = other(rng);
+                        if target != if_true || count == 2 {
+                            break target;
+                        }
+                    };
+                    Monkey {
+                        items: (0..rng.range(1..=size.clamp(1, 10)))
+                            .map(|_| rng.range(50..100))
+                            .collect(),
+                        op,
+                        divtest: primes[i],
+                        targets: (if_true, if_false),
+                    }
+                })
+                .collect();
+            if worries_fit(&monkeys) {
+                break monkeys;
+            }
+        };
+        let notes: Vec<String> = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, monkey)| {
+                let items: Vec<String> = monkey.items.iter().map(usize::to_string).collect();
+                let op = match monkey.op {
+                    Operation::Multiply(val) => format!("* {val}"),
+                    Operation::Add(val) => format!("+ {val}"),
+                    Operation::Square => "* old".to_string(),
+                };
+                format!(
+                    "Monkey {i}:\n  Starting items: {}\n  Operation: new = old {op}\n  \
+                     Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}",
+                    items.join(", "),
+                    monkey.divtest,
+                    monkey.targets.0,
+                    monkey.targets.1
+                )
+            })
+            .collect();
+        notes.join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 10605);
-        assert_eq!(part_1(INPUT), 113220);
+        assert_eq!(Solver::solve_1(EXAMPLE), 10605);
+        if let Some(input) = common::input::for_test(2022, 11) {
+            assert_eq!(Solver::solve_1(&input), 113220);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 2713310158);
-        assert_eq!(part_2(INPUT), 30599555965);
+        assert_eq!(Solver::solve_2(EXAMPLE), Some(2713310158));
+        if let Some(input) = common::input::for_test(2022, 11) {
+            assert_eq!(Solver::solve_2(&input), Some(30599555965));
+        }
     }
 
     const EXAMPLE: &str = "
@@ -545,62 +632,4 @@ Monkey 3:
     If true: throw to monkey 0
     If false: throw to monkey 1
 ";
-
-    const INPUT: &str = "
-Monkey 0:
-  Starting items: 98, 89, 52
-  Operation: new = old * 2
-  Test: divisible by 5
-    If true: throw to monkey 6
-    If false: throw to monkey 1
-
-Monkey 1:
-  Starting items: 57, 95, 80, 92, 57, 78
-  Operation: new = old * 13
-  Test: divisible by 2
-    If true: throw to monkey 2
-    If false: throw to monkey 6
-
-Monkey 2:
-  Starting items: 82, 74, 97, 75, 51, 92, 83
-  Operation: new = old + 5
-  Test: divisible by 19
-    If true: throw to monkey 7
-    If false: throw to monkey 5
-
-Monkey 3:
-  Starting items: 97, 88, 51, 68, 76
-  Operation: new = old + 6
-  Test: divisible by 7
-    If true: throw to monkey 0
-    If false: throw to monkey 4
-
-Monkey 4:
-  Starting items: 63
-  Operation: new = old + 1
-  Test: divisible by 17
-    If true: throw to monkey 0
-    If false: throw to monkey 1
-
-Monkey 5:
-  Starting items: 94, 91, 51, 63
-  Operation: new = old + 4
-  Test: divisible by 13
-    If true: throw to monkey 4
-    If false: throw to monkey 3
-
-Monkey 6:
-  Starting items: 61, 54, 94, 71, 74, 68, 98, 83
-  Operation: new = old + 2
-  Test: divisible by 3
-    If true: throw to monkey 2
-    If false: throw to monkey 7
-
-Monkey 7:
-  Starting items: 90, 56
-  Operation: new = old * old
-  Test: divisible by 11
-    If true: throw to monkey 3
-    If false: throw to monkey 5
-";
 }
@@ -76,120 +76,128 @@ get the best signal?
 
  */
 
-#[cfg(test)]
-mod test {
-    use std::collections::BinaryHeap;
-
-    use itertools::Itertools;
-
-    fn parse_grid(input: &str) -> (Vec<u8>, usize, usize, usize, usize) {
-        let input = input.trim();
-        let cols = input.find('\n').unwrap();
-        let rows = input.lines().count();
-        let input: String = input.lines().flat_map(|l| l.chars()).collect();
-        let mut grid = input
-            .chars()
-            .filter_map(|c| match LETTERS.find(c) {
-                Some(pos) => Some(pos as u8),
-                None => None,
-            })
-            .collect_vec();
-        let start = grid.iter().position(|h| *h == 0).unwrap();
-        let end = grid.iter().position(|h| *h == 27).unwrap();
+use common::{random::Rng, search, ParseError, Solution};
+use grid::Grid;
+
+/// Heights from 1 to 26, along with the start and end positions.
+pub struct HeightMap {
+    grid: Grid<u8>,
+    start: usize,
+    end: usize,
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = HeightMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<HeightMap, ParseError> {
         const LETTERS: &str = "SabcdefghijklmnopqrstuvwxyzE";
+        let mut grid = Grid::parse(input, "a lowercase letter, S or E", |c| {
+            LETTERS.find(c).map(|h| h as u8)
+        })?;
+        let start = grid
+            .position(|h| *h == 0)
+            .ok_or_else(|| ParseError::after(input, "the start S"))?;
+        let end = grid
+            .position(|h| *h == 27)
+            .ok_or_else(|| ParseError::after(input, "the end E"))?;
         grid[start] = 1;
         grid[end] = 26;
-        (grid, rows, cols, start, end)
+        Ok(HeightMap { grid, start, end })
     }
 
-    fn part_1(input: &str) -> usize {
-        let (grid, rows, cols, start, end) = parse_grid(input);
-        let mut distances = vec![usize::MAX; grid.len()];
-        let mut prev = vec![usize::MAX; grid.len()];
-        let mut heap = BinaryHeap::new();
-        heap.push((usize::MAX, usize::MAX, start));
-        while let Some((cost, from, pos)) = heap.pop() {
-            let dist = usize::MAX - cost;
-            if distances[pos] <= dist {
-                continue;
-            }
-            distances[pos] = dist;
-            prev[pos] = from;
-            let (x, y) = (pos % cols, pos / cols);
-            heap.extend(
-                [
-                    if x > 0 { Some(pos - 1) } else { None },
-                    if x < cols - 1 { Some(pos + 1) } else { None },
-                    if y > 0 { Some(pos - cols) } else { None },
-                    if y < rows - 1 { Some(pos + cols) } else { None },
-                ]
-                .iter()
-                .filter_map(|n| match n {
-                    Some(n) => {
-                        if *n == from || grid[*n] > 1 + grid[pos] {
-                            None
-                        } else {
-                            Some((cost - 1, pos, *n))
-                        }
-                    }
-                    None => None,
-                }),
-            );
-        }
-        return distances[end];
+    fn part_1(map: &HeightMap) -> usize {
+        let HeightMap { grid, start, end } = map;
+        search::bfs(
+            [*start],
+            |&pos| {
+                grid.neighbours_4(pos)
+                    .filter(move |n| grid[*n] <= 1 + grid[pos])
+            },
+            |pos| pos == end,
+        )
+        .expect("Cannot reach the end")
+        .cost
+    }
+
+    fn part_2(map: &HeightMap) -> usize {
+        let HeightMap { grid, end, .. } = map;
+        // Start from every square at the lowest elevation at once.
+        search::bfs(
+            (0..grid.len()).filter(|i| grid[*i] == 1),
+            |&pos| {
+                grid.neighbours_4(pos)
+                    .filter(move |n| grid[*n] <= 1 + grid[pos])
+            },
+            |pos| pos == end,
+        )
+        .expect("Cannot reach the end")
+        .cost
     }
 
-    fn part_2(input: &str) -> usize {
-        let (grid, rows, cols, _start, end) = parse_grid(input);
-        let mut distances = vec![usize::MAX; grid.len()];
-        let mut prev = vec![usize::MAX; grid.len()];
-        let mut heap = BinaryHeap::new();
-        heap.push((usize::MAX, usize::MAX, end));
-        while let Some((cost, from, pos)) = heap.pop() {
-            let dist = usize::MAX - cost;
-            if distances[pos] <= dist {
-                continue;
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let (rows, cols) = (size.max(6), 3 * size.max(6));
+        let letters = (0..rows * cols).map(|_| rng.range(b'a'..=b'z')).collect();
+        let mut grid = Grid::from_vec(letters, cols);
+        // A path from S to E that never crosses itself and climbs one
+        // letter at a time, so that E can always be reached.
+        let len = rng.range(26..=grid.len() / 2);
+        let path = loop {
+            let start = rng.range(0..grid.len());
+            let mut seen = vec![false; grid.len()];
+            seen[start] = true;
+            let mut path = vec![start];
+            while let Some(&pos) = path.last() {
+                if path.len() == len {
+                    break;
+                }
+                let next: Vec<usize> = grid.neighbours_4(pos).filter(|&n| !seen[n]).collect();
+                if next.is_empty() {
+                    path.pop();
+                } else {
+                    let next = *rng.pick(&next);
+                    seen[next] = true;
+                    path.push(next);
+                }
             }
-            distances[pos] = dist;
-            prev[pos] = from;
-            let (x, y) = (pos % cols, pos / cols);
-            heap.extend(
-                [
-                    if x > 0 { Some(pos - 1) } else { None },
-                    if x < cols - 1 { Some(pos + 1) } else { None },
-                    if y > 0 { Some(pos - cols) } else { None },
-                    if y < rows - 1 { Some(pos + cols) } else { None },
-                ]
-                .iter()
-                .filter_map(|n| match n {
-                    Some(n) => {
-                        if *n == from || grid[*n] < grid[pos] - 1 {
-                            None
-                        } else {
-                            Some((cost - 1, pos, *n))
-                        }
-                    }
-                    None => None,
-                }),
-            );
+            if path.len() == len {
+                break path;
+            }
+        };
+        let mut climbs: Vec<u8> = (0..len - 1).map(|i| u8::from(i < 25)).collect();
+        rng.shuffle(&mut climbs);
+        let mut letter = b'a';
+        for (&pos, climb) in path.iter().zip(std::iter::once(0).chain(climbs)) {
+            letter += climb;
+            grid[pos] = letter;
         }
-        let newstart = (0..grid.len())
-            .filter(|i| grid[*i] == 1)
-            .min_by_key(|i| distances[*i])
-            .unwrap();
-        return distances[newstart];
+        grid[path[0]] = b'S';
+        grid[path[len - 1]] = b'E';
+        grid.map(|&c| c as char).to_string()
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 31);
-        assert_eq!(part_1(INPUT), 504);
+        assert_eq!(Solver::solve_1(EXAMPLE), 31);
+        if let Some(input) = common::input::for_test(2022, 12) {
+            assert_eq!(Solver::solve_1(&input), 504);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 29);
-        assert_eq!(part_2(INPUT), 500);
+        assert_eq!(Solver::solve_2(EXAMPLE), 29);
+        if let Some(input) = common::input::for_test(2022, 12) {
+            assert_eq!(Solver::solve_2(&input), 500);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -198,49 +206,5 @@ abcryxxl
 accszExk
 acctuvwj
 abdefghi
-";
-
-    const INPUT: &str = "
-abaaaaaaaaccccccccccccccccccaaaaaccccaaaaaaccccccccccccccccccccccaaaaaaaaaacccccccccccccccccccccccccccccccaaaaaccccccccccccccccccccccccccccccccccccccccccaaaaaa
-abaaaaaaaacccccccccccccccccccaaaaaccccaaaacccccaaaacccccccccccccccaaaaaaaaaacccccccccccccccccccccccccccccaaaaaaccccccccccccccccccccccccccccccccccccccccccccaaaa
-abccaaaaaaccccccccccccccccccaaaaaaccccaaaaccccaaaaaccccccccccaaaaaaaaaaaaaaacccccccccccccccccccccccccccccaaaacccccccccccccccccccccccccccccaaaccccccccccccccaaaa
-abcaaaaaaaccccccccccccccccccaaaaccccccaccaccccaaaaaacccccccccaaaaaaaaaaaaaaacccccccccccccccccccccacccccccccaacccccccccccccccccccccccccccccaaaccccccccccccccaaaa
-abccaacccaccccccccccccccccccccaaacccccccccccccaaaaaaccccccccccaaaaaaaaacaaacccccccccccccccccccaaaacccccccccccccccccccccccccaacccccccaaccccaaacccccccccccccaaaaa
-abcaaaaaacccccccccccccccccccccccccccccccccccccaaaaaccccccccccaaaaaaaaaaccccaacaaccccccccccccccaaaaaacccccccccccccccccccccccaacccccccaaaacaaaaccccccccccccccaccc
-abccaaaaacccccccccccccccccccccccccccccccccccaaccaaacccccccccaaaaaaaaaaaacccaaaaccccccccccccccccaaaaacccccccccccccccaacaaaaaaacccccccaaaaaaaaacccccccccccccccccc
-abccaaaaaacccccccccccccccccccccccccccccaaacaaaccccccccccccccaaaaaaaaaaacccaaaaacccccccccccccccaaaaacccccccccccccaaaaaccaaaaaaaaccccccaaaaaalllllllcccaacccccccc
-abccaaaaaaccccccaaaaacccccccccaaaccccccaaaaaaaccccccccccccccaaacaaacaaacccaaaaaaccccccccccccccaccaaccccccccccccccaaaaacaaaaaaaaajkkkkkkkkkklllllllccccaaaaacccc
-abccaaaaacccccccaaaaacccccccccaaaaccccccaaaaaaccccccccaacaacccccaaacccccccacaaaaccccccccaaaccccccccccccccccccccccaaaaaccaaaaaaajjkkkkkkkkkkllssllllcccaaaaacccc
-abcccaaaaccccccaaaaaacccccccccaaaaccccccaaaaaaaaccccccaaaaacccccaaccccccccccaacccccccccaaaacccccccccccccccaaccccaaaaaccaaaaaacjjjjkkkkkkkkssssssslllccaaaaccccc
-abcccccccccccccaaaaaacccccccccaaaccccccaaaaaaaaacaaccccaaaaacccccccccccccccaaccccccccccaaaaccccccccccccccaaacccccccaaccaaaaaajjjjrrrrrrsssssssssslllcccaaaccccc
-abcccccccccccccaaaaaacccccccccccccccccaaaaaaaaaaaaaaacaaaaaacccccccccccaaacaacccccccccccaaaccccaaacccccaaaaaaaaccccccccaacaaajjjrrrrrrrsssssuusssslmcccaaaacccc
-abcccccccccccccccaacccccccccccccccaacaaaacaaaccaaaaaacaaaaccccccccccccccaaaaaccccccccccccccccccaaaaacccaaaaaaaaccccccccccccaajjjrrrruuurssuuuuvsqqmmcddaaaacccc
-abccccccccccccccccccccccccccccccccaaaaacccaaacccaaaaccccaaccccccccccccccaaaaaaacccccccccccccccaaaaaaccccaaaaaacccccccccccccccjjrrruuuuuuuuuuuuvvqqmmmdddccccccc
-abcccccccccccccccccccccccacccccccccaaaaaccaaacccaaaaccccccccccccccccccccaaaaaaacccccccccccccccaaaaaaccccaaaaaacccccccccaaccccjjjrrtuuuuuuuuyyvvvqqmmmddddcccccc
-abccccccccccccccccccccaaaaccccccccaaaaaacccccaacaccacccccccccccccccccccaaaaaaccccccccccccccccccaaaaaccccaaaaaaccccccccaaaccccjjjrrttuxxxuuxyyyvvqqmmmmdddcccccc
-abcccccccccaacccccccccaaaaaaccccccaaaaccccccaaaccccccccccccccccccccccccaacaaaccccccccccccccccccaacaaccccaaccaaccccaaaaaaaccccjjjrrtttxxxxxyyyyvvqqqmmmddddccccc
-abccccccccaaaacccccccccaaaacccccccccaaccccccaaacaaaccccccccccccccccccaaccccaacccccccccccccccccccccccccccccccccccccaaaaaaaaaacijjqrtttxxxxxyyyvvvqqqqmmmdddccccc
-abcccccacaaaaaccccccccaaaaaccccccccccccccaaaaaaaaaacccccccccccccccccaaaccccccccccccccccccccccccccccccccccccccccccccaaaaaaaaaciiiqqqttxxxxxyyyvvvvqqqqmmmdddcccc
-SbcccccaaaaaaaaaacccccaacaaccccccccccccccaaaaaaaaaccccccccccccccaaacaaacccccccccccccccccccccccccccccccccccccccccccccaaaaaaaciiiqqqtttxxxEzzyyyyvvvqqqmmmdddcccc
-abcccccaaaaaaaaaaccccccccccccaaccccccccccccaaaaaccccccccccccccccaaaaaaaaaacccccccaacccccccccccccaacccccccccccccccccaaaaaaccciiiqqqttxxxxyyyyyyyyvvvqqqmmmeddccc
-abcccccccaaaaaacccccccccccaaaaccccccccccaaaaaaaaacccccccaaaacccccaaaaaaaaacccccaaaaccccccccccaacaaaccccccccccccccccaaaaaaaciiiqqqtttxxyyyyyyyyyvvvvqqqnnneeeccc
-abcccccccaaaaaacccccccccccaaaaaaccccccccaaaaaaaaaaccccccaaaaccccccaaaaaaaccccccaaaaaaccccccccaaaaacccccccccccccccccaaccaaaciiiqqtttxxxxwwyyywwvvvvrrrnnnneeeccc
-abcccccccaaaaaaccccccccccccaaaaacccccccaaaaaaacaaaccccccaaaacccccaaaaaacccccccccaaaaccccccccccaaaaaaccccaaccccccccccccccaaciiqqqtttxxxwwwyywwwwvvrrrrnnneeecccc
-abccccccaaaaaaaaccccccccccaaaaaccccccccaaaaaaccccccccccccaaacccccaaaaaaacccccccaaaaaccccccccaaaaaaaaacccaaccccccccccccccccciiqqqtttttwwswwyywwrrrrrrnnnneeecccc
-abccccccccccccacccccccccccaccaaccccaaccaaaaaacccccccccccaccccccccaaacaaacccccccaacaaccccccccaaaaacaaaaaaaacccccccccaacccccciiqqqqttssssswwwwwrrrrnnnnnneeeecccc
-abcccccccccccccccccccccccccccccaaaaaaccccaacccccccaaacaaacccccccccccccaacaaacccccccccccccccccccaaaccaaaaaaaaccccaacaacccccciiiqqpppsssssswwwwrrrnnnnneeeeeccccc
-abcccccccccccccccccccccccccccccaaaaaaaccccccccccccaaaaaaaccccccccccccccccaaacccccccccccccccccccaaaccaaaaaaaaacccaaaaacccccchhhhppppppppssswwwrroonnfeeeeacccccc
-abccccccccccccccccccccaaaaaccccaaaaaaaaccccccccccccaaaaaaccccccccccccccaaaaaaaacccccccccccccccccccccaaaaaaaaaccccaaaaaaccccchhhhhpppppppsssssrroonfffeeaaaacccc
-abccccccccccccccccccccaaaaacccccaaaaaaaccccccccccccaaaaaaaaccccccccccccaaaaaaaacccccccccccccccccccccaaaaaacccccaaaaaaaacccccchhhhhhhppppsssssrooofffffaaaaacccc
-abcccccaacaaacccccccccaaaaaacccaaaaaacccccccccccccaaaaaaaaacccccccccccccaaaaacccccccccccccccccccccccaaaaaaaccccaaaaaccaccccccchhhhhhhhpppssssrooofffcaaaaaccccc
-abcccccaaaaaacccccccccaaaaaacccaaaaaaccccccccccccaaaaaaaaaacccccccccccccaaaaaaccccccccccccccccccccccaccaaaccccccacaaaccaacccccccchhhhhgppooooooofffcaaaaacccccc
-abcccccaaaaaacccccccccaaaaaaccccccaaacaacccccccccaaacaaaccccccccccaaacccaaaaaaccccccccccccccccccccccccccaaacccccccaaacaaaccccccccccchgggoooooooffffcaaaaaaccccc
-abaccccaaaaaaaccccccccccaaccccccccaaaaaacccccccccccccaaaccccccccccaaaaccaaaccacaacaacccccccccccccccccccccccccccccccaaaaaaaaccccccccccggggoooooffffccaccaaaccccc
-abacccaaaaaaaaccccccccccccccccccccaaaaaccccccccccccccaacccccccaaacaaaacccaaccccaaaaacccccccccccccccccccaacaacccccccaaaaaaaacccccccccccggggggggfffcccccccccccccc
-abacccaaaaaaaaccccccccaaacccccccccaaaaaaccccccccccccccccccccccaaacaaaacaaaaccccaaaaaaccccccccaaccccccccaaaaaccccccccaaaaaaacccccccccccaaggggggffcccccccccccccca
-abcccccccaaacccccccccaaaaaaccccccaaaaaaaacccccccccccccccccccaaaaaaaaaaaaaaaccccaaaaaaccccccacaaaacccccccaaaaacccccccaaaaaccccccccccccaaacgggggaccccccccccccccaa
-abcccccccaaccccccccccaaaaaaccccccaaaaaaaacccccccaaacccccccccaaaaaaaaaaaaaaaacccaaaaaaccccccaaaaaaccccccaaaaaaccccccaaaaaaacccccccccccaaaccccaaaccccccccccaaacaa
-abcccccccccccccccccccaaaaaccccccccccaaccccccccaaaaaccccccccccaaaaaaaaaaaaaaaaccccaaaccccccccaaaacccccccaaaaccccccccccccaaccccccccccccccccccccccccccccccccaaaaaa
-abccccccccccccccccccccaaaaacccccccccaaccccccccaaaaaacccccccccaaaaaaaaaaaaaaaacccccccccccccccaaaacccccccccaacccccccccccccccccccccccccccccccccccccccccccccccaaaaa
 ";
 }
@@ -167,133 +167,173 @@ Organize all of the packets into the correct order. What is the decoder key for
 
  */
 
-#[cfg(test)]
-mod test {
-    use std::cmp::Ordering::{self, *};
+use std::{
+    cmp::Ordering::{self, *},
+    fmt::Display,
+};
+
+use common::{parse, random::Rng, ParseError, Solution};
+use itertools::Itertools;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Packet {
+    Value(usize),
+    List(Vec<Packet>),
+}
 
-    use itertools::Itertools;
+#[derive(PartialEq, Eq)]
+enum Token {
+    Open,
+    Packet(Packet),
+}
 
-    #[derive(PartialEq, Eq, Debug)]
-    enum Packet {
-        Value(usize),
-        List(Vec<Packet>),
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
+}
 
-    #[derive(PartialEq, Eq)]
-    enum Token {
-        Open,
-        Packet(Packet),
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Value(l), Packet::Value(r)) => l.cmp(r),
+            (Packet::Value(_), Packet::List(r)) => {
+                if r.is_empty() {
+                    Greater
+                } else {
+                    match self.cmp(&r[0]) {
+                        Less => Less,
+                        Greater => Greater,
+                        Equal if r.len() > 1 => Less,
+                        _ => Equal,
+                    }
+                }
+            }
+            (Packet::List(_), Packet::Value(_)) => match other.cmp(self) {
+                Less => Greater,
+                Greater => Less,
+                Equal => Equal,
+            },
+            (Packet::List(l), Packet::List(r)) => {
+                use itertools::EitherOrBoth::*;
+                l.iter()
+                    .zip_longest(r.iter())
+                    .find_map(|pair| {
+                        match match pair {
+                            Both(l, r) => l.cmp(r),
+                            Left(_) => Greater,
+                            Right(_) => Less,
+                        } {
+                            Less => Some(Less),
+                            Greater => Some(Greater),
+                            Equal => None,
+                        }
+                    })
+                    .unwrap_or(Equal)
+            }
+        }
     }
+}
 
-    impl PartialOrd for Packet {
-        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-            return Some(self.cmp(other));
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::Value(val) => write!(f, "{val}"),
+            Packet::List(packets) => write!(f, "[{}]", packets.iter().join(",")),
         }
     }
+}
 
-    impl Ord for Packet {
-        fn cmp(&self, other: &Self) -> Ordering {
-            match (self, other) {
-                (Packet::Value(l), Packet::Value(r)) => l.cmp(&r),
-                (Packet::Value(_), Packet::List(r)) => {
-                    if r.is_empty() {
-                        Greater
-                    } else {
-                        match self.cmp(&r[0]) {
-                            Less => Less,
-                            Greater => Greater,
-                            Equal if r.len() > 1 => Less,
-                            _ => Equal,
-                        }
-                    }
+impl Packet {
+    fn parse(line: &str) -> Result<Packet, ParseError> {
+        let mut tokens: Vec<Token> = Vec::new();
+        let mut line = line.trim();
+        let full = line;
+        const SEP: [char; 3] = ['[', ']', ','];
+        while let Some(pos) = line.find(SEP) {
+            let word = &line[..pos];
+            let rest = &line[pos..];
+            if rest.starts_with("[") {
+                tokens.push(Token::Open);
+            } else if rest.starts_with(",") && !word.is_empty() {
+                tokens.push(Token::Packet(Packet::Value(parse::number(word)?)));
+            } else if rest.starts_with("]") {
+                if !word.is_empty() {
+                    tokens.push(Token::Packet(Packet::Value(parse::number(word)?)));
                 }
-                (Packet::List(_), Packet::Value(_)) => match other.cmp(self) {
-                    Less => Greater,
-                    Greater => Less,
-                    Equal => Equal,
-                },
-                (Packet::List(l), Packet::List(r)) => {
-                    use itertools::EitherOrBoth::*;
-                    l.iter()
-                        .zip_longest(r.iter())
-                        .find_map(|pair| {
-                            match match pair {
-                                Both(l, r) => l.cmp(&r),
-                                Left(_) => Greater,
-                                Right(_) => Less,
-                            } {
-                                Less => Some(Less),
-                                Greater => Some(Greater),
-                                Equal => None,
-                            }
-                        })
-                        .unwrap_or(Equal)
+                let mut packets = Vec::new();
+                loop {
+                    match tokens.pop() {
+                        Some(Token::Open) => break,
+                        Some(Token::Packet(p)) => packets.push(p),
+                        None => return Err(ParseError::at(rest, "a matching '['")),
+                    }
                 }
+                packets.reverse();
+                tokens.push(Token::Packet(Packet::List(packets)));
             }
+            line = &rest[1..];
+        }
+        if !line.is_empty() {
+            tokens.push(Token::Packet(Packet::Value(parse::number(line)?)));
+        }
+        match (tokens.pop(), tokens.is_empty()) {
+            (Some(Token::Packet(p)), true) => Ok(p),
+            (None, _) => Err(ParseError::at(full, "a packet")),
+            _ => Err(ParseError::after(full, "a closing ']'")),
         }
     }
+}
 
-    impl Packet {
-        fn parse(line: &str) -> Packet {
-            let mut tokens: Vec<Token> = Vec::new();
-            let mut line = line.trim();
-            const SEP: [char; 3] = ['[', ']', ','];
-            while let Some(pos) = line.find(SEP) {
-                let word = &line[..pos];
-                let rest = &line[pos..];
-                if rest.starts_with("[") {
-                    tokens.push(Token::Open);
-                } else if rest.starts_with(",") && !word.is_empty() {
-                    tokens.push(Token::Packet(Packet::Value(word.parse::<usize>().unwrap())));
-                } else if rest.starts_with("]") {
-                    if !word.is_empty() {
-                        tokens.push(Token::Packet(Packet::Value(word.parse::<usize>().unwrap())));
-                    }
-                    let mut packets = Vec::new();
-                    while let Some(t) = tokens.pop() {
-                        match t {
-                            Token::Open => break,
-                            Token::Packet(p) => packets.push(p),
-                        }
-                    }
-                    packets.reverse();
-                    tokens.push(Token::Packet(Packet::List(packets)));
+/// A random list of values and lists, nested no deeper than `depth`.
+fn random_packet(rng: &mut Rng, depth: usize) -> Packet {
+    Packet::List(
+        (0..rng.range(0..=4))
+            .map(|_| {
+                if depth > 0 && rng.chance(0.3) {
+                    random_packet(rng, depth - 1)
+                } else {
+                    Packet::Value(rng.range(0..=10))
                 }
-                line = &rest[1..];
-            }
-            if !line.is_empty() {
-                tokens.push(Token::Packet(Packet::Value(line.parse::<usize>().unwrap())));
-            }
-            assert_eq!(tokens.len(), 1);
-            match tokens.pop() {
-                Some(tk) => match tk {
-                    Token::Open => panic!("Invalid token"),
-                    Token::Packet(p) => p,
-                },
-                None => panic!("Empty list of tokens"),
-            }
-        }
-    }
+            })
+            .collect(),
+    )
+}
+
+pub struct Solver;
 
-    fn part_1(input: &str) -> usize {
+impl Solution for Solver {
+    /// Every packet in the order it appears, ignoring the blank lines.
+    type Parsed<'a> = Vec<Packet>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Packet>, ParseError> {
         input
             .trim()
             .lines()
             .filter_map(|line| {
                 let line = line.trim();
                 if line.is_empty() {
-                    return None;
+                    None
                 } else {
-                    return Some(Packet::parse(line));
+                    Some(Packet::parse(line))
                 }
             })
+            .collect()
+    }
+
+    fn part_1(packets: &Vec<Packet>) -> usize {
+        packets
+            .iter()
             .fold(
                 (1usize, 0usize, None),
-                |(index, total, prev): (usize, usize, Option<Packet>), current: Packet| match prev {
+                |(index, total, prev): (usize, usize, Option<&Packet>), current: &Packet| match prev
+                {
                     Some(prev) => (
                         index + 1,
                         total
-                            + match prev.cmp(&current) {
+                            + match prev.cmp(current) {
                                 Less => index,
                                 Greater => 0,
                                 Equal => panic!("Indeterminate comparison"),
@@ -306,44 +346,53 @@ mod test {
             .1
     }
 
-    fn part_2(input: &str) -> usize {
-        let mut packets = input
-            .trim()
-            .lines()
-            .filter_map(|line| {
-                let line = line.trim();
-                if line.is_empty() {
-                    None
-                } else {
-                    Some(Packet::parse(line))
-                }
-            })
-            .collect_vec();
-        packets.push(Packet::parse("[[2]]"));
-        packets.push(Packet::parse("[[6]]"));
+    fn part_2(packets: &Vec<Packet>) -> usize {
+        // The divider packets [[2]] and [[6]].
+        let divider = |n| Packet::List(vec![Packet::List(vec![Packet::Value(n)])]);
+        let mut packets = packets.clone();
+        packets.push(divider(2));
+        packets.push(divider(6));
         packets.sort();
-        (packets
-            .iter()
-            .position(|pt| *pt == Packet::parse("[[2]]"))
-            .unwrap()
-            + 1)
-            * (packets
-                .iter()
-                .position(|pt| *pt == Packet::parse("[[6]]"))
-                .unwrap()
-                + 1)
+        (packets.iter().position(|pt| *pt == divider(2)).unwrap() + 1)
+            * (packets.iter().position(|pt| *pt == divider(6)).unwrap() + 1)
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let left = random_packet(rng, 4);
+                // The packets of a pair must be in one order or the other.
+                let right = loop {
+                    let right = random_packet(rng, 4);
+                    if left.cmp(&right) != Equal {
+                        break right;
+                    }
+                };
+                format!("{left}\n{right}")
+            })
+            .collect();
+        pairs.join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 13);
-        assert_eq!(part_1(INPUT), 6076);
+        assert_eq!(Solver::solve_1(EXAMPLE), 13);
+        if let Some(input) = common::input::for_test(2022, 13) {
+            assert_eq!(Solver::solve_1(&input), 6076);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 140);
-        assert_eq!(part_2(INPUT), 24805);
+        assert_eq!(Solver::solve_2(EXAMPLE), 140);
+        if let Some(input) = common::input::for_test(2022, 13) {
+            assert_eq!(Solver::solve_2(&input), 24805);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -370,457 +419,5 @@ mod test {
 
 [1,[2,[3,[4,[5,6,7]]]],8,9]
 [1,[2,[3,[4,[5,6,0]]]],8,9]
-";
-
-    const INPUT: &str = "
-[[[5,[10,6],5,1,6],4],[[9,[7,4,7],6,[],7],[[9,6,0,10],7,[8,5,5,2,7],[7,10,5,6]]]]
-[[[[10,8,4,6],7,2,[8],0],7,8],[[4,[0],[10,0,9],[1,10],4],[[6,6,1,10],4,6,[],[9,1]],[[4],[7,6,4,10,6],1,[2,2,5,3,2]],[[10,9,5],[1,1,1]],[[3,4,3],[3,10,0,8],[0,7,8,9],2,4]]]
-
-[[],[1,[[6,9,6,9,7],[9,2,3]],[[9],6,[2,9]],[2,6,2,4]],[[[10,3,1,4],[6,5,5,5,1]],[4,[9,3,6],7,2],7,8],[],[6,6,[8],2,1]]
-[[9,[[1,9,9,4],6,9,[4,10,5],[0,9]],8],[],[10,[[2,10,3,10],[]],2,[10]],[3,[8,[],3,8]]]
-
-[[2,[[8,4,1,10,4],[0,8,3,6,4],[6,9,8,9],7,6],[6,[3,3,3,1]]]]
-[[[6],8,[[1]]],[[[0,0],2,[0,9],[6,1],[7,1,4]],[7,[9,5],[10],[],2],8],[1,8],[],[1,[[7],[10,6],[],4,8],[10,[4,9,9,5],7,[8,10,3,0,2]],0]]
-
-[[[3,8,9],[],[[4]],[]],[],[[9],5,10,3,[3,[],3,[6,8,4],8]],[],[8,7,[],0]]
-[[],[[[10],5,0,6],5,7,1,[[5,5]]],[8]]
-
-[[9,8,4,[2,4,6,[4,9]]]]
-[[[9]],[9,[[4,7,8],[4,4],[5,10,0,7,9],2]]]
-
-[[[],[8],[[9,4,1,6],[4,10,9,5],[],[5,8,2,7,3]],6,4],[[[3,0]],5],[2],[0,[],[2]]]
-[[[6],[1,[5,9,10,7,6],10],8,6],[[2],[[],[1,7],[10,2,0],[4,4]],[[8,4,10,10,9]],3,[5,[],5]],[]]
-
-[[[],5,9,2,8],[7,10,0],[2,0],[[9,[3],[],2,[0,2,0,9]],4,[]]]
-[[[[],[7,0,0]],1,9,[[2]]]]
-
-[[[],[[]],4,4,[0]],[],[[[9]]]]
-[[8,[],10],[[2],9,[2,3,[],[0],[0,3,0]],9]]
-
-[[6,6,[[],[0]],1],[9,5,8]]
-[[3,0],[10,5,8,2,[0,6,[1,8,1]]],[9,10]]
-
-[[[2,[4,2,10,4]],4,10,1,[5]],[[[4,1,5],4],6],[7]]
-[[3,[[1,2,5,7,7],[9,8,5,8,7],7],[10,9],0],[10,[],5,[6,[],[0,6,6,4,4],[6,1,1,6,0],[]],[4]],[[7,[4,10,0],9,[9]],[[0]]]]
-
-[[[6,1,[]]],[3],[]]
-[[[8,[10,8,6],8],4,[4,7,10,1]],[0,[[],8,[9],5],[[9,8,8,1]]],[[[9,5,4],[7,8,10,7,10],[]],[[],[10,10],[],8,[8]],[],[5,[3,7,6,1]]],[[[5,5],[10,7]],1,[5],[],[[4,5],2,10,[]]],[[],8,10,9,[10,6,[6,3]]]]
-
-[[[[],[7,1],[7,0,10,0,6],8],[],7,[[],2,4,4],[]]]
-[[],[4,1,[[3,5,8,3],10,0],[4,[],[0,8,4,1],[5,9],1],10],[[[2,5,4,10,6],[2,8],8],1,[[]],[0,[],2,2,2]],[],[]]
-
-[[[[],8,8,9]]]
-[[10],[[[3,6],3],[[1,6,2],[6,10,2],7]],[],[]]
-
-[[[10,6,[3,3]],[[5],9,[10],10,6],[5,0,2,[10,8,10],[8]]],[4,6,6,[10,[0,9],8,6,[0,5,5]],5],[7,[[]],3,9],[[9,1,[8,0,7,3,2],[8,1,7,4],[6,9,4,7,5]],[[5],0],1,[1,7,[]],[5]]]
-[[],[6,1],[[7,2],[5,[6,6,6],[9,1,2,5],[9,1,8],9],2,7,[[],[4,1,4,9,1],[4,4]]],[0]]
-
-[[],[10,7,1,4,2],[9,8,4,[[3,7,9,8],2,9,[6],[6,9,0]]],[[[6,5,7,7,0]],[7],4]]
-[[3,[[7,10]],9]]
-
-[[0]]
-[]
-
-[[4,2,6],[10,[],3],[2,[2,8,3,[],[9,10,5,2,7]],9,7,9],[[],[],10,[[5]]]]
-[[3,[[1],4,4,[1,7,3,2,9]],9,7,[[]]],[[4,[5,5,1,0,7]]],[4],[8,[8,9,1],[[],[10],3]]]
-
-[[7,10,3],[[5]],[]]
-[[[[6,0,6,1,6],5,[10,2,9,2,8]],[],[2,[3,5,5,0,6],10,2],1],[[]],[[[3,0],[],7,7,1],[[8,4,8,9,2],1,[10,3,5,5],5,2],5,6,[[0,8,0,2],[5]]],[3,10,[1],[[2,10,2,9,8],[6,10,4],[]],[9,[3,2],[8,8,5],9]]]
-
-[[[[2],[8,7,8,4,10]],8,1,8,10]]
-[[[1],0,[[9,3,4,0]],9,[0,3,1,7,[4,2,7,3,10]]],[6,3,[[9],1,7],1],[],[[7,5,1,1,[2,5,2]],6,4,0,[[2,7,0,8,1],[7,4,7],[3,0,1,6],[7,9,0,1,1],[9]]],[[0,5],1,2,[4,2,7]]]
-
-[[4],[[1,2,[8,10],[2,8]],10,0]]
-[[3,4],[2,[[1,8]],[10,[1,0,8],[1],[4]],[0,4,8]],[6,[[8,10,7],6,2]]]
-
-[[[1,[6,9,9],[8,6,0]],4,[6,[2,1,10],4],7,[2,[2,9],1]]]
-[[],[5,3,[8,[],9]],[8]]
-
-[[[3,[8,2,1,4,0],[9,5,7,7,8]],[[5,5,2,2,9],[10],8],1,3]]
-[[0,1,[],5],[[8,4],[10],6,7,0],[2],[[7,[3,2]],7,[],2,[0,[5,8,7,1,2],[0],1]],[[[4,4,4,10,8],0,2,10],[[9,8,6],3,[9,2,4,3,8],5,1]]]
-
-[[4,[[]]],[10,[[2,7],8]],[[[6,7],10,10,5],1,2,[[],2,10]],[[2,5,1,0,8],[[5,8,10]],6]]
-[[8,[[4,5,6,2],5,[7],8,9]],[],[[],3,[],4],[[0],5,1]]
-
-[[3],[0,[],[3,1,4,10],2,[5]],[2,6,[],[[1,5],1]],[[],7,8,[]]]
-[[[[],9,10],[[6,10,6,7]],7],[]]
-
-[[[4,[10]],9,[9,[9,8,5,9,1],[10],[3]]]]
-[[3],[2,[10],[[4,2,7,2]],[[],[8],[7,5]]],[[[5,3]],3],[[7,[9,9,5,2,3],10,5],8,6],[4,[[3,1],[10,4,6,8],[8,0,8,2,5]],[[1,6,10,2],[2,10,5],6],1,[9,2]]]
-
-[[],[[[4,2,4],8,[8,9,3]],9,[]],[],[[[3]]]]
-[[],[5,[[2,8,5,1],[0,10,6],[],2],[[1],[2,1,6],7,[1,5,1]],7],[0,9,7,5,0],[[[0,2,10,8],4,[6,6,5,4],[10,8,0]],0,4]]
-
-[[[]],[1,8,0,[[1,1,1,1],9,7,[],9]],[6],[6,[[0,4,9]],[[10,0],3,[0,10,4,6,1],[4,6,6,10,5],[]],[4,3],[8,3]],[2,5]]
-[[[6,10,7,1]],[7,[[0,6,5]],[[10,8,9]],[[]]]]
-
-[[4,[[2,7,2,9,4],[8,8,3,2,1]]]]
-[[[[],0,7,1],[[]],0,[6,[2,10,4,2]],[7,8]],[8,[[8,0],[10,0],10],1,4,[[10,9,3,8,0],7,[10,3,1,1],3]],[],[[1,10,[7],[4,8,1,2,10],4],[[],6]],[[1,[7,10,10,0],[],[],[2]],[[2,0],8,[7],[2,2,10],3]]]
-
-[[4,[[2,7],10,3,10,[1]]],[0,1,[[],[],1],[[8],2,6,[],4],[7]],[],[2,6,[[3],[1,1,4,9,0],0,[8,3,0,9],1],0],[2,8]]
-[[9,1,9,[4,[8,4]]]]
-
-[[[0,[6],6,9,[2,2,3,2,5]],[9],[7,3,[1,7]],[[0,5,10,3],[],[5],0]]]
-[[[[0],9,0,9],[[4,3,9,3],[],2,0,[3,9,5]],[3,10,5,[9,3]]],[9,[5,8,8,[]]],[[[6,5,8,5,8],[4,7,5,6,1]],9,6],[8,7]]
-
-[[5],[],[8,4,[],[[3,7,0]],3]]
-[[[[1,6,9,6,4],8,[]],10,[10,[0,3,7,2,4]]],[[6],[7,10],[[],6,[7,8]],7],[4,[5,[2,6],5],3,5]]
-
-[[],[[]],[],[10,8],[[5,9,[5],[],[]],[[6],[5,8,3],[2,8,7,10,2]]]]
-[[],[[]],[[[10,5,9]]],[8,6,[0,8],0,[4,[8],7]]]
-
-[[[],[],[],0],[5,[[],0,[10,3,6,1,8],[7]],0,[[],8,[],[9,9,6,1]],[[6,3],10]],[[[1,8,5,1],[4,1,10,2,9]]],[7],[[],[7,2,[3,1,8],10,[8,5]],2]]
-[[],[[[2],1,10,6,[0,7,7,10]],7,0,[[0,8,9,6,9],[7,10,9,4,9],9,5],[[9,10,0],[],5,[10]]],[],[[[0,7,5,4],[10,7,7,4],9]],[2]]
-
-[7,6,8,6,7]
-[7,6,8,6]
-
-[[[[]],[[],[7,5,3,9,4],[4,4,5],[5],[10,0,9,1]],[10]],[[],[[4],0,1,10,[]],[[],3,0,[2,7,8,10,3],10],0,7],[[],[5],[],[[],8,3,2],[[4,7]]],[]]
-[[[[4,3,6,1]],3,[3,5,[6],8,3],[[4],[]]]]
-
-[[[],[8,[2,7],10,9,6],7],[[],[[2,4,1,4,3],[6,0,8,1],[7,3,8,1],[0,6,5],3],9,[[8,0,2,9,1]],2],[5,1,8,3,[[2,9,5,3],9]]]
-[[0,[[10,6,0],10],[],4,5]]
-
-[[9,[9,[7,7,4]]]]
-[[],[[]],[0,7,[[10],[3],[2,6,1,4]]]]
-
-[[[[4,6,1],8,4,3],7,[[10,5,0]],0],[7,[],10,[9,[3],[7,3,3],[8,10,9,6],2]],[1,5]]
-[[[5,[9,2],[6],10,[5,8,6,8,3]],[[2,0,8]],6,[2,4,[10],1],[1,[6,9,10,2],0,[4,9],[9,6,2]]],[[[3,4,3,5],[4,3,7,8],[5,5,1,10],4],[6,[],[9,9],[8,2,5],1],[[],[2],[5,0,9],7,0],[7,9],7],[1,5]]
-
-[[7,[[3],[0,2,5,10],9,10,4],1,7,6]]
-[[1,[8],[6,3,3,[6,3,1],[9,5]],10,[9,[3,3],3,0]],[[8,3],7,5,[9,4]],[10,[2,5,9],10,[4,3,[1],[8,8],0]],[],[]]
-
-[[[[1,1,3,9]],[[6,10],2],[[8,0,7,3],[0,8,4,8],0]]]
-[[],[9,[[10,8],0,[1,5,5],10,4],[0,2,9,[0,4,6]]],[[[9,0],[10,0,9],[9],[10,9,10,4,2],[1]],3,[],[[],3,[0,5,6,1],[]]]]
-
-[[10,[[3,7],2,5,[0]],[4,[3,6,9]],3],[6,8]]
-[[1],[8,10,3],[1],[1,3],[3,[6,[4,2],4,[2,9,0],[8,7,1,10]]]]
-
-[[[[],6,5,0,10],4,0]]
-[[5,[[1],6,3],9]]
-
-[[7,[[],[],[2,6,10],[0,6,4,2]]]]
-[[],[4,1,[[0,3,2,10]],[]],[[7,2,7],[[2,10,6,1],[10,6]],[4,3,8,[9,8,0,2,6]],7,[]]]
-
-[[4,0,[[3,6,1,2,1],[10,9,6,7,10]],[2,[6,9,3,10],[6,2,9,5,6]],[]],[[5,[9],10,[10,1,0,10,2]],2,[7,10]],[],[8,8],[1,8,[],[[7],9]]]
-[[],[5,[9,2,[7,9],[9,3,3,0,3]],[[4,0],[1,7],[9,2,8]]],[[[1,8,10],[],[]]],[[[8,5,9,2,10]],[],[[0],2,[4],[5,1]],10,7],[3,7,[[8,3,1,6,9],1,[],6,[9,3]],5]]
-
-[[],[4,[2],6,8]]
-[[[5,[7,3]],8],[[[4,3,1],4,2,2],[10,4,[3,0],[8,7,8]]],[],[[[7,0]],8,4,2,[5,0,4,[5,10,1,1],[0,9]]],[[[10,6,2],3,[2,6,4]],3]]
-
-[[8,[[8,1,0,0],9],5,7,[7,[8,6]]],[[[7,10,5,10,7],[8,8,1,10],7,[2,10,4]],5,7],[4,2,1,[[0,3,4,7],[9,0,1,8],6,9]]]
-[[8,[3,[],[3,3,10]],[10,[4],[10,5,2,4,5],4,[]]],[[3,0,3,9,2]],[[[]]],[8,6]]
-
-[[[]],[[8,[1,0,0],[6],2,[8,4,3,3,10]],5,[[3,1,3],[2,6],[9,2]]],[[]]]
-[[7],[[9,1],10,5,8,[7,[3,2],[7,0,2,1,1]]],[5,1,0,[1,[8,0,2,9],10,1],6]]
-
-[[5,2,[]]]
-[[[[10,10,2,4],10],0,7,[2,[10,8,3,0,8],[8],[4]]],[],[2,[]]]
-
-[[[3],[],[]],[],[[[5],[9,2,4,8,10]],[[3,6]],5,[[],[4,6,1,4],1],0],[3,[7],[[],7,[],4]],[[],[[9,3,3,8],4,9,5]]]
-[[[],[0,3],[1,6,7]],[[]],[],[8,6],[4,[[5]]]]
-
-[[],[[0,[8,0,5,6,2],[6,8,4],[8,2],2],[],[1,[6]],9],[1,[7]]]
-[[[[0]],[[0,10,4,8,7]],8,0],[2,9,[[9,8,2],8,[7,10,7,5]],[[],[],3],7]]
-
-[[8,8,[6],6,[8,0]]]
-[[4,5,[1,1],8,[]],[10,[[4],7]],[[[3],9],0,[3,8,8,[7,8],[]],0],[[],[5,[5,2,0]]]]
-
-[[[],8],[[],4,7,[],7],[5,[],[],[[6,7,0,5,4]]]]
-[[[5,[2,1,3,0,3],[6,3],[6,5,4,1,4]],[[2],[]],7,10,1],[],[[[],[9,9,2,5],[1,9,7,10,5],4,[1,0,10,7]]]]
-
-[[7,8]]
-[[6,[[9,5],[2,10],4],9,[[10,0,8,6],5,[4],8]],[],[[[3,3,1,2],7],6,8,[[]]],[]]
-
-[[2,[]],[4],[],[],[[2,8,7,[8,8,0]]]]
-[[0,3,4,[2,3,2,0,[7,7,9]],[[2,0,9],[1,6,1,0,3]]],[3,[[3,2,5,3],6,[2,2,3,7],[10,7,4],3],10,[3,4]],[1,5,1,1,[[3,8,6],[0,1],[2],[]]],[9,4,8,7,10],[0,[3,[9,4,3]]]]
-
-[[[[0],9,[6,3,5,9]],[[1],8,[4,2,1,7,10],[1,6,3,7,8]],[[9,2,0,8,7],6]],[7,9,[[7,9],[],[5,9]],0,1]]
-[[7,[[],[4,2,0,3]]],[[8,9,3,8],3,[[]]],[10,5,[8,[6,10],0,1,7],5,4]]
-
-[[[1,5],7,9]]
-[[10,[[4,9]],[[5]],7],[[],[[1,4,7]],[[1,9,0,0],[2,10,5,2,1]]]]
-
-[[],[[6],[[6,6]],9,[[5,3],9,2,[8,1,6],8]],[],[[[3],[6],[0,3,0],[4,0],[1,8,8,3,1]],0,[[],[10]]],[2,[7,[6,10],5,0,8],[1,[5,1,1]],1]]
-[[9]]
-
-[[6,3],[4,4,3,6,[4]]]
-[[],[3,10,0,10]]
-
-[[8,[4],[[8]]],[6,[]],[],[2],[0,3,10]]
-[[[0,[6,2,1,3],2,4,[3]]],[[],0,1,[7,[1,9,4,3]],1],[[[10],[7,10,2,8],6]]]
-
-[[7,[[3,8,1,7,10]],6,[9,0,[9]],8],[[9,7,4],[[2]]],[[[2,5,7,7],2]]]
-[[[[8,0,8],0],[0],[[0,8,5],[8,10,6,2,2],7,[9,0]],0],[[[0,2,9,0],10,[0,3],[1,1,7],0],5]]
-
-[[],[[5],[],[]]]
-[[],[[[6,2,4],10,[8]],1,2,6],[[[3,2,2],8],[]]]
-
-[[[[],[10],10,7]],[],[6,6]]
-[[8,4]]
-
-[[7,4,[1,[3,5,3,0,7],4]],[4,[4,8,[]],[[2,6]]]]
-[[],[4,1,0,4],[]]
-
-[[10,[6,[9,9]]]]
-[[2,6,9,10]]
-
-[[9,8,[[7,2,8,6],[7],[5,1]],4,[5,1,[5,8,10],[7,5]]],[1,3,[[1,7],10,2,6]]]
-[[9,2,[6,2]],[[[2,10,7,7,3],[7],[7,6,5,8,1],[2,0],[6]],[[0]],0]]
-
-[[],[],[9,[[6,4,0],[4,9,0,2],[0,8,5,0,0]]]]
-[[1,[[0],10,6,2,[7,5,5,5,0]]],[7],[]]
-
-[[[6,6],6],[[3,[3],[]],[1],[3,2,2,6,[10]]]]
-[[[[],4,4,10],[[9,4,6],4,[1,2,4,6,4],5,[]],2,[[6,1,9,1,7],9,8,[5,0],[8,3,7,6]],[[8,10,0,5],9,10,10,4]],[[[4,2,6],9,7],9,0,7,9]]
-
-[[9,10],[9,3],[],[9,[[2]]],[[[],1,[1,9,3,8],[7,2],10]]]
-[[[[10,0],9,4],[6],[[5],8,[2,10,8,1,8]],[[10],0,[8,1]],[8,[10],[2,4,5,10,8],[1],[8,6]]],[3]]
-
-[[2],[[6],0,[8]]]
-[[[[10,1,0],[4]],1,[[7,4],[10,7,5,5]]],[9,[[10,8],[1],6,7],3,[[4,8],[],10]],[[2]],[6],[0,3,2,[7,[3,9,8],6,4]]]
-
-[[[[10,4]],[6,[],1,5,5],[8,8,3],3,[0,[6],[],2]],[7,[9],2],[[[0,4,1]],3,[7,[3,5,2,7,5],3],9,4],[2,3,7,7,9],[2,5,[4,[8,2,4,4],[9],[2,3],[6,7,10,0]],[[9,8,6],[6,1,2,0,1],[7,1,5],[5,0,9,7],2],3]]
-[[0,[[8,4,7,3,6],2,[8,10,8]],[3,2,1,[5,3,1,4,1],1],[[4,2,8,1],[10,0,3],[5,7],5]],[5,4,1,2],[[[10,0,2]],[[3,10],4],0]]
-
-[[[3],[[10,4]]],[0,[[4,2],6,6,10],[[9,6,4],[6,8,4],[10,7,10,4],2],2,5],[7]]
-[[5,[[0,9,4]],7,5,[[0,10,3,8,10],[7,2,1]]],[9,[1,[9,2,10,1,1],[1,0]],2,[[9,4],3],2]]
-
-[[[],8,1,7],[[1]],[8,2],[5,[[],[4],[4,9,2,4],[10,3,0,9],[9,3]],[[4,3,4,1,6],[2]],[[6,6],10,5,[6,8,9]]]]
-[[10,[],[[],[1,5,4,10,3],[2,4,5,6],[7,8],[]],[5,[6,1]]],[],[3,[7,[6,0,2,1]],8,[10,[8,9],4,9,7]],[[[],1],[[10,6,9,2],10,[6,1],1,1],9,0,[[9,10,4],9,0,[9],1]],[[[6,0,6],[6,9,0,1,2]]]]
-
-[[1],[3,9,6,[[5],[],0,5,10]],[[9,[],[3,1,8]],0,[[6,8],6],[[],10],[4]],[[4,[5,6,4,1,5],3,[1,6,5,7]],[[8],2,2],6]]
-[[7,5,9,[4],[[],[8,1],5]],[7,3,1,8],[[8,3,0],9],[],[[[1],8,5]]]
-
-[[6,9,[2,[4,9,3],[7,6,10,7],2,7],8],[[4,10,8],2],[[[8,1,7],8],3],[[[6]],[3],[[],8]],[2,0,8,[8,5]]]
-[[4,[9,9],2,[[],[1]]],[[],7,6,1,[]],[]]
-
-[[[[5,2,4,9],[9,10,8],[]],0],[[8]]]
-[[],[9,[[10,4,10],[1,7,10,7],[]],[],7],[[9,5],[[2,2]],0,[[3,4]],[[10,9,1,9,6],[3,0,5,4]]]]
-
-[[[10,10,[5]],[10],7,[]],[3,5,3],[],[],[[],[7,[9,8,9],[4,2,9,9,2],[]],0,0,7]]
-[[[[],1,6,9,[]],0,[0,[0,0],[10,0,0,1,2],0],[[1,7,7],3,[],[9,4],6]],[],[1,6,[5,2],[1,5,[7,2,8]],[[5,9,6,4],[0,10],[6,9,3],[3,8,0]]],[7]]
-
-[[0,[2],10],[2,10,7,[[9]]]]
-[[2,0,2],[10,2,[9,8,9,2,4]]]
-
-[[2],[2,[10,[4,1,3],10],7,[[7,7,3],[7,8],[0,7,6,3,8],3]],[[[9,0,3],[],[1,3,10,0,8]]]]
-[[[[1,3,8,5],10,[4],2],10],[0,9,7,[[0,4,10,0,6],[]],7],[[[0],1,9,[3],[6,9]],4,9],[[5],6],[1]]
-
-[[[3,[5,3,2,7,9]],10,[]]]
-[[[5,7,5],[[9,1,6,7],9,[2,6,5],[6,6,1],[0,6]],[[]],[],9],[[1,[3],[3,10,0],4],2,[],[[2,1,1,5,9],6,8],[]],[],[[],6,[[5,2],2,[8]]],[]]
-
-[[],[],[1,[[2,0]]]]
-[[7,2,2,[],[[8,7,3,6],4,2,2,[]]]]
-
-[[6,9,8],[[],3,8,[],2],[3,9],[[[],10],[[9,3,9,8]],2,7]]
-[[2,[8,[]],[7,8,5,5,5],2,1],[],[[[6,1,9,2,9],7,4],[3,[3,8,1,6]],[[4,6,5],[3,10,8,5,6],[1],8,[5,0,4,0,6]],1]]
-
-[[],[],[2,1,[4,7,[1]],[9,[7],0,6]]]
-[[3],[0,[[6,6,9,6],[4,7,10,1,8],[4,0,10],1,[10]]],[0,[6,5,[8,9,3,9,6],[],[4]]],[[],[[]],4]]
-
-[[1,8,10,[]],[9,[],[[8]],[[9,6,3,2],[3,8,10],[],3],[1,9,[0]]],[],[5,[1,[],[4,5,7,10,9]],[[10,6,0,1]],9,[0]]]
-[[0,3,[9,9]]]
-
-[[4,0,3],[],[],[[[]],[[5,3,1],8,[],1,[1,8,3,7]],[6]]]
-[[[]],[[[8,0,3,2,2],[3],[1,6,8],10,4],[6,4,7,[1,2]],[8,[3],[10,9],[4,0,10,8],[1]]],[[],[1,2,6,8],5],[3,9,7],[[6,6],0]]
-
-[[7,[],[0],5],[7,[7]]]
-[[0,[8,[3,3,8],10,7],5,[[1,7],[0,0],[5,2,0],1],2],[7,8]]
-
-[[4,[2,2,2,9],[[6,6,1,10,4],[]],5,10],[],[6,[[5,3,7,9,2],[4,2,4,9,7],[1,4,9,7,1]],2,[],[]],[[[7,0],7,[9,4,0]],9,[1,[1,0,1],[9,3,3],[9,2,10,5,10],10],7,[[9,0,8,1,8],8,[7,7,10,2]]],[]]
-[[[3,7,[]],[9,7],0],[[[9,9,3,5,2],1],7,[10],[[10],2,[6,1],[1,10,3,1]],8],[3,9],[7,7,2,[2,[8,7,6],10]],[1]]
-
-[[[7,[10,1],[2,9],[3,4,6,9]],2,[],3],[[],6,3,[],[[4,7]]]]
-[[],[[6,[],[],[0,10,2,6],1],1,3,[3,[10,2,7,1,4],[6,3,0],[1,3,6,3],[4]],[0,[8,10,0,8,5],3,2,6]],[],[]]
-
-[[5,[[4,0],[1,3,4],7,2],0,8,[]]]
-[[],[[[],10,[10,9,6,0,1],[]],[[8],4,6,4,[0,2,8]]],[4]]
-
-[[],[],[],[[[],[8,6],[],[2,7,7,4]],[],1,[9,9,[1,3,4],0,[5,2]],[[4,4,5,6,10],[5,1,3,3],0]]]
-[[10,[[6,4],[3,1,1,8,4]],9,[[3]]],[9,2,6,8,1]]
-
-[[],[1],[[[4],[5,1,6]],[]]]
-[[10,[[]],[[8,10],8],[1,[5,3,7,8,7]]],[],[],[[[7]],[[2],6],[[0,8,1,3,10],[],2,0]],[[7,8,[]],2,1,[[8,7,4],1,[4],[],2],7]]
-
-[[[[3,5],[]],[7,0,5],[[],1,[7,2,7,10,1],3],[[4,1,5,5,6],[3,4],[6,1,3,9,2],8],[8]]]
-[[10,10,[6],[4]]]
-
-[[[]],[8,4],[4,7,8,3,[[8,4,2],6,0]],[[1,[4,6,4],[],1]]]
-[[[5],[[4,4,9,2,0]]],[9,[[7]],[[6,2],[0,3,1,1,7],5,4],[0]]]
-
-[[[3,[1,0,10,7],[8,8,8,10]],[10,[0,3],8,[9,5]],6],[[10,[3],[9,10,0,2]],[7,4],[0,7,4,6,[3,5]],[],[10,[5,4,6,1],5,4]],[[[0],0],5],[4],[8,[[9,4,5],3],[9,[0,7,2]],[],[]]]
-[[5,3,9,0],[7,[6,[8,1,0,1,8],3,[9,2],5],[[1,0,0,6,4]],[6,[2,4],5,[2,10]]],[],[3,9]]
-
-[[[1,[4,0,10,6,8]],1],[],[[[]]]]
-[[],[],[[9,[9,7],7,[9,4,3],0],9,6],[2,[[6,8]],2,[9,7,[4,6,10,4,2],[]]]]
-
-[[2,10,[4,9,[],[7,10,0],1],[[1,8,5,4,6],5]],[[[]],[[10,2,1]],[[]]]]
-[[9,9,[10,[1,5,1,2]],[9],3],[8,8],[4,[5,[6],10],[[0,5],[7,10],2],6,[[2,9,8],[5,4,7,5]]],[1],[[10,8,4,[1,7,8,9],8]]]
-
-[[3,[]]]
-[[10],[[3,[7,10,7],[2,6,6,1,1],5],8],[1],[4,[9,1,[2]],7]]
-
-[[],[[[3,2,0],[3,4,5,10,8],[3,3,3,0,3],[8,3],9],[[5,0,7,8],[],[1,1]],[[10],[2,7,4,4],[],6],2],[[1,[3],[7,4],[8,1,9],3],0,7]]
-[[0,8]]
-
-[[[],[]],[3],[1,9,10,[[4,3,2],[3,10,0,6,4],7,7,10],[6,[10],[9,8,0]]],[]]
-[[[5,0,[0],[9,10,7],[1,0]],5],[],[5,3,0,3],[[[3,8,9],[],[2,2,6,6,10],[]]]]
-
-[[[[9,1,6]],3,[[2,0,8,1,0]]]]
-[[3,[[2,1],[8],10,6]],[10,2,3,6,[]],[0,10,0,[[4,7,9],[],3],3],[[[4,2,5],[8]],9,6,[3,[],7,[0,4,8,0]]],[[[6],10,[10,9]],2,[7,[3],[2,0],[6,5,5,7,0]]]]
-
-[[1,5],[8,[5,1,10,[0,4,9,4,1]],6],[],[7,[3,3,5,10,4]]]
-[[],[10,[7]]]
-
-[5,8,1,2]
-[5,8,1,2,1]
-
-[[0,[],[],[[6,6,7,7],10,3],1],[[6,7,[8,1,4,4,7],[6,6],2]],[[[],7,[],4,2],[[5,5,5,0,7],6,[8,9,7]],[[6,3,3,8,9],1,[6,9,8,5],3],3,[[9,9],[],7,[8,2,8,10],8]]]
-[[[9,[2],[],[4,6],[10,2]],5]]
-
-[[[9],3,[7,2,10]]]
-[[2],[9,1,7,[9]]]
-
-[[],[]]
-[[5,[],7],[[[6,0],[7,5,9,7],2]],[[],[[5,2,8],[]],[[5,4,1,10],[3,5,2,2,10],[0,6],10]],[[2],0,8],[]]
-
-[[[9,10,[4],7,0],1,1,[[],5]]]
-[[[[9,8,8,3,1],[10,7,2,10,5]],4,3],[9,[5],[[],[5],10,[10],[10,3,4]]]]
-
-[[10,[[2],[7,7,3,3,3]],[1,[],[10,4,4]]]]
-[[[[1],[1,2,5,7]],[6,3,[2,1,7]],8],[4,5]]
-
-[[[3,[1,6,1,4,4],[4,5,5,0]],8,8,9],[[],[],[6,[4,10],[],[4,9,2]],6]]
-[[0,5,7,8],[]]
-
-[[2,[[0,5,9],[10,8],[2,1],[5,6,5],[7,9]]],[7,1,[10,3,[2,4],[9,10,1,9,10],0],0],[0,[[2,10,4,0],4],[6]]]
-[[[[9,10,9],[8,7,5,7,8]],0,0,[[2,3],8,0,[2,5,10,9,8]],6]]
-
-[[2,[9,4,5,[2,0]],1,7],[9,[6,[9,5,1]]]]
-[[],[[],4,[[4,10,5,8],10,[0,3,6],8],[6]],[[9]],[3,9,9,[],[9,[7,9,5],8]],[[2,1,9,[2,0,9,7,4],[9,10,10]],[8,[9,2,3],2,[6,9,3,8]],[10,7,[],10],2]]
-
-[[5],[],[],[9],[[[7,8,8],5],[1,[7,4,10],[9,1],2,[]],[],[4,5,1,10,10]]]
-[[[10,[10,3,4,0,8],4,5,1],[10,7],[[9,6,6],[5,6,3,7],[5,9,2,0,8],3],[[]],[[1,10,7,1,3],8,4,3]],[[[]],[9],2]]
-
-[[6,[[1],[7],[]],5,6,8],[6,[2],[[]]]]
-[[[7,[10],[7,8,8,7,1]]],[3,10,3,[[6],7,[0,9,7,7],1,[9,9,1,2,2]],3],[6,[6,[2,9]],[[],[2,1,3,2,9],[3,7,9,6],5],[[1]],[6,[9,6,1,3]]],[[2,[2],[5,0,2],5],0,4,5,1]]
-
-[[[9,0],4,[[7]],4],[9,7],[[1,[0],[1,0,7,1,3],[8],[4,2,0]],9,[[0,5],0,7],6,[[],10]],[]]
-[[[[5,10],[4,4,5,3],[9,8,9],[],[5,2]],10,9],[],[[[],[]],5]]
-
-[[],[],[[],7,7,[2,[8,9]],[0,[7,3],[3,6,1,10,5]]],[3]]
-[[[[0,9,7,10]],5],[[],[]],[[[0,2,10],[10,0,5,3,9],9,[],5]]]
-
-[[8]]
-[[[2,[1,6]],[[9,6,6,4,10],[],9,6],[],[]],[],[[1,1,6],4,[[],[4,3,2,9,2]],3]]
-
-[[5,8,10,4,3],[0,9,[2,9,[4],[6,9,8,0],[9]],6,[]]]
-[[],[7,5,[5,[6,5,1,1],4],[[4,10,0,4,10],[10,9],[0,9,1]],6],[[],[[0,9],2],[0,[2,7,9],[],10],[[7,1,9,5,0],[4,3,1,8,1],3,[8,8]],9],[[10,3,5,[5,4,9,10,6]],7],[[1,[],1],[[0,4],6,1,[10,10,8,6,9]],[],6]]
-
-[[],[1,[2,2,[2,9],4,[]],2],[],[4,10,[],7,[8,[],[]]],[6,8,10,3]]
-[[[3,0],4],[[8,[1,10,5]],[[1,7,0,3],[7,3,5]],[10,8,[0],2,[9,2,1]]],[[],[10],[[9,0]],[8,0,4],[[8,0],3]]]
-
-[[10],[6],[[6,[5,2,7,10,1],[1,6,6],[9,6,10,8],10],2,9,[[0,3,7],0]],[[[3,2],2,9,[]],10,7],[3,1,[6],[9,10,[]]]]
-[[[],[[]],6],[[],[],[7],[]],[3,0,8,5]]
-
-[[],[3,[10],8],[[[9]],[],[[4,5,0,9,2],[],[8],[0,6,9]]],[],[8]]
-[[7],[[8,9,4,8],[]],[],[3,8,[[8],[],[6,4,4],3]],[[[10,6]],[[],[],9,[2,3,7,1],10]]]
-
-[[9,[9,3]],[[7,7,7,7,3],1],[7,[[],[6,10,1],[4,5,3,8]]]]
-[[8,4],[[4,1,[1,3,3],0,[0,10]],[[0,6,8],0,[7,5,4],6,[7,2,5,7]],10,2,9]]
-
-[[],[6],[[[1,4,4,1],[]],2],[1,1,[[0],[6,6]],2]]
-[[6,[[0,7],0,[6],3],9,[[8],3,[1,5,0,8,6],[]]],[0,8,2,2,10]]
-
-[[3,[[7],[10,6,9,3,0],[3],4,4],8],[[0,7,[7,8,8],1,[0,4]]]]
-[[[10,9,[],[5,2,8,3]],[8,[8,1],9,[]],10],[6,8,[9,[7],9,[5,8,8,10,1]]],[4],[[[4,9,6],5,9],4,4]]
-
-[[],[],[[0,[5,5,5,1,3],1,[],0]],[[5,1,[2,10],[1]],9,2,[8,9,[10,10]]]]
-[[[3,1]]]
-
-[[[8,3,[4,2,1,2]],[10],9],[[0,5],[2],3,1],[0,[[1,7,7,9]],1,[8,[7,6,8],5]],[[8,[8,8]],[10,3,[3],[10,9,1]],[]]]
-[[[[2,0,2]],[0,2,9]],[8,5,[0],[8,6],10],[10,[[5,1,4],3],10],[[5,[],0,3,[2,4,7,8]]]]
-
-[[],[[],[6,[5,7,5,5],[8,4,3],6,8],10,[[8,5,6,1,7],[9,8,9,2],[5,2,4,10]],[[8]]],[[5,5,[2,4,4,4,9],9],2,6,[[7,5,7,4,4],3,[],[9]],[[3,9,5]]]]
-[[9,6],[[[0]]],[0,8,[[9,7],4,[8,0,0]]],[]]
-
-[[1,1],[4,1,[5,[0,7,3,5,0],[7,8,9],[4,0,0,8],2],1,[9,[1,9],[],[3,4,8,4,1]]],[[5,0,[4,2,0,9,4],[]],0],[3]]
-[[0,7,[[6,0,8],0,[]]]]
-
-[[],[[],[6],1],[7,[6,3,5],[]]]
-[[],[[7,[6],[3,2,2],[10]],[[],[6],[7,9,9]],[[8],[2,10,5],[1,9,5,6,4],3],8],[[],[[9],[2],[0,8,10,6],[3,7]],[5,[1,3,9],2,10,7],[7]]]
-
-[[[[]],[[1,5,5,3,3]]],[],[1,[8,8,[3,9,6]]]]
-[[[],7,2,6,[9,[5,10,0],[9,6,6,5,5],1]],[]]
-
-[[1,5,[4,0,[8]]],[[[5],8,[7,6,5],3,0],[],4,6],[2,[[2],[0,2,5,3,3],1],4,7,6]]
-[[2,[[10,10,1,0,9]]],[[],9,1],[[3,4]],[[[10,2,3],[9,3,7],[10,1,6,1]],7]]
-
-[[0,[],[[],10,7,[7,8,8]]],[],[0,[[7,2,2,7],4,1,7,[7,8,0,6,6]],[[8,8,6]]]]
-[[9],[[[4,7,5],[1,5,8,2],[3,2,8,6,0],[7,5]],[[7,9,6],[]],[0,3,8]],[[4]],[[],[2,5,[]],0]]
-
-[[8,[[2],0,[]],[5,9],[]],[[2,6],[]]]
-[[[]],[0,3,9,4,0],[[[2,4],[6],[4,2,1],[]]],[[1,0,[0,0],7,6],1,5,7,[2]]]
-
-[[[[4,10,7],0],[[1,1,7,4,5],[1],8,5,9],[[9,9],3,[],[7,6,2]],[[3,0],[3],3],[2,[3,6,3,2],[10,2,6],[],4]]]
-[[[10,9,3,0],8]]
-
-[[[[4,10,7],7,[4,8,5,2,4]],8,4],[[[],5],[],7,[2,3]]]
-[[[9],[1,0,[],2,[0]],[[0,1],[10,8],[10]]]]
-
-[[[[10,1,3,6,3],[3,0],2],[],6],[9,9,[4,[10],4,5,[5,0,4]]],[9,9]]
-[[10,10,8]]
-
-[[[[3,6]],8,[],6,[0,2,8,[1,0],8]]]
-[[6,6,6,7,1],[],[],[],[[[6],[6,3]],[4,0],3,3,[[],[10,4,9,8]]]]
-
-[[1,[[2,1,2,10],5,[],[8,4,2],1],[7,9,[4,5]],[3,[6,6]],5],[]]
-[[]]
-
-[[[3,10],[8,9,9],2,9],[8,[],10,10]]
-[[[[],[0]],3,6]]
-
-[[3,4,[]],[4],[3,9,[5,[1],[],[10,7],[9,0,2,9]],[2,2,9],4],[[[2,2,6]]]]
-[[],[4,6,[[],[],[3,7,5],9,7]],[],[[8,5,7],8,1,3,8]]
-
-[[],[[0,2],0,7,[],[[6]]],[[],[8],[3,[],3],[7,[5,0,4],7],5],[1,1,[[]]],[[[0,10,7,10],2,0,[3,7,1,1]],9]]
-[[[8,[7,6,6]],9],[10,8,9,6],[[[],[1,7,3]],[[5,5,5,1],[],0,[7,7,9,8],7],[10,[],[3,9,7,4,3],10],[[8,7],10]],[[2],[5],4,[[2,6],[7,7,10,7,0],[3,8,3],3,6],10],[9,10,[[6,2]],5]]
-
-[[4,2],[[9,5,7,7,[]]]]
-[[[1,[4,8,7,2,4],4,[4,6,5,6,4]],[8,[6]],8],[3]]
-
-[[3,2,1,8]]
-[[[10,6,[2],2],10],[[],3,10,[[],[],[4],6,8],[[8,5,10],[],5]]]
-
-[[[6,[5,5,0]],5],[[],7,1,[],7]]
-[[3,4,1,6,[[],[4],9]],[[[3,4,5]],[],4]]
-
-[[4,[5,4],[[0,10,9,1,5]],5,5]]
-[[3]]
-
-[[0,[8],9],[3,[[5,7]],8],[[5,7,1,10,4]]]
-[[6,[3,6,3,[9,2,9,2,9]],[[3,4,1,9,5],7,4,10,[5,0,4,4,2]]],[0],[[0,1,[7],[],0],[5,4,[9,3]],9,1,[[6,0],[7,4]]],[[5,8],2]]
-
-[[6,[],[[4],[3,5]],6],[8,[7,5,3]],[5,[[],2,[1,1]],[6,4],[],[[0],9,[0,8,10,4],0]]]
-[[[[10,5,10,4],[9,1,7,6,10],[0]],9,[1,2,3,5],[0,[5,7,4,7],[9,10,7],1,7]],[[[],0],7,[8,[10,1,10,7,8]],[4,1,[4,3,10],[],1],5],[8,9],[[7,10,2,[4],2],[[3],[8,8,0,6],6,4]]]
-
-[[[[7,8,2,2],[8,2,3,0],[4,3,8,0,8]],5,[[9,7,1],[],[1,0],[1,2,6]],9,[[5,1,7],3,[],5]]]
-[[[0,[8,3,5],[5],1],[8],[[4,10,10,8]],0],[[0]],[[[7,2],[8,9],[]],[[],[1,9,7],[9,4,6],[5]],[[5,8,7,2]]]]
-
-[[[],2,[1,[6,6,2,2,5]],8]]
-[[0,[[2,7,6,0]]]]
-
-[[],[10,1],[6,[2,[],8,0,[5,1,7,9,4]],9,4,9],[[[8,5,9,4,1],6],3,[],9,3],[[2],[],[[9,3,7],8,[0,1],[1,2,1,9,1],[]],[[6,7],[5],2,9],[[]]]]
-[[[8,6]],[0],[],[10]]
-
-[[],[9],[],[[[],[2],[9]]],[6,7,10,[8,[],[6,3],0]]]
-[[7],[9,8,[[2,5],[6]],[[3,10,5,5,4],[1,8,3],0,9,[0,2,5]]]]
-
-[[6,[],[[6,6,0,3],[10,0,6,5,0],9]]]
-[[],[[10,[7]],8],[7,7],[[5,1,[10],[8,1,6,0,10],3]],[4,[0,6]]]
-
-[[3],[[6,9]]]
-[[[[5,10],8],10,[[9,1,10,4,4],[10,3],4,[9,9,9,9,5]]]]
 ";
 }
@@ -121,9 +121,46 @@ contain a beacon?
 
  */
 
-#[cfg(test)]
-mod test {
-    fn part_1(input: &str) -> usize {
-        todo!()
+use common::{random::Rng, ParseError, Solution, Unsolved};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = ();
+    type Answer1 = Unsolved;
+    type Answer2 = Unsolved;
+
+    fn parse(_input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part_1(_: &()) -> Unsolved {
+        Unsolved
+    }
+
+    fn part_2(_: &()) -> Unsolved {
+        Unsolved
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let point = |rng: &mut Rng| (rng.range(0..=4_000_000), rng.range(0..=4_000_000));
+        let distance = |(x, y): (i64, i64), (bx, by): (i64, i64)| (x - bx).abs() + (y - by).abs();
+        let beacons: Vec<(i64, i64)> = (0..size / 2 + 1).map(|_| point(rng)).collect();
+        let sensors: Vec<String> = (0..size.max(1))
+            .map(|_| loop {
+                let (x, y) = point(rng);
+                let mut closest: Vec<_> = beacons
+                    .iter()
+                    .map(|&beacon| (distance((x, y), beacon), beacon))
+                    .collect();
+                closest.sort();
+                // There is never a tie for the closest beacon.
+                if closest.len() == 1 || closest[0].0 < closest[1].0 {
+                    let (bx, by) = closest[0].1;
+                    break format!("Sensor at x={x}, y={y}: closest beacon is at x={bx}, y={by}");
+                }
+            })
+            .collect();
+        sensors.join("\n")
     }
 }
@@ -89,27 +89,44 @@ strategy guide?
 
  */
 
-#[cfg(test)]
-mod test {
+use common::{parse, random::Rng, ParseError, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Each round as the opponent's shape and the second column, both as
+    /// numbers from 0 to 2.
+    type Parsed<'a> = Vec<(usize, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    fn part_1(input: &str) -> usize {
+    fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
         input
             .trim()
             .lines()
             .map(|line| {
-                let (opp, you) = line.split_once(' ').unwrap();
+                let (opp, you) = parse::split_once(line, " ")?;
                 let opp = match opp {
                     "A" => 0,
                     "B" => 1,
                     "C" => 2,
-                    _ => panic!("Invalid card"),
+                    _ => return Err(ParseError::at(opp, "A, B or C")),
                 };
                 let you = match you {
                     "X" => 0,
                     "Y" => 1,
                     "Z" => 2,
-                    _ => panic!("Invalid card"),
+                    _ => return Err(ParseError::at(you, "X, Y or Z")),
                 };
+                Ok((opp, you))
+            })
+            .collect()
+    }
+
+    fn part_1(rounds: &Vec<(usize, usize)>) -> usize {
+        rounds
+            .iter()
+            .map(|&(opp, you)| {
                 let outcome = if you == (opp + 1) % 3 {
                     6
                 } else if opp == you {
@@ -122,2545 +139,57 @@ mod test {
             .sum()
     }
 
-    fn part_2(input: &str) -> usize {
-        input
-            .trim()
-            .lines()
-            .map(|line| {
-                let (opp, outcome) = line.split_once(' ').unwrap();
-                let opp = match opp {
-                    "A" => 0,
-                    "B" => 1,
-                    "C" => 2,
-                    _ => panic!("Invalid card"),
-                };
+    fn part_2(rounds: &Vec<(usize, usize)>) -> usize {
+        rounds
+            .iter()
+            .map(|&(opp, outcome)| {
                 let (outcome, you) = match outcome {
-                    "X" => (0, (opp + 2) % 3),
-                    "Y" => (3, opp),
-                    "Z" => (6, (opp + 1) % 3),
-                    _ => panic!("Invalid card"),
+                    0 => (0, (opp + 2) % 3),
+                    1 => (3, opp),
+                    _ => (6, (opp + 1) % 3),
                 };
                 outcome + you + 1
             })
             .sum()
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let rounds: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.pick(&['A', 'B', 'C']),
+                    rng.pick(&['X', 'Y', 'Z'])
+                )
+            })
+            .collect();
+        rounds.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 15);
-        assert_eq!(part_1(INPUT), 12645);
+        assert_eq!(Solver::solve_1(EXAMPLE), 15);
+        if let Some(input) = common::input::for_test(2022, 2) {
+            assert_eq!(Solver::solve_1(&input), 12645);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 12);
-        assert_eq!(part_2(INPUT), 11756);
+        assert_eq!(Solver::solve_2(EXAMPLE), 12);
+        if let Some(input) = common::input::for_test(2022, 2) {
+            assert_eq!(Solver::solve_2(&input), 11756);
+        }
     }
 
     const EXAMPLE: &str = "
 A Y
 B X
 C Z
-";
-    const INPUT: &str = "B Z
-A Y
-A Y
-B X
-A Y
-B X
-A X
-B X
-B X
-B X
-C Y
-C Y
-A X
-A Y
-A Y
-B X
-A X
-C Y
-B Z
-A X
-B Z
-C Y
-C Z
-A X
-C Z
-A Y
-A X
-B Z
-B X
-A Z
-B Z
-A X
-A Z
-B X
-B X
-C Y
-B X
-A X
-A Y
-C Y
-B Z
-C X
-A X
-B Z
-B Y
-B X
-A Z
-B Z
-B Z
-A X
-B Z
-B X
-C X
-A X
-B X
-B X
-B Z
-B X
-B Z
-A Y
-B Z
-B X
-C Y
-A Y
-C Y
-B Z
-A Y
-B X
-A Y
-C Y
-A Y
-B Z
-B Z
-C Z
-B X
-A X
-C X
-B Y
-A Z
-C Z
-B Z
-A Y
-A X
-B Z
-C Z
-B X
-B Z
-A Y
-B X
-A Y
-A Y
-A X
-C Y
-B X
-B Z
-C Y
-B X
-B X
-C Z
-C Y
-C Y
-A X
-B Z
-A X
-A X
-B Y
-A Y
-B Z
-B Z
-B Z
-A Y
-A X
-A Y
-B X
-B Z
-A X
-B X
-B X
-C Y
-B Z
-A X
-A Y
-B Z
-A X
-B Z
-A Z
-A Y
-A Z
-B Z
-A X
-A Z
-A X
-C Y
-A Y
-A X
-B Z
-C Z
-B Y
-B Z
-B X
-A X
-C Y
-B Z
-B Y
-B X
-B X
-A X
-C Y
-A Y
-B X
-A Y
-A Y
-A Y
-B X
-A Z
-A Y
-B X
-A X
-A Z
-B X
-B X
-C Z
-C Y
-B X
-B X
-A Y
-C Y
-C Y
-A X
-B Z
-B Z
-A Y
-C Y
-B Z
-A Y
-A Y
-A X
-B Z
-B X
-A X
-A X
-B X
-A X
-B X
-A X
-B X
-A X
-B Y
-B Z
-A X
-B X
-B X
-B Z
-A X
-B Z
-A Y
-B Z
-A X
-A Z
-B X
-A X
-A Y
-C Y
-C Y
-B Z
-A X
-C Y
-A X
-B X
-A Y
-A X
-B X
-B X
-A Y
-A X
-A X
-A Z
-C Y
-A X
-B X
-A X
-A X
-A X
-A Y
-A Y
-C Z
-C Z
-A Y
-B Z
-A X
-B X
-C Y
-B Z
-B Z
-C Y
-C Y
-A Y
-A Y
-A Y
-A Y
-C Z
-A Y
-B Z
-B Z
-C Z
-C Y
-C Y
-C Y
-C Y
-C X
-A X
-B Z
-B Z
-B Z
-B Z
-C Y
-B Z
-A X
-A X
-A X
-B Z
-A Y
-C X
-B Z
-A X
-A Y
-B Z
-C Z
-B X
-A X
-B Z
-A X
-A Y
-B X
-C Y
-B X
-B Z
-A X
-B X
-B X
-A X
-B X
-B Z
-B X
-A X
-B X
-B X
-B X
-B Z
-B X
-B Z
-C Y
-A X
-A X
-C Y
-C Z
-A Y
-B Z
-B Z
-A X
-B Z
-A Y
-A X
-A Y
-A Y
-A Y
-B Z
-C Y
-A X
-A Y
-A Z
-B X
-A Z
-B X
-B X
-B X
-A X
-A X
-C Y
-B X
-A X
-B Z
-A Y
-A Y
-A X
-B Z
-A Y
-B X
-A Y
-B Z
-C Y
-A Y
-C X
-B X
-B Z
-C Y
-A Y
-A X
-A X
-B Y
-C Z
-B Z
-A Y
-A X
-A Y
-A X
-A Y
-A Y
-A X
-A Y
-A Z
-B X
-A X
-C Y
-A X
-C X
-A Y
-A X
-A Y
-A X
-A X
-B X
-A X
-B Z
-A X
-C Z
-B Z
-B Z
-A Y
-B X
-B X
-A Y
-B X
-B X
-C Y
-A Y
-C X
-C Y
-B Z
-A Y
-A X
-C Z
-A X
-B X
-B X
-C X
-A Y
-B Z
-A X
-A Z
-C Y
-B Z
-C Y
-C X
-A Y
-B Z
-B Z
-A Y
-B Z
-B X
-B X
-B Z
-B Z
-B X
-C Y
-A Y
-A X
-A Y
-B Z
-A X
-A Y
-A Y
-A X
-B X
-A Z
-A X
-B Z
-B Z
-A X
-C Y
-C Y
-A Y
-A X
-A Y
-B Z
-B Z
-B X
-B Z
-A Y
-A X
-A Y
-A Y
-A Z
-C Y
-C Z
-A X
-C Y
-A Y
-A X
-A Y
-A X
-C Z
-B Z
-B X
-B X
-B X
-A X
-A Z
-B Z
-B X
-B X
-B X
-B X
-B X
-B X
-C Y
-B X
-B Z
-A X
-C Z
-A X
-C X
-B Z
-A X
-A Y
-A Z
-C Y
-B X
-C Z
-C Y
-A Z
-B Z
-C X
-A Y
-A Z
-B Z
-B Z
-B Z
-A X
-A X
-B X
-A X
-B Y
-A X
-A Y
-A Y
-B X
-A Y
-B X
-C Z
-A Y
-C Y
-A X
-A X
-A Y
-B X
-B Z
-A X
-B X
-B Z
-A Z
-C Z
-B Z
-B X
-A X
-A X
-B X
-A X
-A Y
-A Y
-B Z
-A X
-A X
-A X
-A X
-C Y
-C Z
-A X
-A Z
-A X
-B X
-A X
-B X
-B Z
-B X
-C Y
-B Z
-A X
-B Z
-A Z
-A Y
-A Y
-A X
-A X
-B Z
-A Y
-A X
-A X
-B X
-B Z
-A Z
-A Y
-A Z
-B X
-B Z
-A Y
-A Z
-B X
-B Z
-A Z
-A Y
-A Y
-A X
-A Y
-B Z
-A Y
-A X
-A X
-A Y
-A Y
-A X
-A Y
-A X
-C Y
-B Z
-B X
-B X
-B X
-A X
-B Z
-B Z
-A X
-B Z
-A Y
-C Y
-C Y
-A Y
-B Z
-B X
-C Y
-A X
-A Z
-B X
-B X
-C Y
-A X
-B X
-B Z
-A Y
-A X
-B Z
-A Y
-A Y
-C Z
-B Z
-B Z
-A X
-C Y
-A Y
-A Z
-A Y
-C Z
-B Z
-A Y
-C Z
-B X
-A X
-B X
-B X
-C Y
-A Y
-B Y
-A Y
-B X
-A Y
-A Y
-B X
-B Z
-B X
-A Y
-A Y
-C Y
-C Y
-A Y
-C Y
-A Z
-B Z
-A X
-A Z
-A X
-C Y
-A X
-A X
-B Z
-B Z
-C X
-C Z
-A X
-C Y
-B Z
-B X
-A Y
-A X
-A X
-B Z
-A X
-A Y
-A Y
-A X
-B Z
-C Y
-A Y
-B Z
-A Y
-B X
-A X
-A X
-A Y
-A X
-B Z
-C Y
-A X
-A Z
-B Z
-B Z
-A X
-C Y
-A X
-B X
-A X
-A X
-B X
-A X
-B X
-B Z
-C X
-A X
-C Y
-C Y
-B Z
-B X
-C Y
-C X
-A Y
-B X
-A Y
-B Z
-C Z
-B Z
-A Y
-A X
-B X
-A Y
-A Y
-B Z
-B X
-A Y
-B X
-A X
-A Y
-B Z
-B X
-A Z
-A Y
-A X
-A Y
-B Z
-B Z
-A X
-A X
-B Z
-A X
-B Z
-A X
-B X
-B X
-A X
-A Y
-B X
-A Y
-A X
-B X
-B X
-B Z
-B X
-C X
-B Z
-A Y
-B Z
-A Y
-A X
-B Z
-B X
-B X
-C Y
-B X
-B X
-A X
-A Y
-A X
-B X
-C Y
-C Z
-A X
-B X
-B X
-A Y
-B Z
-B X
-A X
-B X
-C X
-A Y
-A Y
-A Y
-A Y
-C Y
-A Y
-A Y
-A X
-A Y
-B X
-A Z
-A X
-B Z
-B X
-A X
-B Z
-A X
-B Y
-A X
-A X
-A Y
-B X
-B Z
-C X
-A X
-A X
-A Y
-B Z
-C Z
-A X
-C X
-B Z
-B X
-B X
-B Z
-B Z
-A X
-C Z
-A Y
-A Y
-B Y
-B X
-B Y
-A X
-B Z
-B Z
-A X
-B X
-A X
-B Z
-A Y
-A Y
-B Y
-A Y
-A Y
-A X
-B X
-A X
-B Z
-B X
-B Z
-C Y
-A X
-B Z
-A Z
-A X
-A X
-B X
-B X
-A Z
-B X
-A X
-A Z
-A X
-A Z
-A Y
-A X
-A Z
-C X
-A X
-C Y
-B X
-A X
-B X
-B X
-B X
-B X
-A X
-B Z
-B X
-A Y
-B X
-A Y
-B X
-B X
-A Y
-B X
-A X
-A X
-A Y
-B Z
-A Z
-B Z
-C Y
-B Y
-A Y
-A X
-A Y
-B Z
-A X
-B Z
-B X
-A X
-B X
-B X
-A X
-A Y
-B Z
-C Y
-A Y
-B Z
-C Y
-A X
-C Y
-A Y
-A Y
-A X
-C Y
-A Y
-B Z
-B Z
-A Y
-B Z
-B Z
-C Y
-A X
-A Y
-A Y
-C Z
-A X
-A Y
-A Y
-A X
-A Y
-B X
-A Y
-A X
-A Y
-B X
-B X
-A Y
-A Y
-A Y
-C Y
-A X
-A X
-B X
-A X
-B Z
-C Y
-B Z
-C Y
-A X
-C Z
-C Y
-B Z
-B Z
-A Y
-B X
-B Z
-C Y
-A X
-A X
-A X
-B Z
-A X
-A Y
-B Z
-C X
-C Y
-A Z
-A X
-C Y
-A X
-A X
-B X
-C Y
-A Z
-B Z
-C Y
-B X
-A X
-A X
-C Y
-A X
-A X
-B Z
-B X
-A X
-A Y
-B X
-A X
-A X
-B X
-C Y
-A Y
-C Z
-A Y
-B Z
-A X
-B X
-B X
-A X
-B X
-B Y
-A X
-C Y
-A Y
-A Y
-B X
-A Y
-B X
-B Z
-A Y
-C Y
-C Y
-A Z
-B Z
-C Z
-B Z
-A Z
-A Y
-B Z
-A X
-B Z
-A Z
-A Z
-B Z
-B X
-A X
-A X
-A X
-B X
-A X
-A Y
-C Y
-B Z
-B X
-A X
-B Z
-B X
-B Z
-A X
-B X
-B X
-B X
-A X
-B Z
-A X
-B X
-A X
-C Y
-A X
-A Y
-A Z
-C Y
-A Y
-A X
-C Y
-B Z
-A X
-B Z
-A X
-B Z
-B Z
-B Z
-C Z
-A Z
-B X
-A X
-A Y
-A Y
-A Y
-B X
-C X
-A Y
-A X
-A X
-C Y
-A Y
-A Y
-B X
-C Y
-A Z
-A Z
-A Y
-C Y
-B Z
-A Z
-C Y
-A X
-B X
-B X
-B X
-A X
-C Z
-C Y
-B Z
-B Z
-A X
-A Y
-A Y
-A Y
-C X
-A Z
-A X
-B X
-B Z
-B Z
-A Z
-A X
-B Z
-A Y
-B Z
-C X
-A X
-B Z
-A Y
-A Y
-A X
-B Z
-B X
-A Y
-A Y
-A Y
-A Y
-A Z
-A X
-C Y
-A Y
-A Y
-C Y
-C Y
-B X
-C Y
-A X
-A Z
-B Z
-C Y
-A Z
-A X
-A Z
-A Y
-C Y
-B Z
-A Y
-B X
-B X
-A X
-B X
-A Z
-B Z
-A X
-A X
-C Y
-A Y
-C Y
-C Y
-B X
-A X
-C X
-B X
-B Z
-A Y
-B Z
-A X
-A Y
-B Z
-B Z
-A Y
-A Y
-A X
-A Y
-B Z
-A Y
-B Z
-C Y
-C Y
-B X
-A Z
-A Y
-C Y
-B X
-B Z
-B X
-C Y
-B Z
-A Y
-A Y
-B Z
-A X
-A Z
-B X
-A X
-C Z
-B X
-B X
-B X
-C X
-C Y
-B X
-A Y
-B Z
-B X
-B Z
-C Y
-A X
-A Y
-A X
-B X
-B Z
-B Z
-A X
-B X
-C Y
-C X
-B Z
-A X
-A Y
-B Z
-B Y
-C Y
-C Y
-A Y
-B Z
-B X
-A Y
-B Z
-A X
-A X
-A Y
-B Z
-A Y
-A X
-B Z
-A X
-C Y
-A Y
-C Z
-B Z
-C Y
-B Z
-A Y
-C Y
-A X
-A Y
-B X
-B Z
-B X
-B Z
-A Y
-B X
-A Y
-B Z
-A Y
-C Y
-A X
-A X
-B Y
-B X
-A Y
-C X
-B Z
-B Z
-A Y
-C Z
-B X
-A X
-A Y
-B Z
-C Y
-C Y
-A Y
-A Y
-B X
-C Y
-A Y
-B X
-A X
-B X
-B Z
-A X
-A Y
-B X
-B X
-C Y
-B Z
-A X
-A X
-B X
-C Y
-B X
-A X
-A X
-B X
-C Z
-C Y
-B X
-B Z
-A X
-A Y
-C Y
-A Y
-A Y
-A Y
-B X
-C Y
-B X
-A X
-A Y
-A X
-B Z
-A Z
-B X
-A Y
-B X
-A Y
-B X
-B Z
-A X
-A X
-B Z
-A X
-A Y
-B X
-B Z
-B Z
-A X
-A Y
-A Y
-A X
-A X
-B X
-B Z
-B Y
-A X
-C Y
-A Y
-C Y
-A Y
-C X
-B X
-B X
-A X
-B Z
-A X
-C Y
-B X
-C Y
-A Z
-A X
-B Y
-A X
-B X
-B Z
-A Z
-C Y
-A Y
-A Z
-A Y
-A X
-A Y
-A X
-A X
-A X
-B Z
-B Z
-C X
-A X
-C Z
-B X
-C X
-B Z
-B Z
-A Z
-B X
-A Y
-B Z
-C Y
-A X
-C Y
-C Y
-B Z
-A Y
-A Z
-A X
-B Z
-B X
-C Z
-C Y
-A Y
-C Z
-B X
-B Z
-C Y
-A X
-B X
-C Z
-B Z
-C Y
-B X
-A Y
-B Z
-C X
-A Z
-A X
-A Y
-B Z
-B X
-B X
-B X
-B Z
-A Z
-A X
-B Z
-B X
-A X
-A Y
-B X
-B X
-A X
-A X
-B X
-B X
-C Y
-A Y
-C Z
-A Y
-A Y
-A X
-B X
-B Z
-A X
-B X
-A X
-B X
-A Y
-C Y
-A Y
-A X
-A Y
-A Y
-C Y
-B Z
-B Z
-A X
-A Y
-C Y
-C Y
-A Y
-A X
-A X
-C Y
-B X
-A Y
-B Z
-C Y
-B Z
-A X
-A Y
-A X
-B Z
-B X
-C Y
-A Z
-C X
-A Y
-A X
-C X
-B X
-A Y
-A X
-B Z
-B Z
-C Y
-A X
-A Y
-A Y
-B X
-A Y
-A X
-C Y
-B X
-C Y
-A Y
-A Y
-A Y
-A X
-A X
-C Z
-B X
-C Y
-B Z
-A X
-A Y
-B Z
-C X
-B Z
-A X
-C Y
-C Y
-B Z
-B Z
-A X
-C Y
-C Y
-A Y
-C Y
-B X
-B Z
-A Z
-C Y
-A Z
-B Z
-A X
-B Z
-A X
-B Z
-A X
-A Y
-A X
-B Z
-A X
-A X
-A X
-B X
-C Y
-B Z
-B X
-A Y
-A Y
-C Y
-B X
-A Z
-C Z
-C Y
-A X
-A Y
-A X
-B Z
-A X
-B X
-C Z
-A Y
-C Y
-A Y
-A Y
-A Z
-C Y
-A X
-B Z
-C X
-B Z
-A X
-B Z
-B Y
-A X
-C Y
-A X
-A Y
-B X
-A X
-B X
-A Z
-A X
-A Y
-B X
-A Y
-B X
-C Y
-B X
-A X
-A Y
-A X
-B X
-A X
-A X
-B X
-A Y
-B Y
-A X
-A Y
-A X
-B X
-C Y
-A X
-A Z
-C Y
-A Y
-C Z
-B X
-B Z
-A Y
-C X
-A Z
-A X
-A X
-B Z
-C X
-A Y
-A X
-A Y
-A X
-A Y
-B Z
-B X
-A Z
-B Z
-A X
-A Y
-B X
-B Z
-A Y
-B Z
-C X
-B Z
-A X
-B X
-B X
-C Y
-B X
-B Z
-A X
-A Y
-B X
-A X
-B Z
-A X
-B Z
-B Z
-C Y
-C Y
-B Z
-B X
-B Z
-A X
-A Y
-A X
-B X
-B Z
-A Y
-B Z
-B X
-B X
-B X
-B Z
-B Z
-A X
-B X
-A Y
-B X
-C Y
-B Z
-B Z
-B X
-C Z
-A Y
-A Y
-B Z
-B Z
-A X
-C Z
-A Y
-A X
-A Y
-B X
-C Y
-A Y
-A X
-B X
-B Z
-A Z
-A Y
-A Z
-A X
-B X
-B X
-A Z
-A Y
-A X
-C Y
-A Y
-A X
-C Z
-A X
-B X
-A Y
-B X
-A Y
-A X
-A Y
-B Z
-B Z
-C Z
-A Y
-A X
-A Y
-A Z
-A Y
-B Z
-B X
-A X
-A X
-B Z
-C X
-A Y
-A X
-A Z
-C X
-C Y
-B Z
-A Y
-B X
-B Z
-C X
-B Z
-A Y
-B Z
-B X
-A Y
-B Z
-B X
-A X
-B Z
-C Y
-A Y
-B Z
-A X
-A X
-B Z
-A X
-C Y
-A X
-B X
-B Z
-B Z
-C Y
-A X
-B Z
-C Z
-B Z
-A Y
-B Z
-B Z
-A X
-B Z
-A X
-B X
-A X
-A X
-B Z
-B X
-A Z
-B Z
-B X
-B Z
-B Z
-B Z
-B Z
-A Y
-B X
-A Y
-B Z
-B X
-A X
-A X
-B X
-B Z
-C Y
-B Z
-B X
-B Z
-B Z
-C Y
-A X
-A Y
-B X
-A Z
-B Z
-B Z
-A X
-A Y
-B Z
-A Z
-A Y
-A Y
-A Y
-A Y
-C Y
-B Z
-A X
-A X
-B X
-A Y
-A Y
-A Y
-A Y
-B X
-B Z
-C Y
-B X
-B X
-A Y
-B X
-B Z
-A X
-C X
-B X
-B X
-B X
-C X
-C Y
-A Y
-A X
-B X
-A Z
-C X
-B Z
-C Y
-B Z
-A Y
-A X
-B X
-B X
-B X
-A Y
-B Z
-A Z
-C Y
-A Y
-A X
-B Z
-C Y
-A Y
-A X
-B Z
-C Z
-C Y
-A Y
-A X
-A Y
-C Z
-A Y
-B Z
-B X
-B X
-C Z
-C Y
-C Y
-C Z
-A Y
-A Z
-C Z
-A Y
-A X
-B Z
-A Y
-A X
-C X
-A Y
-C Y
-C Y
-A X
-B Z
-B X
-A Z
-B X
-A X
-C Y
-A Z
-B Z
-A X
-B Z
-B X
-A Y
-C Z
-B X
-B Z
-C Y
-A X
-B Z
-B X
-A Y
-B X
-C X
-A X
-A X
-A X
-A X
-C Y
-C Y
-A X
-B Z
-A X
-A Y
-A Y
-A X
-A X
-B Z
-B Z
-B X
-A Y
-A Z
-C Y
-A X
-A Y
-B Z
-B X
-B X
-A X
-C X
-B Z
-C Y
-C Z
-B Z
-A X
-B Z
-A Y
-A Y
-A Y
-C Z
-C Y
-A Y
-C Y
-A Y
-A X
-B Z
-B Z
-B X
-C Z
-A Y
-C X
-B Z
-A Y
-B Z
-B X
-B Z
-A X
-A X
-B Z
-C Y
-A Z
-A Y
-B Z
-A Z
-C Y
-B Z
-B X
-A X
-B Z
-B X
-A X
-B Z
-A X
-A X
-B Z
-C Y
-B Z
-A Y
-A X
-A X
-C X
-A Y
-B Z
-C Y
-A Z
-C Y
-B X
-A X
-B X
-C X
-C Y
-B Z
-B Z
-A Y
-A Z
-B X
-B Z
-A Y
-B X
-A Z
-C Y
-C Z
-C Y
-A Y
-C X
-C X
-B X
-A Y
-A X
-B X
-B X
-C Z
-C X
-A X
-A Y
-B X
-B X
-C X
-B Z
-A Y
-B Z
-A Y
-B Z
-C Y
-A X
-A X
-B X
-B Z
-A X
-C Y
-B X
-A X
-A X
-A Y
-A Z
-B X
-A X
-A Y
-A Z
-B X
-B Z
-A Y
-A X
-B X
-A Y
-C Y
-B Y
-B X
-A Y
-C X
-A Z
-C Y
-A X
-B Y
-C Y
-B Z
-B X
-C Y
-C Y
-A Y
-A X
-C X
-A Z
-A X
-B Z
-B X
-B X
-A X
-B X
-A Z
-B X
-B Z
-B Z
-C Z
-B Z
-B X
-C Y
-C Y
-A Z
-C X
-A X
-B Z
-A X
-C Y
-A X
-A Z
-B Z
-B Z
-A X
-B Z
-B Z
-A X
-C Z
-B Z
-B X
-C X
-A Z
-B X
-A Y
-B Z
-C Y
-B Z
-B X
-A Y
-B Z
-A X
-B Z
-A Y
-A Z
-B Z
-C Y
-C Y
-A Z
-B Z
-B X
-B X
-C Z
-B Z
-B Z
-A X
-A X
-A X
-A X
-A X
-C Y
-A X
-B Z
-A X
-B Z
-C Y
-C X
-A Y
-B X
-A X
-B Z
-A Y
-B Z
-C X
-A Y
-B X
-A X
-A Y
-B Z
-A Y
-A X
-B X
-B Y
-B X
-B Z
-B X
-A X
-B X
-B X
-B X
-A Z
-A X
-A X
-B X
-C Y
-A X
-A X
-B X
-B X
-B X
-B X
-A Y
-A X
-B Z
-A X
-A Y
-C Y
-B Z
-A Y
-A X
-C Z
-A X
-B Z
-A X
-B Z
-B Z
-B Z
-C Y
-B X
-C Y
-A Z
-B Z
-A X
-A Y
-A Y
-A Y
-B X
-B Z
-B Z
-A Y
-B Z
-B Z
-B X
-A X
-B Z
-A Y
-A Y
-A X
-B Z
-A X
-A X
-B Z
-C Y
-B X
-A X
-A X
-A Z
-C Y
-A X
-A X
-A X
-A X
-A X
-C Y
-A X
-A X
-B X
-B Z
-C Z
-B X
-A Y
-B Z
-A X
-A Y
-B Z
-B Z
-B Z
-B Z
-A X
-A Y
-A Y
-B X
-A Y
-A Z
-A Y
-B Y
-A X
-B Z
-A X
-A Y
-C Y
-B Z
-C Y
-A X
-B Z
-A X
-A Z
-B Z
-A X
-B X
-C Y
-A Y
-A X
-A Z
-C Y
-A Z
-A Y
-A Y
-A X
-A X
-A Z
-A Y
-B X
-B Z
-B X
-B X
-C Y
-A Y
-A X
-B X
-A X
-B Z
-A X
-A X
-B Z
-C Y
-B X
-C Y
-A X
-B X
-C Z
-A Y
-B X
-A Y
-A Y
-B X
-C Y
-B Z
-A Y
-B Z
-A Y
-A X
-A X
-A Y
-B Z
-B Z
-A Y
-A X
-A X
-B X
-B Z
-A Y
-A X
-C Y
-B X
-C Y
-B Z
-B Z
-B X
-A Y
-B X
-B X
-A X
-C Y
-C Y
-B Z
-B X
-A Y
-C Z
-C Z
-C Y
-A Y
-B X
-B Z
-B X
-A X
-B Z
-A Y
-A X
-C Y
-B X
-A Y
-B X
-A Z
-A X
-A Y
-C Y
-A Y
-A X
-C Y
-C Z
-B X
-A Y
-C Y
-B Z
-B X
-A X
-B X
-B X
-C Y
-B Z
-B Z
-B Z
-B X
-B Z
-A Y
-B Y
-B Z
-A Z
-A X
-B Y
-A X
-B Z
-A Y
-B X
-A X
-B Z
-C Z
-B Z
-B Z
-A Y
-B X
-A X
-A X
-B X
-A Y
-A Y
-A X
-C Z
-C X
-A Y
-C X
-A Y
-A Z
-C Y
-A X
-B X
-A X
-B Z
-A X
-C Y
-A Y
-B X
-C Y
-A Y
-A Z
-A Y
-A Y
-B Z
-C Z
-C Y
-A Y
-A Y
-B X
-C X
-A Z
-B Z
-C Y
-A X
-A Y
-B X
-A Y
-C Y
-C Z
-A Y
-B Z
-A X
-C Y
-B X
-B Z
-A X
-C Z
-B Z
-C Y
-C Z
-B Z
-A X
-A Y
-A X
-B X
-B Z
-B X
-A X
-B Z
-C Y
-A Z
-A Z
-C Y
-A Y
-C X
-B X
-A X
-A X
-B X
-B Z
-B X
-B Z
-B Z
-A X
-B X
-A X
-A Y
-A X
-C Z
-B Z
-A X
-C Z
-C Z
-C X
-A X
-B X
-A X
-A Z
-A X
-C Z
-A X
-A X
-B Z
-C Z
-C Y
-B Z
-B X
-A X
-A X
-A Y
-B Z
-A Y
-B X
-B Z
-C X
-B Z
-B X
-A Y
-B Z
-A Z
-A Y
-A Y
-B X
-C X
-A Y
-A Y
-C Y
-C Y
-B Z
-B X
-C Y
-C Y
-A Y
-A X
-A Y
-A Y
-B Z
-B X
-A X
-B X
-B Z
-B Z
-A Z
-A Z
-A Z
-A X
-B X
-B Z
-A X
-B Z
-A X
-B Z
-B X
-C Z
-B Y
-A Y
-A Y
-A X
-A X
-B Z
-B Z
-B X
-A Y
-C Y
-B X
-A X
-B X
-C Y
-A X
-A X
-B X
-C Y
-C Y
-C X
-B X
-C Y
-A Z
-B Z
-B Z
-B Z
-B X
-B Z
-A X
-B X
-A X
-A Y
-C Z
-B X
 ";
 }
@@ -112,20 +112,49 @@ group. What is the sum of the priorities of those item types?
 
  */
 
-#[cfg(test)]
-mod test {
-    use std::collections::{HashMap, HashSet};
-
-    fn part_1(input: &str) -> usize {
-        let priorities: HashMap<char, usize> = HashMap::from_iter(
-            "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
-                .chars()
-                .enumerate()
-                .map(|(i, c)| (c, i + 1)),
-        );
+use common::{random::Rng, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn priorities() -> HashMap<char, usize> {
+    HashMap::from_iter(LETTERS.chars().enumerate().map(|(i, c)| (c, i + 1)))
+}
+
+/// `len` items in a random order, made up of every item in `must` and the
+/// rest picked from `pool`.
+fn compartment(rng: &mut Rng, len: usize, must: &[char], pool: &[char]) -> String {
+    let mut items = must.to_vec();
+    while items.len() < len {
+        items.push(*rng.pick(if pool.is_empty() { must } else { pool }));
+    }
+    rng.shuffle(&mut items);
+    items.into_iter().collect()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Contents of each rucksack.
+    type Parsed<'a> = Vec<&'a str>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
         input
             .trim()
             .lines()
+            .map(|line| match line.find(|c: char| !c.is_ascii_alphabetic()) {
+                Some(i) => Err(ParseError::at(&line[i..], "a letter")),
+                None => Ok(line),
+            })
+            .collect()
+    }
+
+    fn part_1(rucksacks: &Vec<&str>) -> usize {
+        let priorities = priorities();
+        rucksacks
+            .iter()
             .map(|line| {
                 let half = line.len() / 2;
                 let l = &line[..half];
@@ -134,19 +163,16 @@ mod test {
                     .intersection(&HashSet::from_iter(r.chars()))
                     .next()
                 {
-                    Some(letter) => priorities.get(letter).unwrap().clone(),
+                    Some(letter) => *priorities.get(letter).unwrap(),
                     None => 0usize,
                 }
             })
             .sum()
     }
 
-    fn part_2(input: &str) -> usize {
-        const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
-        let input: Vec<_> = input.trim().lines().collect();
-        let priorities: HashMap<char, usize> =
-            HashMap::from_iter(LETTERS.chars().enumerate().map(|(i, c)| (c, i + 1)));
-        input
+    fn part_2(rucksacks: &Vec<&str>) -> usize {
+        let priorities = priorities();
+        rucksacks
             .chunks(3)
             .map(|chunk| {
                 match chunk
@@ -154,29 +180,71 @@ mod test {
                     .fold(HashSet::<char>::from_iter(LETTERS.chars()), |set, line| {
                         HashSet::<char>::from_iter(
                             set.intersection(&HashSet::<char>::from_iter(line.chars()))
-                                .map(|c| *c),
+                                .copied(),
                         )
                     })
                     .iter()
                     .next()
                 {
-                    Some(badge) => priorities.get(badge).unwrap().clone(),
+                    Some(badge) => *priorities.get(badge).unwrap(),
                     None => 0usize,
                 }
             })
             .sum()
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let letters: Vec<char> = LETTERS.chars().collect();
+        let mut rucksacks = Vec::new();
+        for _ in 0..size.max(1) {
+            // Every letter but the badge is left out of one rucksack of the
+            // group, so the badge is the only letter all three have.
+            let badge = *rng.pick(&letters);
+            let left_out: Vec<usize> = letters.iter().map(|_| rng.range(0..3)).collect();
+            for elf in 0..3 {
+                let mut carried: Vec<char> = letters
+                    .iter()
+                    .zip(&left_out)
+                    .filter(|&(&c, &out)| c != badge && out != elf)
+                    .map(|(&c, _)| c)
+                    .collect();
+                rng.shuffle(&mut carried);
+                // One item goes in both compartments, and the rest are split
+                // between them so that nothing else does.
+                let shared = carried.pop().unwrap();
+                let (left, right) = carried.split_at(carried.len() / 2);
+                let (left_must, right_must) = if rng.chance(0.5) {
+                    (vec![shared, badge], vec![shared])
+                } else {
+                    (vec![shared], vec![shared, badge])
+                };
+                let len = rng.range(4..=16);
+                let left = compartment(rng, len, &left_must, left);
+                rucksacks.push(left + &compartment(rng, len, &right_must, right));
+            }
+        }
+        rucksacks.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 157);
-        assert_eq!(part_1(INPUT), 8233);
+        assert_eq!(Solver::solve_1(EXAMPLE), 157);
+        if let Some(input) = common::input::for_test(2022, 3) {
+            assert_eq!(Solver::solve_1(&input), 8233);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 70);
-        assert_eq!(part_2(INPUT), 2821);
+        assert_eq!(Solver::solve_2(EXAMPLE), 70);
+        if let Some(input) = common::input::for_test(2022, 3) {
+            assert_eq!(Solver::solve_2(&input), 2821);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -186,307 +254,4 @@ PmmdzqPrVvPwwTWBwg
 wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
 ttgJtRGJQctTZtZT
 CrZsJsPPZsGzwwsLwLmpwMDw";
-
-    const INPUT: &str = "
-GGVGlqWFgVfFqqVZGFlblJPMsDbbMrDMpDsJRn
-LwzHtwdLHHwDrzPZzzsJbJ
-wdLTBvSvHvZVGCjhfN
-HsSSnZVHjjssZnJpSJjBHHWgQGcgqqQLQdQFqNgWgqGNDg
-rmmRwrtfThtTrbCrGGGcLBDTqDBNQLdL
-mwPrrbzPfwvbzhwMMnnjHnBjZlnzMM
-gjjdMBgdqdTpJpBcjgRRRlqnvrqfnZtrtZDw
-zHShWLhCszCWHVbVzQWCPtQvNZRwtfftfNnrnftlfR
-PzPSssHbVhCLFMJFcMFJJMjdJw
-ZqJdtpfpjmpJjpnwWdttTCDLLTQFNTzTzrcqrQqc
-MsSlBGvBsSGGSlbGsCgggNTgzNLczFQNrNQVQcFzFF
-sGHHSGllhvMGhSRGCjtjtjnjnnmHWpWWtJ
-tMdjQlHPHsGjsCtsCpwwqfhfnnFMDMrpfD
-SbNvWvBRJRWwFSgppgSrfg
-RNcNbvzJRcVLRVzTRFLjdHCQttdZdPlHstPl
-QWqgpdBflpHNCNWNHHPm
-VVMbbJsLFVMhrMJMmRjFNHwHjjCTGSSRFj
-mbMsZzsLmVhJZrcLcJhLMtnqvBnZdggplDffvlnlvnDn
-prnNnsFnZpnBNdNtFrNnzNQQwTTQZqTHTQJQMwHDMDlZ
-jgfgcSmbLmhmcPShghRdmwJTQjTlqGlJQJHqQqGHqQ
-hRVhPfbCgbVggLVRSSmRhRPhrrrnCzzsvCvrnvFnNppsvBtd
-QJLNDWSWQdLFFFhLdt
-npHhHMsfsjpZjznRtmrMCdBwFBFrBdmt
-HsjHqRRfnnHRsgfHffZspgzqDGQSWbQTDNGhQhSqNPhDWWbT
-bsCmFDsGZCNsDmLDLZBSHSJTHnrZQMQSSQ
-jqRpwvfqnnRQrftdBMHddB
-phpchwpzjpvwRzwcsnlFsssPCCGzDlsD
-rMqzVQfrfVZWZhTdRTQL
-cgmtFtjFFJDDtFvSFRZdLlhpHZddmwTZWh
-FbcSTtctcvFTJNgtJDGNPnCqMPMfMBfznGVsrMCq
-wLJfGJJPZLBfwSLGHbqmhhDHHhFDzfhv
-FsnpFjVjplTQCspNlCDbzhMMbqvMvsgmHDqb
-lRdlTdTddllpRQFRltVVdFRcwrtrcWWcPrrWPrPSrZWLPc
-VGVZhTppGTfPnJVJrFqbsmbSSshHqWqRHF
-llzDCzlBLdNcCddlMMNBdCCtWHbFqFRRRsHjWtRwSWqbmjWm
-NbcMBBvzzMQLCDBVTQQPVrPQPPZVPp
-cdcgfmQdqlqhzzPjzfwpwf
-GLBGBDvbvRzGwtnnmPpp
-ZRCZBRFSRvLRLFvvbLLFQdHMTHTlQlNqNmqFlWdH
-vzjzvHtcHvJcDStLLGSShCbbfF
-MWFFTVZRMmMgdQdSQLwQrQwbGw
-gFTmgmVZssRsWZRNzJlBHHnnJDvzNPlP
-rHrvHpmHZfdGDDGGZd
-cTlMsNhllMhGchNPCBlhMQgVDdgDSSWVbWVwRQwRSgbV
-lnBjnNNTTMnCTcChPNhMvtzvFGLtJrjFtHHHzHJm
-lgpdZZMmGVVzVZzt
-HfHLrHqbPbzJJzRJJPTl
-HsLWWbDqFrqlqfbsbDqDBncpgFmmvpnmmgpvdvjcdM
-GpNVbTpJJNvMBMVvJTGvhnWQQScllnhhWlhVSznV
-ZjswwHHLZzGnjWjSjl
-sHdftLLtgLfwdtPmHtMbNpMTpNqGRbPvTqPv
-sHSNNhNwsllGSGGlWSGWSsFrrVbQrdmFrVrrmnrHmrHr
-QQMRZDDRcrcnmRcV
-fJfCPMJCzTMZSGsWwsWBwqQf
-HwQZZJsHdqqsdJQGRgCgVGgSqgpcGG
-ljWWbnPhjBlGpCRCnScSGg
-hrrztWlbPjltjMPSdJDZSsHttwsZwD
-VzzbmzvpvNhvBDqc
-QHSJSQGCwJCGrGQjjcgcBFhdgqdqFdDNNw
-rCGJtZrHhhtLRsth
-TMWwCLPpMTThrvtMRJjbjRvmJs
-fDzcHFfSfFQfZzZRJbdmmqqssqtbSW
-WWgGZglcllgPBBCBNVGPNr
-wrwwhpTpbqhqrshrrfrFfwfzRJGdNJHNmcFzCCzCRJGzGR
-vMggvjQvgPvQjVLMMPSZqWNJGCzcNGdcdzHPPzcmCzPz
-qDZWvBZVfDhbTtrp
-LpDvHdjVghnjbGrn
-FBBBPwwlBlwSfFTWPHPWWhmgngmmnPnmbsngngbGrb
-FwftBSCSfWCtwfVQDvHHCMVvdQLQ
-ZrQpQlSpNlqQCVnQBmdDqmWDqmWWBDBB
-HsZMsJvZzLMHTRwWhgDwmfDBgdhWdf
-RZvTzJGzRjFrVNVjlQrS
-mqjMwfqlSSPmSrlPhwhVpGRcppWcpcGRcGWv
-ssJDJJNgZNDWrRWcRpvr
-ZTsTnnsLJQgPnfMwmnMrfm
-qsVBvZqWLdfbfvLj
-mPNRgmHBBGQrCbSbrdfCCSbC
-PlQTGcTTcgGFQQGPTGllpqMzwzpVJZwBMssZ
-FWGcNRLRLhwJJQfV
-nzbzlDBHSpTDbpDpzHwCqhqwJJghQqQMCCBw
-JnzndzpmJFmNsrrFsc
-gmRwwDwfnRDJgwZLFQFFNGNQrFBmFbbm
-CCVHVWWThSrjVGvbNj
-WpdqpplppHCWzlClMMTTZJcJsdscJLLdbDDfZDgL
-VNtCCMDllpBqDvtdCczTSgjHlzGSHSGZTZ
-hPFPsQhhFhLnbsRnLFssdzcHdsSHSSHgjzHG
-QPWPQrPPmbdnbWLFPrrBVrVDBqqNMVwttDtBvD
-PPNNRggwgRRgHBhDtwhTwbDs
-SFGSFSMCJFMrcrCMSSsbtrTbbZhBvtHhrTHD
-MFfSMpflQLQflfLjnLmddsLdddqq
-RcgbcrrFscVrwZVCgVGGmHppNNndWnGdNqddqqNqND
-jTlSTBSTjLTvlvjjPtvMLlhHnftphtDFNFqDnDHWNddn
-QBMQvzzjzvJPjFQMmwZJrgmCCJVRVbbc
-CzPJsWCpvsNszsJsNsHlDhMrrJGjhrRVhRGgDDjG
-tFFdbqFLFdwctQdfVhjRRghTcrjVRTDW
-bwQtFLdLBdFmwnHnWHPBNnHCnp
-CNTstGNslRRRstlmNmmTZZqfFwtqgwqgfBPSwSWwqgWq
-hpDbcHbpSrcgqqzhhWVfqg
-DDcLDjbMjCSsZRNlMv
-MhHMCMNbzbMHlcqmGmrmWc
-tnPggdZPBPgdtttJpdnwVBnmqQcvlQrQlfGqmfWffBcqWD
-VPPwPPLPwLGFGLzCbG
-rqBcBmjHTGfPbcVgPG
-dlDpsdshzlldlDvsWlWvLQbQBbfLFLbPvbBGQBgG
-BlBznnRWzlphphBnhZjZtNNCNmrNqjCqHwHN
-mQBvmvBmmLJvvrLtttQrfhGlcRGfRhVGWJVChlRG
-MzPswTsbTPPsNgMNszgzMpbMfcRcGflVGRfWSpFRlWWWFhcC
-bcPsTbgbbTTwNZzTZzvdjdjtQQndZvdrvdmZ
-hQzTQJFFZJrcdcdZFFrGFSVWVRWRwRgRHVMWDCDSWc
-lPmpNBnnnsNBnLnfbfnCDWMvwRvDCCMPwwHWvM
-HpjmffNlnqqhddTddFZjGJ
-BwsLFFbHLbVCSCSFbsbFLsJbqnTtZrRMHTZtrTrZTcRRRRTq
-lGhNhpPmmhpztZTBrcpjRqpB
-QPzdfzBQNgFJSCwsdLbS
-ZsZsSBTgffSCqSSfrMnnMwjqmqmnnnqwMm
-bbPPbzVclcPzGNlvzVtmnDBnQmtnQLBjJVLn
-zPFGplGGvdPbHplcbzzvdlNBTThgRpCTCTfhfsCCsSRZhR
-CVLSVCLVZRsHcnzSRpdZZRCdPlmcMWDDlPNqMqtDMmqPMlDt
-TBnGjfQrQJjhfWlPPmPQDNlmlP
-fjhhGvjvvrTTBhvTBTbvGVRLzVnbSRZpHddspHRzLs
-DDtWjfRfftWMLzSQjzzhWjjwRVPHqFbBbZHVwZBFvFwZvZ
-JGllgCJlJsrCGPrCNTPdslvZVVNVbvBqNbbpbbFHpBwZ
-CcPdnCdmCJjfcftWhtSL
-pgpfddDGHWzDNGNGpRCQjCTFHZZQFQjcRT
-bJlhqmMvnlrRQFtTthPVhZ
-lvbJrlJMBwfzGNTddB
-wpbJGGZpsjvtdWvGWF
-HqqhBhBqhhNQHTSHqqNzRHVPvTvddWrjtrjFvrvdTdVP
-NRLCRzlqHQtNRBLzQllhhZcgbggwmLDZpsgssDpwwD
-pDzFzJFcVMcWJFJFzpLBsqWLZssshsGLGbsS
-wqHqfvnfrRwQtdQRthhBbBbZLhPLnBTGsh
-CfQqlqvtfHNvMVmzmmMCFDMc
-GcgpNHvcSNvpSLphdhsLdQTsdWThhQ
-wwzttPrrhQswdhnT
-tjJjMJRbRbjztmjtjbgcRsNlgglHpDFSlSvg
-VVLvLqqPVlvcqLLdwLbHpzcHSsbRJppHbHpF
-CfjjCNGmMWhWjhWHWb
-ZmGZffggrDqZvZtlbTqL
-TTmmhvBvvHWzHpsPpstpLVdwwsLb
-qflfFgNctNcCnCCNDnfFFNDwrslwZbPswwZbJLJZbrlPLL
-FgQDDcncStCgtqccjSDTHWMThvhTQMBQhWvWRG
-SqhVghPccSBhgSBqWBFNQNsHQHMjCCQQWCwQHN
-fLZftnlttcbbtZbZlpZtttQjwsCQjRwwRDQspMRMNNQs
-TfLtvbJtZmlbTTTtlJbFvVqPSgBdPqPhFSGBcd
-pPPNDptcqtpcDztLDhhngnnJgJTmJwNnwm
-HVVCsSClHGBCHslWHbSCGGVngTrJwnnJnQRRBrhQhgJhdm
-WTWWWsvVlvGbWCFvjDftPpjqZLLtDz
-wWclwtDwRvflvffB
-sMMsGdsSTMrJZNqczfdvhvnzCnfv
-rspppMjMspSTSMpgLjcPFmwPLmPHwb
-tCdSMHtHtRFHdWSSJQSgrrrnghTNJN
-BGfcvDsfvsqcvqfGvfGnNLhggBNQJNJQmpgQJm
-sGfQDPDZzfDZzclwDzwsDlfjtbdHClFRCMWjbMFMRFWbdj
-pJNCcvqCccsVvFCpqsmvWJfCBWgSzBBRrrBRDDgDrSbbgQbQ
-TMLnLjjffwfwGdjQjDDBjBrDtztRSb
-MdPLGhHnMZhlPHHTFfZvVCpmmmcFcVFC
-SwFMfCMRCdQDdMbmdFfdbbnlcVncVCcgLqWcNNnCcWlW
-hPjQzzhGzhpPrtPJPpPHrVgnqVVncVVnNHlqVnncNB
-ptjGrptztpthtrtJJhTsGwFDZZDQmSdfZSwsRZSwfZ
-rSSWWCWrdllHWpjcnFNnRCNjQp
-bGwwJqGVGbGJVVhgbBgttGmBQjFsMjpMcMnnMBcQFNnsssvv
-bfthwmfJfgwwmmwZqVJPHNHSZHWzSlDPrdDdSH
-nmJccvclcbwmlbbvVbvsHwJJPCPNCNPnLBhrBPPLBhLhBgBP
-MdRGtdDRTqWDMqtMDtQDRWSdLLBsrhLgBCgrgCgNNLPBfNMf
-dRZQdDdRRSQWGsjZmwzjmlzsZH
-PBGGMrTQTrTBpPQpLpSlwjwfjtlnfwbmGttw
-fCsJCWJcvRCtwwjbCl
-NsqcsfcvDHFVDJvdLQTrpdTTzTPpHr
-rltrwsBTlrfGZggGBLGGNN
-jhMnRQJVphMnbhvQjDZNqqZDNTNHZVHGHq
-MRvbhQRQQChpvbjvMSvQnMcsFsfwwmlCwFwWcTWwrmPc
-DDvLLLBnvrzvbvbmtv
-TMwRjTRMGCwGGwrjQQnmrQrrQdhZ
-MJPFHFTwgCGqGqgJMGDfSWcsnBSccgVDlnpW
-flzVzNrdLNLJzrGlfdlzjrQDgFTpDgDmmmgFmqFDQjQh
-CbnBcsZnPZVSnwvVsZbRhhBDpgFphgmgDgTppq
-ZWnsWSnncSZsntZCbsswwJMzdLzlMdNMLtMVfrllMt
-ZffSgNfgJgGCHZcZrpHrNJTLhqvSLTqQnvVTLvzvLTjV
-tWFtHMwlBlDqjjzjnqvvlV
-DRMPDtWHPFDBFFwWMFBmFRPgZpJfsffNGJNrGcsprrsmfg
-wRZRmpZmlPqZjzGrdrGq
-bBhQQFPQbPDVNzVNzdGWNdrf
-QFbcDcDbLHgHBPDFRsSSMtmvRttMpCLS
-MpWJVVJMcWvpRShcwpLGflmqzSfNdfNLdQzN
-CDBTtCgtbjgCRrBrPBTQqzflNqjGdLzzmqffzq
-rFgnnBbttDTPtHCDPrPMnpwVJhJvMZvpMvppRZ
-sWTTmpsfsWppPTTsTVZWHVVZNvVcdcJvdN
-DjjBzjhRHvvvSzdc
-rMBjjrjbjrGDlgMlMrGjBgRLPTTwHMsfnFwFQPMPMnmFFm
-QRRbDjjmPzNQwFDNmrQmzCbVHrMhBVrJLJJfMGGLtfJBHh
-dsWcsqqWSWvnWnWcWGPJLBqhLBqGhBJHHH
-ZWnPWgWgPnlbCDDwmmDbRZ
-nfPqqfLqQnfHBSqnzztQjVmjfGRWJNGRWsJWJfmJ
-TTMlMMlFDMGVGRsVJH
-CbDbFDbvgTFFwgTDlDprhlPSqBzSnPdLPtPPHgznQqBQ
-fJmWVfHqjfjhZCQZ
-NcNzBNvgszQmzjnthZQC
-LsLsgBNFmFgTFgGBBgcdMdvPDPDJWrlpVbGpJWqHDlHJHD
-SllDdvzgdFDdlPJvbFDDSzFScPTRTNcwfZRwRhcwwNnRZTtf
-WBpWBCLGVpLjHrHGGVhZNwcTVcNhVnRcNZ
-LHLQLspHWQGpWCHnBvdzDJFlqvdsqgSgqS
-GcTctDMjMhpMDRjLsMMsfDWFfdPCFNbnCPnvCPgW
-JmvwqlBwnmfdFPFP
-SvZqHSZqqHZZZBlllBwSwsRsMHpLjpLsMGtsMspGRT
-ClLnCLfClLVllfLLcQjLBCfCmSHVsttsmtsVStDNVdppdsSp
-PFrRMbWqMRwFRqRSqwqvMvMsGtgsdmssrgNtdmpNdDGgdt
-bwJbPWPwFFPFSczCZzZZCcfjBJ
-MwmBmzwJQTcTmfPVfZPwhhwHPH
-jlnrglFLvbrGRFGnvFZdNNFfPZddPThVhdPH
-RjbjpgbnLGvpLgzBqBpmWmmzqTMS
-FnsSpttPnPbNCFDtsPnFHQZTQZgcwgDDTfrfTHMZ
-mRjzRzlvBvhjZrQmMMwfZZNN
-ldzddlzLRlRWdhjdRLjhRWtJbJbNtJJpJPbCbGCWNG
-wBwmNZBTmzzcVcmpzZqdMgPjnLSVlPgDPdbMdg
-flJvGtHffHDddddbHjnb
-RstrhfrhhRGFQtRhtftvQhvFZpsmpWwNlWqcWTccBNWswqNp
-DPWhbzDlQLLlQbLDlLhPhLFNNJqCFGqnNJCCSCnGPnGN
-wvwjtvtdwfssvSJgFFvGGSCFcp
-mtdrZwwJsrtddrHRtZWbVThLlBzVTzhHQWhB
-TsRRWctsTJMQZllggc
-zDvhpbprgGvpvVlVQlZpQMJVlQ
-rrrvFvGCDhDSrrrvChCgSstBNTSftWBjTdfWBN
-JJdssBcLVGrgbBHWrH
-QZTptvmvmlZpRDlMMMZCQvnjjFnrjWGFbjnrnFGWgZrz
-TMRplDMggtwlppTlvhsJJqdcqwVPSSNcLd
-JjTCCrcRvccPLmMP
-NfGFPZlNnwBfPlbbbQZGqLHgzLghSmMBzSgvzmDMhv
-ZfbnNQpQnZGlGlGpWTddjdTJdpRPTrCj
-gWLblMMggdWsdRJlblMRMMqWDvPvcPPPccJPJVTZVZThmcDP
-rQFfGfrCHrjnrtNTcPShTSPvvVLtmm
-fQrCfLrpLHnCHwslqzqsslswzqRW
-zpJtGlJPMPTlTjGJCDGCDljpdnvhhWnZnZnDwwmvnWDWWvdd
-sHrVrSrRRRLNgLVBqSsZmWwvwcvwZjmwngmdbn
-QsQQBrrLHTjPpTQzzP
-JDlzHHzzptRDmbTMrrVQ
-dRRNqnCBnmrQsVQQ
-wFPCBNFgwjPwhgFNztftpJRPpzRvvHtZ
-DlBhrDBPPwMWwhWchW
-ntSqbbSJFJNqzVzjCfMvfSlSRWccRL
-mVlHtNVtqldbJVmNHmdTTBBgrQQgGsPQdrDgsP
-HWHNbBgvNLdcvQMnSf
-wqqqVPDPhqwszFwrrszFfMdWthLcMdfhthSQfJSt
-qVPVwTzFwFDpDrPDzDPFDPlCHjBGjTmZGjbWWGZBRTNjjH
-GVgdWjllSqgjdgHqqlfmhwcpwCzhvZwMcScv
-nsJQbLRQsNnzQDQQPPBbRBRhfZwpZcvwpvvmLCcvpcmfMM
-DRJtnnRbBBnPztsrPzRBPbsFFHqqVrqggjFWqrgWjTGgFq
-hhZJQPJFHGGlcWWslpNN
-VwwwJjvwMtrCnwjDNDzlfscWszWW
-nVStCrMqbVwqVqSqwnLPhTJFdRgJHZSFRLTP
-vPgMbbRhhvMvNjjLWsWQsHQmHwBrmmBzww
-tFctDnVFpppHVBTdzdTQwl
-FtSFqSptfJCqqJStZCqDpDJMhvLLgLMgQgjgGZgGgMPLZg
-zwsWgSGWLSVhPWhtLgLWhPVNQTmDrDQttZpdQtdpQDDQZQ
-fjCHcvvjMDrppCQpVV
-VMqRnVJMVLPzbRWhGh
-mjRmzQlzDzNHWwDZ
-FBfJBGqnnpfSVGnpJbJVfNtCsJHWZvrsNJCZrCNsvN
-fZPBnfPqSBqdfpFbVnVSjgdcLLgRLjmgRhLLghlR
-FSFnTcppdQtnnDhtzDfg
-ZLGVmBLBVwZCVjjGqGhVwVVgzzbMDtNNvszMmMffNDDvtM
-VZPJjBZVqBZZBjqwVqllpSTphhQFPShWSQcW
-hTRdcLrCLgplLvBFGvlL
-nZDZqzbDbDzRZtVNDzDWGwslsllBFpnlpGvJssFG
-zbqjNWQVmVPrrRjRdRhS
-VpNCbVHlHHZfflVfmchctqFcqQQjZmZM
-WDSRGgsSvgJSRrnWgqQhmjBqmhqrtLqmQm
-znSGTgDJnsDGzgwCwlpbCNwHzVtl
-sTTTrpHFFFqTnQbbvfJdDzHHDLVV
-CjMtgMgRvbPfjjvB
-mhMvlhhWClvqshNTQQqsNN
-tWFtFBzbwdFrpmdhdm
-qTqDjJjJQQqMjTDLJjNqNqPNdmpcSmhdmhhmcrWZpdPGddcc
-RjNQLJNTTJDDJRHHjQqnMWtlvvVvbtBvRVzgzgwgVg
-CGdQjwdJrbBmpmZZZlRWcb
-NgtMPVstgSzBLzhgzgLgDRlcmDWRmlZvcSmDSvvp
-LhNsgPPLFPPsNzMhhVzPsGJBFqwQGfnqfQjdGdGfwr
-CNbNdbzjCZpPNzjmzjsCMRJvnnMRGnsvJGRs
-wrtdwTLWFcFWdFgwRRsnJGnGfTGJfMsq
-FttcwgBtgVLgPldQSNZBzBpz
-DjRZrrRmttRFDvDrFTZsnWnHVSTSSJVZJH
-dNNhLqlLLqdCzfMMlCfSncTVVWcHdcVsVdSVnT
-QqppMfzMfqWCwbRQrwFrrttQ
-dwGjHrtjsdhfCHnPSpfMfDPpPDWS
-lmNzzlLbFqcqNgzpWMSvbbvDQDGWDp
-LBmglgmqBqmrwCGhCjVtBC
-tvHgWZCCprlgpWglCtjPhLmPmhVdJFSzVzdJVmmQ
-fBnTTnNNBnwfnNqcBbBBTbGJQQJhSSdQJJsmdJFSQGSmVV
-cMcDwFbRfFRlHCRCZrrp
-ZFWmgghzBgwgjWBzjzmRWWMmsVwnVrsdVdwNrrpnnVrPCnCP
-GLLbtGqllctqvGJvSlQbJGsPnVdsdpsTPLsVppBCTVss
-tJBStGSvctvDDfczmRgRZjzDjZmgzH
-FMrLmsQQSWzCZBhpQJTQQZ
-dPPVncVvPBJDCPhwJD
-fvHbbVHvqnvvvBzgLbbGGmrbMr
-mrZzrzqDrhZqDddSFrCGLLLPQPQBJPJJBnQq
-TgbpGblWlMsjgWlgMfpNRgbRHHBnHHHtLpCJPCPBnBLJtQQL
-sbTlblTlvRbbGblbFcdDzccVcDVvzzzd
-zMzfzlGwSBMMSCMzhsPgfcPcfcbhjQPt
-FHHqJVdJmFmdVrJdJppthscjGtqRPRcccgcQbR
-rvNJJpLrvvLnJvNFFvZZZBWznBWGSDCMnCwz
-";
 }
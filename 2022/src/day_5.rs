@@ -149,49 +149,83 @@ crate ends up on top of each stack?
 
  */
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
+use common::{parse, random::Rng, ParseError, Solution};
+
+/// Starting stacks of crates, bottom first, and the rearrangement steps as
+/// (count, source, destination) with zero based stack indices.
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    steps: Vec<(usize, usize, usize)>,
+}
+
+pub struct Solver;
 
-    fn part_1(input: &str) -> String {
-        let input = input.lines().collect::<Vec<_>>();
+impl Solution for Solver {
+    type Parsed<'a> = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Procedure, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
         let (stacks, steps) = {
-            let mut sections = input
+            let mut sections = lines
                 .split(|line| line.trim().is_empty())
                 .filter(|s| !s.is_empty());
-            let stacks = sections.next().unwrap();
+            let stacks = sections
+                .next()
+                .ok_or_else(|| ParseError::at(input, "the stacks of crates"))?;
             let stacks = &stacks[..(stacks.len() - 1)];
-            (stacks, sections.next().unwrap())
+            let steps = sections
+                .next()
+                .ok_or_else(|| ParseError::after(input, "the rearrangement steps"))?;
+            (stacks, steps)
         };
-        let mut stacks = stacks
-            .iter()
-            .fold(Vec::<Vec<char>>::new(), |mut stacks, line| {
-                let mut si = 0usize;
-                let mut start = 0usize;
-                while start < line.len() {
-                    let col = &line[start..usize::min(line.len(), start + 4)].trim();
-                    if !col.is_empty() {
-                        if stacks.len() < si + 1 {
-                            stacks.resize(si + 1, Vec::new());
+        let mut crates = Vec::<Vec<char>>::new();
+        for line in stacks {
+            let mut si = 0usize;
+            let mut start = 0usize;
+            while start < line.len() {
+                let col = line[start..usize::min(line.len(), start + 4)].trim();
+                if !col.is_empty() {
+                    if crates.len() < si + 1 {
+                        crates.resize(si + 1, Vec::new());
+                    }
+                    match col.strip_prefix('[').and_then(|c| c.strip_suffix(']')) {
+                        Some(c) if c.chars().count() == 1 => {
+                            crates[si].insert(0, c.chars().next().unwrap_or_default())
                         }
-                        stacks[si].insert(0, col.chars().nth(1).unwrap());
+                        _ => return Err(ParseError::at(col, "a crate such as [A]")),
                     }
-                    si += 1;
-                    start += 4;
                 }
-                stacks
-            });
-        for line in steps {
-            let (num, mut src, mut dst) = line
-                .trim()
-                .split_whitespace()
-                .enumerate()
-                .filter_map(|(i, w)| if i % 2 == 1 { Some(w) } else { None })
-                .map(|nstr| nstr.parse::<usize>().unwrap())
-                .collect_tuple()
-                .unwrap();
-            src -= 1;
-            dst -= 1;
+                si += 1;
+                start += 4;
+            }
+        }
+        let steps = steps
+            .iter()
+            .map(|line| {
+                let rest = parse::strip_prefix(line, "move ")?;
+                let (num, rest) = parse::split_once(rest, " from ")?;
+                let (src, dst) = parse::split_once(rest, " to ")?;
+                let stack = |text: &str| match parse::number::<usize>(text)? {
+                    i if (1..=crates.len()).contains(&i) => Ok(i - 1),
+                    _ => Err(ParseError::at(
+                        text,
+                        format!("a stack from 1 to {}", crates.len()),
+                    )),
+                };
+                Ok((parse::number(num)?, stack(src)?, stack(dst)?))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Procedure {
+            stacks: crates,
+            steps,
+        })
+    }
+
+    fn part_1(procedure: &Procedure) -> String {
+        let mut stacks = procedure.stacks.clone();
+        for &(num, src, dst) in &procedure.steps {
             for _ in 0..num {
                 let take = stacks[src].pop().unwrap();
                 stacks[dst].push(take);
@@ -200,45 +234,9 @@ mod test {
         stacks.into_iter().map(|mut s| s.pop().unwrap()).collect()
     }
 
-    fn part_2(input: &str) -> String {
-        let input = input.lines().collect::<Vec<_>>();
-        let (stacks, steps) = {
-            let mut sections = input
-                .split(|line| line.trim().is_empty())
-                .filter(|s| !s.is_empty());
-            let stacks = sections.next().unwrap();
-            let stacks = &stacks[..(stacks.len() - 1)];
-            (stacks, sections.next().unwrap())
-        };
-        let mut stacks = stacks
-            .iter()
-            .fold(Vec::<Vec<char>>::new(), |mut stacks, line| {
-                let mut si = 0usize;
-                let mut start = 0usize;
-                while start < line.len() {
-                    let col = &line[start..usize::min(line.len(), start + 4)].trim();
-                    if !col.is_empty() {
-                        if stacks.len() < si + 1 {
-                            stacks.resize(si + 1, Vec::new());
-                        }
-                        stacks[si].insert(0, col.chars().nth(1).unwrap());
-                    }
-                    si += 1;
-                    start += 4;
-                }
-                stacks
-            });
-        for line in steps {
-            let (num, mut src, mut dst) = line
-                .trim()
-                .split_whitespace()
-                .enumerate()
-                .filter_map(|(i, w)| if i % 2 == 1 { Some(w) } else { None })
-                .map(|nstr| nstr.parse::<usize>().unwrap())
-                .collect_tuple()
-                .unwrap();
-            src -= 1;
-            dst -= 1;
+    fn part_2(procedure: &Procedure) -> String {
+        let mut stacks = procedure.stacks.clone();
+        for &(num, src, dst) in &procedure.steps {
             for _ in 0..num {
                 let take = stacks[src].pop().unwrap();
                 stacks[dst].push(take);
@@ -249,16 +247,68 @@ mod test {
         stacks.into_iter().map(|mut s| s.pop().unwrap()).collect()
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // At least two crates in every stack means there is always a stack
+        // with a crate to spare.
+        let height = size.clamp(2, 40);
+        let mut stacks: Vec<Vec<char>> = (0..rng.range(3..=9))
+            .map(|_| {
+                (0..rng.range(2..=height))
+                    .map(|_| rng.range(b'A'..=b'Z') as char)
+                    .collect()
+            })
+            .collect();
+        let top = stacks.iter().map(Vec::len).max().unwrap();
+        let mut lines: Vec<String> = (0..top)
+            .rev()
+            .map(|level| {
+                let row: Vec<String> = stacks
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(c) => format!("[{c}]"),
+                        None => "   ".to_string(),
+                    })
+                    .collect();
+                row.join(" ")
+            })
+            .collect();
+        let labels: Vec<String> = (1..=stacks.len()).map(|i| format!(" {i} ")).collect();
+        lines.push(labels.join(" "));
+        lines.push(String::new());
+        for _ in 0..size.max(1) {
+            // Leave a crate behind in every stack, so that each one has a
+            // crate on top at the end.
+            let sources: Vec<usize> = (0..stacks.len()).filter(|&i| stacks[i].len() > 1).collect();
+            let src = *rng.pick(&sources);
+            let dst = (src + rng.range(1..stacks.len())) % stacks.len();
+            let num = rng.range(1..stacks[src].len());
+            let at = stacks[src].len() - num;
+            let moved = stacks[src].split_off(at);
+            stacks[dst].extend(moved);
+            lines.push(format!("move {num} from {} to {}", src + 1, dst + 1));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), "CMZ");
-        assert_eq!(part_1(INPUT), "QNNTGTPFN");
+        assert_eq!(Solver::solve_1(EXAMPLE), "CMZ");
+        if let Some(input) = common::input::for_test(2022, 5) {
+            assert_eq!(Solver::solve_1(&input), "QNNTGTPFN");
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), "MCD");
-        assert_eq!(part_2(INPUT), "GGNPJBTTR");
+        assert_eq!(Solver::solve_2(EXAMPLE), "MCD");
+        if let Some(input) = common::input::for_test(2022, 5) {
+            assert_eq!(Solver::solve_2(&input), "GGNPJBTTR");
+        }
     }
 
     const EXAMPLE: &str = "
@@ -271,521 +321,5 @@ move 1 from 2 to 1
 move 3 from 1 to 3
 move 2 from 2 to 1
 move 1 from 1 to 2
-";
-
-    const INPUT: &str = "
-[N]     [Q]         [N]
-[R]     [F] [Q]     [G] [M]
-[J]     [Z] [T]     [R] [H] [J]
-[T] [H] [G] [R]     [B] [N] [T]
-[Z] [J] [J] [G] [F] [Z] [S] [M]
-[B] [N] [N] [N] [Q] [W] [L] [Q] [S]
-[D] [S] [R] [V] [T] [C] [C] [N] [G]
-[F] [R] [C] [F] [L] [Q] [F] [D] [P]
- 1   2   3   4   5   6   7   8   9
-
-move 3 from 9 to 4
-move 2 from 5 to 2
-move 8 from 1 to 9
-move 4 from 7 to 1
-move 5 from 3 to 8
-move 3 from 3 to 7
-move 11 from 8 to 3
-move 7 from 3 to 6
-move 2 from 5 to 9
-move 3 from 1 to 6
-move 6 from 2 to 4
-move 6 from 7 to 5
-move 1 from 6 to 1
-move 1 from 9 to 4
-move 16 from 4 to 9
-move 2 from 1 to 2
-move 1 from 4 to 6
-move 1 from 3 to 7
-move 2 from 2 to 4
-move 1 from 7 to 9
-move 22 from 9 to 8
-move 1 from 6 to 3
-move 18 from 8 to 5
-move 3 from 8 to 2
-move 3 from 2 to 9
-move 13 from 6 to 7
-move 1 from 6 to 7
-move 4 from 3 to 6
-move 2 from 6 to 3
-move 2 from 3 to 8
-move 3 from 7 to 8
-move 14 from 5 to 2
-move 3 from 2 to 5
-move 2 from 8 to 4
-move 4 from 8 to 6
-move 4 from 6 to 4
-move 11 from 5 to 2
-move 3 from 9 to 2
-move 7 from 2 to 3
-move 11 from 7 to 2
-move 1 from 5 to 7
-move 5 from 6 to 8
-move 30 from 2 to 7
-move 23 from 7 to 2
-move 4 from 3 to 4
-move 3 from 9 to 6
-move 4 from 8 to 2
-move 1 from 8 to 2
-move 2 from 7 to 9
-move 4 from 2 to 3
-move 1 from 5 to 9
-move 6 from 4 to 7
-move 5 from 3 to 6
-move 1 from 3 to 6
-move 1 from 9 to 2
-move 16 from 2 to 5
-move 7 from 7 to 6
-move 9 from 2 to 1
-move 2 from 1 to 4
-move 8 from 5 to 3
-move 5 from 7 to 4
-move 1 from 9 to 8
-move 9 from 3 to 6
-move 25 from 6 to 8
-move 2 from 9 to 5
-move 3 from 4 to 2
-move 7 from 4 to 1
-move 1 from 8 to 7
-move 6 from 5 to 2
-move 11 from 8 to 5
-move 1 from 7 to 9
-move 10 from 1 to 2
-move 6 from 5 to 1
-move 1 from 4 to 2
-move 13 from 8 to 1
-move 17 from 1 to 2
-move 5 from 1 to 9
-move 1 from 8 to 4
-move 1 from 1 to 3
-move 1 from 3 to 6
-move 1 from 9 to 3
-move 2 from 4 to 5
-move 1 from 4 to 8
-move 1 from 9 to 1
-move 8 from 5 to 7
-move 1 from 8 to 1
-move 7 from 7 to 6
-move 2 from 1 to 2
-move 1 from 3 to 6
-move 2 from 5 to 4
-move 8 from 2 to 1
-move 1 from 9 to 7
-move 1 from 5 to 1
-move 2 from 7 to 3
-move 2 from 3 to 7
-move 2 from 7 to 8
-move 2 from 1 to 5
-move 3 from 9 to 2
-move 2 from 8 to 9
-move 1 from 9 to 2
-move 1 from 9 to 8
-move 1 from 8 to 7
-move 6 from 6 to 5
-move 1 from 6 to 2
-move 2 from 4 to 5
-move 2 from 6 to 8
-move 1 from 7 to 1
-move 2 from 8 to 4
-move 11 from 2 to 5
-move 18 from 5 to 6
-move 6 from 2 to 6
-move 10 from 2 to 7
-move 1 from 4 to 3
-move 7 from 2 to 8
-move 7 from 1 to 4
-move 6 from 7 to 8
-move 2 from 7 to 3
-move 8 from 4 to 7
-move 1 from 1 to 3
-move 1 from 2 to 1
-move 4 from 7 to 1
-move 4 from 1 to 3
-move 2 from 3 to 9
-move 2 from 5 to 4
-move 1 from 2 to 1
-move 2 from 1 to 5
-move 1 from 3 to 1
-move 2 from 5 to 2
-move 1 from 2 to 6
-move 5 from 7 to 4
-move 1 from 1 to 2
-move 10 from 8 to 1
-move 2 from 2 to 7
-move 2 from 7 to 1
-move 1 from 7 to 9
-move 1 from 5 to 7
-move 3 from 8 to 7
-move 3 from 3 to 6
-move 3 from 7 to 1
-move 5 from 1 to 4
-move 1 from 7 to 6
-move 22 from 6 to 3
-move 2 from 6 to 2
-move 19 from 3 to 4
-move 15 from 4 to 8
-move 9 from 8 to 4
-move 5 from 6 to 8
-move 2 from 2 to 8
-move 2 from 9 to 4
-move 7 from 1 to 5
-move 1 from 1 to 3
-move 1 from 9 to 7
-move 5 from 8 to 3
-move 4 from 8 to 1
-move 5 from 1 to 5
-move 10 from 4 to 3
-move 3 from 4 to 2
-move 2 from 8 to 3
-move 12 from 4 to 8
-move 1 from 7 to 6
-move 3 from 2 to 9
-move 2 from 4 to 5
-move 5 from 3 to 7
-move 1 from 7 to 2
-move 1 from 1 to 6
-move 1 from 7 to 2
-move 15 from 3 to 8
-move 10 from 5 to 6
-move 3 from 7 to 8
-move 1 from 5 to 8
-move 1 from 2 to 3
-move 7 from 6 to 1
-move 3 from 5 to 3
-move 5 from 3 to 5
-move 3 from 5 to 4
-move 2 from 4 to 9
-move 2 from 3 to 5
-move 14 from 8 to 5
-move 1 from 9 to 1
-move 16 from 5 to 3
-move 16 from 3 to 6
-move 2 from 9 to 8
-move 21 from 6 to 7
-move 2 from 1 to 7
-move 1 from 2 to 7
-move 4 from 1 to 7
-move 1 from 4 to 7
-move 16 from 8 to 5
-move 20 from 7 to 4
-move 1 from 9 to 8
-move 1 from 7 to 4
-move 3 from 8 to 6
-move 1 from 9 to 1
-move 2 from 1 to 4
-move 2 from 5 to 2
-move 5 from 4 to 7
-move 1 from 6 to 9
-move 11 from 7 to 6
-move 2 from 7 to 5
-move 12 from 6 to 2
-move 13 from 2 to 1
-move 1 from 2 to 3
-move 1 from 8 to 4
-move 6 from 4 to 1
-move 1 from 6 to 7
-move 7 from 4 to 9
-move 8 from 9 to 3
-move 2 from 8 to 3
-move 10 from 5 to 4
-move 11 from 1 to 8
-move 1 from 1 to 3
-move 5 from 1 to 8
-move 8 from 5 to 6
-move 13 from 8 to 9
-move 12 from 3 to 5
-move 12 from 5 to 9
-move 1 from 7 to 9
-move 1 from 1 to 2
-move 1 from 1 to 4
-move 3 from 8 to 5
-move 1 from 2 to 5
-move 1 from 4 to 8
-move 5 from 6 to 3
-move 1 from 8 to 4
-move 13 from 4 to 7
-move 3 from 7 to 6
-move 1 from 1 to 4
-move 4 from 4 to 2
-move 1 from 6 to 3
-move 2 from 5 to 9
-move 2 from 5 to 9
-move 1 from 4 to 8
-move 6 from 9 to 4
-move 22 from 9 to 2
-move 8 from 7 to 4
-move 7 from 2 to 1
-move 3 from 3 to 8
-move 2 from 6 to 7
-move 14 from 4 to 2
-move 2 from 6 to 1
-move 1 from 8 to 7
-move 3 from 3 to 9
-move 1 from 8 to 4
-move 3 from 1 to 9
-move 3 from 9 to 3
-move 31 from 2 to 8
-move 8 from 8 to 4
-move 1 from 9 to 1
-move 9 from 4 to 5
-move 7 from 5 to 6
-move 2 from 5 to 1
-move 1 from 2 to 1
-move 1 from 7 to 9
-move 1 from 2 to 9
-move 2 from 6 to 4
-move 4 from 7 to 4
-move 4 from 9 to 8
-move 6 from 4 to 1
-move 1 from 3 to 2
-move 1 from 3 to 6
-move 1 from 9 to 2
-move 2 from 2 to 4
-move 1 from 9 to 1
-move 1 from 3 to 1
-move 17 from 1 to 9
-move 4 from 6 to 2
-move 1 from 9 to 7
-move 4 from 9 to 7
-move 1 from 8 to 4
-move 3 from 7 to 6
-move 1 from 4 to 9
-move 10 from 8 to 5
-move 6 from 6 to 5
-move 1 from 7 to 2
-move 1 from 1 to 4
-move 1 from 4 to 5
-move 9 from 8 to 3
-move 4 from 3 to 9
-move 1 from 4 to 6
-move 1 from 6 to 5
-move 1 from 4 to 8
-move 2 from 3 to 8
-move 1 from 3 to 8
-move 3 from 8 to 9
-move 5 from 2 to 9
-move 1 from 7 to 9
-move 5 from 8 to 7
-move 3 from 8 to 4
-move 2 from 8 to 5
-move 24 from 9 to 7
-move 1 from 3 to 5
-move 2 from 9 to 4
-move 22 from 7 to 9
-move 1 from 3 to 4
-move 6 from 4 to 6
-move 4 from 6 to 7
-move 10 from 5 to 3
-move 8 from 3 to 5
-move 2 from 3 to 4
-move 2 from 4 to 6
-move 10 from 7 to 3
-move 21 from 9 to 1
-move 2 from 3 to 4
-move 4 from 3 to 8
-move 2 from 4 to 8
-move 1 from 7 to 8
-move 4 from 6 to 8
-move 3 from 5 to 4
-move 8 from 8 to 2
-move 18 from 1 to 6
-move 3 from 4 to 1
-move 1 from 2 to 8
-move 5 from 1 to 8
-move 3 from 3 to 6
-move 3 from 2 to 9
-move 3 from 8 to 1
-move 11 from 5 to 2
-move 3 from 8 to 7
-move 10 from 2 to 9
-move 1 from 7 to 9
-move 3 from 8 to 1
-move 2 from 7 to 8
-move 6 from 9 to 5
-move 4 from 2 to 8
-move 8 from 5 to 8
-move 1 from 3 to 7
-move 2 from 5 to 6
-move 3 from 1 to 6
-move 2 from 1 to 6
-move 4 from 9 to 8
-move 4 from 9 to 8
-move 1 from 9 to 4
-move 9 from 6 to 9
-move 16 from 6 to 9
-move 1 from 4 to 7
-move 1 from 2 to 9
-move 5 from 8 to 5
-move 4 from 5 to 1
-move 6 from 1 to 7
-move 12 from 8 to 4
-move 5 from 8 to 1
-move 6 from 9 to 3
-move 1 from 1 to 6
-move 2 from 5 to 8
-move 12 from 4 to 7
-move 2 from 8 to 4
-move 1 from 4 to 8
-move 1 from 7 to 6
-move 1 from 4 to 6
-move 14 from 7 to 1
-move 3 from 3 to 2
-move 7 from 9 to 7
-move 3 from 3 to 5
-move 15 from 1 to 2
-move 2 from 5 to 9
-move 1 from 8 to 9
-move 16 from 9 to 1
-move 1 from 5 to 9
-move 5 from 6 to 2
-move 12 from 7 to 2
-move 20 from 2 to 6
-move 10 from 2 to 6
-move 11 from 1 to 7
-move 2 from 7 to 4
-move 2 from 2 to 5
-move 1 from 2 to 3
-move 2 from 5 to 6
-move 1 from 9 to 5
-move 1 from 5 to 9
-move 25 from 6 to 7
-move 25 from 7 to 6
-move 1 from 3 to 1
-move 1 from 2 to 5
-move 1 from 4 to 3
-move 33 from 6 to 3
-move 1 from 9 to 5
-move 2 from 3 to 5
-move 28 from 3 to 9
-move 5 from 1 to 9
-move 4 from 1 to 8
-move 2 from 3 to 2
-move 2 from 8 to 1
-move 1 from 4 to 6
-move 3 from 5 to 3
-move 1 from 2 to 4
-move 2 from 2 to 8
-move 1 from 6 to 5
-move 30 from 9 to 2
-move 7 from 2 to 6
-move 1 from 1 to 3
-move 1 from 1 to 7
-move 1 from 5 to 6
-move 1 from 5 to 4
-move 5 from 7 to 4
-move 4 from 7 to 3
-move 1 from 3 to 7
-move 3 from 8 to 7
-move 8 from 3 to 1
-move 3 from 1 to 7
-move 4 from 1 to 4
-move 3 from 9 to 8
-move 8 from 6 to 2
-move 18 from 2 to 6
-move 6 from 7 to 2
-move 1 from 1 to 7
-move 3 from 4 to 7
-move 5 from 4 to 8
-move 2 from 8 to 7
-move 7 from 2 to 5
-move 5 from 2 to 7
-move 10 from 7 to 9
-move 5 from 5 to 9
-move 1 from 3 to 9
-move 5 from 2 to 6
-move 3 from 7 to 9
-move 2 from 5 to 6
-move 2 from 2 to 9
-move 2 from 8 to 7
-move 1 from 4 to 5
-move 8 from 9 to 2
-move 5 from 6 to 7
-move 4 from 9 to 1
-move 4 from 8 to 9
-move 12 from 9 to 1
-move 16 from 1 to 4
-move 12 from 6 to 2
-move 3 from 7 to 6
-move 3 from 7 to 3
-move 1 from 9 to 4
-move 12 from 4 to 5
-move 2 from 4 to 3
-move 1 from 7 to 1
-move 4 from 4 to 3
-move 1 from 8 to 2
-move 6 from 3 to 1
-move 1 from 1 to 8
-move 7 from 2 to 5
-move 1 from 8 to 1
-move 4 from 5 to 4
-move 5 from 5 to 9
-move 1 from 3 to 8
-move 1 from 9 to 7
-move 1 from 8 to 1
-move 4 from 5 to 6
-move 5 from 5 to 9
-move 7 from 9 to 5
-move 11 from 6 to 4
-move 1 from 9 to 4
-move 1 from 9 to 1
-move 1 from 7 to 2
-move 9 from 4 to 3
-move 5 from 1 to 9
-move 3 from 5 to 1
-move 5 from 9 to 8
-move 8 from 3 to 1
-move 2 from 5 to 3
-move 7 from 2 to 5
-move 1 from 6 to 4
-move 3 from 5 to 9
-move 3 from 6 to 9
-move 3 from 2 to 9
-move 5 from 3 to 6
-move 1 from 9 to 5
-move 4 from 8 to 3
-move 1 from 8 to 4
-move 8 from 1 to 3
-move 12 from 3 to 7
-move 1 from 2 to 4
-move 3 from 2 to 8
-move 6 from 7 to 6
-move 4 from 5 to 7
-move 5 from 9 to 7
-move 2 from 9 to 2
-move 1 from 9 to 5
-move 4 from 5 to 1
-move 1 from 5 to 4
-move 14 from 7 to 6
-move 1 from 1 to 7
-move 10 from 4 to 5
-move 4 from 1 to 2
-move 1 from 4 to 6
-move 1 from 7 to 4
-move 17 from 6 to 8
-move 1 from 5 to 7
-move 10 from 5 to 4
-move 1 from 2 to 6
-move 4 from 2 to 6
-move 13 from 6 to 1
-move 9 from 4 to 3
-move 2 from 2 to 4
-move 1 from 6 to 7
-move 1 from 4 to 3
-move 8 from 3 to 5
-move 1 from 3 to 4
-move 17 from 1 to 3
-move 15 from 3 to 7
-move 3 from 4 to 1
-move 6 from 8 to 9
-move 6 from 9 to 1
-move 2 from 3 to 1
-move 2 from 5 to 2
-move 6 from 7 to 6
-move 3 from 6 to 9
 ";
 }
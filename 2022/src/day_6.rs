@@ -78,14 +78,23 @@ start-of-message marker is detected?
 
 */
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
+use common::{random::Rng, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// The datastream buffer.
+    type Parsed<'a> = &'a [u8];
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    fn part_1(input: &str) -> usize {
+    fn parse(input: &str) -> Result<&[u8], ParseError> {
+        Ok(input.trim().as_bytes())
+    }
+
+    fn part_1(input: &&[u8]) -> usize {
         input
-            .trim()
-            .as_bytes()
             .windows(4)
             .take_while(|w| {
                 w[0] == w[1]
@@ -99,13 +108,8 @@ mod test {
             + 4
     }
 
-    fn part_2(input: &str) -> usize {
-        let mut input = input
-            .trim()
-            .as_bytes()
-            .iter()
-            .enumerate()
-            .collect::<Vec<_>>();
+    fn part_2(input: &&[u8]) -> usize {
+        let mut input = input.iter().enumerate().collect::<Vec<_>>();
         let mut flags = vec![true; input.len()];
         input.sort_by_key(|(i, c)| (*c, *i));
         for (_, mut group) in &input.into_iter().group_by(|(_i, c)| *c) {
@@ -113,7 +117,7 @@ mod test {
                 Some(e) => e,
                 None => continue,
             };
-            while let Some((i, _c)) = group.next() {
+            for (i, _c) in group.by_ref() {
                 if i - prev < 14 {
                     /*
                     i and prev are in the same group which means the
@@ -123,94 +127,55 @@ mod test {
                     elements to be in the range start..end. We record
                     this fact by setting the flags to false.
                      */
-                    let start = if i < 13 { 0 } else { i - 13 };
+                    let start = i.saturating_sub(13);
                     let end = prev + 1;
                     flags[start..end].fill(false);
                 }
                 prev = i;
             }
         }
-        flags
-            .iter()
-            .enumerate()
-            .filter(|(_i, &f)| f)
-            .next()
-            .unwrap()
-            .0
-            + 14
+        flags.iter().enumerate().find(|(_i, &f)| f).unwrap().0 + 14
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // With only a few letters, a run of 14 different ones is unlikely,
+        // so plant one.
+        let mut stream: Vec<u8> = (0..40 * size.max(1))
+            .map(|_| rng.range(b'a'..=b'l'))
+            .collect();
+        let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+        rng.shuffle(&mut marker);
+        let at = rng.range(0..=stream.len());
+        stream.splice(at..at, marker[..14].iter().copied());
+        String::from_utf8(stream).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
-        assert_eq!(part_1("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
-        assert_eq!(part_1("nppdvjthqldpwncqszvftbrmjlhg"), 6);
-        assert_eq!(part_1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
-        assert_eq!(part_1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
-        assert_eq!(part_1(INPUT), 1640);
+        assert_eq!(Solver::solve_1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
+        assert_eq!(Solver::solve_1("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
+        assert_eq!(Solver::solve_1("nppdvjthqldpwncqszvftbrmjlhg"), 6);
+        assert_eq!(Solver::solve_1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
+        assert_eq!(Solver::solve_1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
+        if let Some(input) = common::input::for_test(2022, 6) {
+            assert_eq!(Solver::solve_1(&input), 1640);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
-        assert_eq!(part_2("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
-        assert_eq!(part_2("nppdvjthqldpwncqszvftbrmjlhg"), 23);
-        assert_eq!(part_2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
-        assert_eq!(part_2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
-        assert_eq!(part_2(INPUT), 3613);
+        assert_eq!(Solver::solve_2("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
+        assert_eq!(Solver::solve_2("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
+        assert_eq!(Solver::solve_2("nppdvjthqldpwncqszvftbrmjlhg"), 23);
+        assert_eq!(Solver::solve_2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(Solver::solve_2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
+        if let Some(input) = common::input::for_test(2022, 6) {
+            assert_eq!(Solver::solve_2(&input), 3613);
+        }
     }
-
-    const INPUT: &str = "
-vftfrfcrfrpptffhnnnsznzgngqgsssczcjcdcssmgmtmnmqnmmfttbdttqtggmgpmmqrqzrzttptpdt\
-pddqfdffgjgzjzczjcjjvttvmmrwmrwwjtwjttnccgbcbjbgjjcvvsqshszhzvhvsstjjljcczmzjmzz\
-dtzzhfhvvvflljtllfqqdmmmhmfhffzhhtgtssqppgcppfjjrnngwwrvrvqvpppcspsrrsqqqlmmpcmc\
-jmmjhmhzzpnnggmjjrprptpccdggcrrwfwggsfggbbqlqflqqcsqqccqzqbbnbmmlzllnhnwwsjsgstg\
-ttfdtffzfvzvbzzrlzlppcsctcncrncnjnfnqnvngnzzscslsppdqdbbthhqvqfvqqvccvdcddsllnwn\
-qwwsccjmcjmmfmppnzpzffslffjrfjftftrffwcwppfsftssdwdzzffnwndnvnggbbfbgfbbfmbbjzzd\
-qdtqtfqqpzzqpzpllvrrbqqbcqqzvznzmmwcwtwftwfwtftvtddhldldblbzlbzlllcjjwllvcllqplq\
-plpzlzqzsqzsznnqwnqqrcqqnrrhmmqbqqlbqqrhrrdgrrsqssnwsnswsgsfsbbdhbdbqqtsqsbsmmbt\
-mmghhcththrrnvvnlvvdvwwjwmwbmmvtvztzmtmrmprpvvmbmrbrsbbpsbbtccwgccbctbccjrjcjtcc\
-rrdnnwwqrwwjpjtptcpcfpfvfjjtqtnqqvzqqfhfrftrrgqgbqqtwwthwhqwqjqmmlglnlrnlrnnshsm\
-spmmfnnqwqgwqqprpbbjbhjbbfqftfwwjhhcpcgcpcvppgfgcfgffbzbfzbzqzccmppndppspbssnbbp\
-dpjpgpngpnggtztjzjdjhjmmwrmmqhhcvvvltvllvmvpvlplwplpmllgpllczlzpzczwzswwqgqbqppc\
-jjhjddgccfmmctcjcscczzzjzwzjjsccdmdppdrdccmttjpttqvqdqtdqtqhqchhhtbbddmhhwdhdwdj\
-dccnhcnndpdldvldlvddbjdbjbppjbpbssqnqnmmmclcggrmmdnnwmwddvjvdvrvzzglzlnnllhqqrbb\
-cvvdtttdvvnfnqqstszsjsrscspszppfjjfttgqttdpdbbbvnndbdcdqdrdhhdhhdjjjzzhqqrcrvccr\
-vrtvtnvnpppccpdpccjgjmjzzbsbgsbstsbbtdttrqtrrsrdrdcdjcddbcbjcbjbwbbslbbbbnmmtsmm\
-rwrnwwqtqzqpprhrzhrhhldddrpdrpdpwdpwdpwwlmmzssbwswppldlmddphhnfhhczhhqrrdgdjjdtt\
-ztftzffdvddqnqvvlbbncnffssbnbfnnzbnznhzhdzzrqzzptzppsnsnzntzzfwfzzvrzrbzrbzrbzrr\
-qqltqlqppbwppmvpmvpmmbnnbhbmbdmbbhmmngmgmhgmghhvttzzfvfggrrchrrbffzjzlzsllbqqhqr\
-rmqrrlzzsqzqqmhhmnmtntstnsnhsnnwwpdphhgjhjccbcqcjcbjbttmssqffjzjmzmqqrbbfnffcbfc\
-fzfrzztftntbntttlddvwdvdcdhcdhdbbjfjzjwpbcvlqvcwjrcjssdfmgwrhrjvhpgqsbtzqqdwjrqs\
-jplqjdzdcrtvqlcrfpcgwpjnbpcmbwnwbzhcvjvzzpvqnzdqdgpfrvdpfdmpprmzmghdfjjzfqjqcbpl\
-wntzmsrpqclgrqzhlsgwffqqntswnjsmrcpjlsvdrmcwdgqzsbsbvhbszqgwqffcgbqmjrfjdvbpwbrz\
-wbjgvvjchwfscrhrtzbghjlcnsqqhdgqtdcqrrpsbzqvjwptblszrtffhwcvbngnsdjgpscfzwrncwlp\
-fqgwdzffsqmjcbrlffftpvhjchmgmgqvjnpfsjnfzddqjsfqcpjgfrfgrtlmtfqphjfmdcvmghrdqvbb\
-bhlstgpcgmqnwpdjwbrdbntbpncnztmnmzmsjzrwjmccqslngrvbjcjjgcvnvhsslfhwpwtjjcwgzqpd\
-vqrlbttnnwdphztmwcdlvlqggrdprmzdfpbfhmsgqznzjhdqpmhfphqcvbfqmhnfpcvstrhdbtmljnnh\
-qtfnpdwnszfrflsbbqjsvbvggzfhlcljwlrlfnlwlzzllzbqftlwzqsvwlldslnfnnbhlwmhqhrjlqzp\
-dsjlhjncpwtnpnqvjtzzjnmdntmbjbcwphplbcwdfcqbhhnjnjsfplgwbrqpqmghnzvdprtlmgvwhdgp\
-dwfvdtqtnqdvbntmsrhftwvrgwcbvhnwmhfggdzfgbdfqsbngmvjgssclqlhqggwndzzhcrzrmnzggnv\
-bbbpzfdjtnlvlnprjtlljhtdqjlddmjdswjrwhwdbbbrmpwsgpfgnplbtzfzlvgnfrvvnbjtsglbzmtm\
-cjbbjclmjgtdrrbpbbqzqnvrgqssfrwhrtpsbgsvnnfbmqgbvhshmpqtqljlpwptqzprdbgnzmlgtrvg\
-nmmfhtccsvbmsfnlnzwhrmcnwmmhgwclghgmspwjvbgqrbbhhrglstdntwnvcgdcmwgbwrwhsqzsdnqs\
-vmcbzrvtztspshrfmqbtrdtnsqdllqzdcbmvbdswzrrzchqgpgmhvjgwgnpqfwcmchsnqssnnslzwtwv\
-bqjfbhlbdjzjrqjvsshcvcbwhsvvwtmjwjfgszmvfzclbqjhnczqcznprwzjnlgmdgbfjnrqfgvstcld\
-nttbjmhsqgqlmzqtsqngvmdvrwcjtfljzdmnndnrbqnlqtmsqngflwsghzzsfcdnttpblqqhmtgnqmcd\
-fmclsvgnsfpnnfssqzjtdsjbjnnmqhfctlddtwrqlpczlzvhddrtjfwgqhfcvchzqgfhdbfpbvtqcjvc\
-hqmwhvwjrbtcjgbfqjdsbfpgjrzvlgqwphshnqrvqsppgsnfswvrzpwmdfmmwntnsddzppffjvbnshhq\
-gwclvtpjzvlbdzblhhmhrmjrpmltglsdffnstsdqwjhnjccqhbdrgnwmpwczflfvsbznpphgpbfzffbc\
-dnbrqbwddlhvgqsdmdhmlzbdztrrswsbvdgptvhhcdtwzqqhzqpswwvftppwvwhrspfqwppjbdlhcchl\
-ftjhrpwhtvqhmwwtcbfhgbqzvzdlwlzwcsqgvmmsnhrfmwwpcrjlsgzmgdqstlwbzrzbqfnpqffmjqbq\
-qzcnsqrfstnwjflwlpfgcjwjdvtjslrcpgwsvrbvjtzqvnjlqrvvwjhzbzqqjhcrbdtwqjbtmwfrmcgn\
-bdcrhrvlcgrgtglpfmvpgwbzccddlrbsjzwbgwthhdmjjtpchtsbnnpgqfcpmsrgvqwhcdqzmtzlzbfd\
-gmvtqzzdcrnhtlcwnmhdjtwdsrfnlmwpnfwdrptclvwrnwrnntwwqvfmjgswbtqcvmbfbgstvsntndzh\
-jjnjfblqdqrgchchtgdwtvlqzrlpsqgbltjzjngdscdczwzhnlszpdnvnbrmfmjpdzvjfgvtwtpwdjjf\
-gspbvtdjrwzncdpbsthgcwvvdbbvpvqdqpzjmlzhtjmjwmzsmrcstrsvbccqhppwrtmslggqbgglgrgf\
-frbwzmbghfqclwwgssgghqjgfjgvwjhhwnntnrnhmfslqpmwzlggsbmrjjgfzfpjlvmshfsdjtshdlfz\
-vjtlqwjbbgmnjhrhtpbgvcsjvwzlqvfchhpfwsbhcztmdgfzgsmszwfbvvgmgpqsrbzvtpmpqdvhgrjm\
-mspnswjrjnjqfgjwsfbzhwhtlfwjfdhgsvcwqlbznqlnhsmzwltfwclcwgjdbhqvjbbchmcpptmpdqzw\
-pfwrbmchpbqndtmdrwtcvlmrrnvhnpzwqcwwgmcblzvnzbzsspwchtqvjmphqtzgwdzqlbvgdjbssdjw\
-ljhlsjwzrdvqtrzcdwszqgfdwgnqdrmssqqhtblqzdhtqtqmlbbfhzvlbrphcjhzpvvshjffnsjcbgvn\
-gnsjmfdbgfzphjc";
 }
@@ -706,62 +706,91 @@ Simulate your complete series of motions on a larger rope with ten knots. How ma
 the rope visit at least once?
  */
 
-#[cfg(test)]
-mod test {
-
-    /// N is the length of the rope.
-    fn simulate_rope<const N: usize>(input: &str) -> usize {
-        let (mut tailpos, _) = input.trim().lines().fold(
-            (Vec::<(i32, i32)>::new(), [(0i32, 0i32); N]),
-            |(mut tailpos, mut pos), line| {
-                let (dir, steps) = line.split_once(' ').unwrap();
-                let steps: usize = steps.parse().unwrap();
-                let (xstep, ystep): (i32, i32) = match dir {
-                    "R" => (1, 0),
-                    "L" => (-1, 0),
-                    "U" => (0, 1),
-                    "D" => (0, -1),
-                    _ => panic!("Invalid direction"),
-                };
-                for _ in 0..steps {
-                    pos[0].0 += xstep;
-                    pos[0].1 += ystep;
-                    for i in 1..N {
-                        let (xd, yd) = (pos[i - 1].0 - pos[i].0, pos[i - 1].1 - pos[i].1);
-                        if i32::max(i32::abs(xd), i32::abs(yd)) > 1 {
-                            pos[i].0 += i32::signum(xd);
-                            pos[i].1 += i32::signum(yd);
-                        }
+use common::{parse, random::Rng, ParseError, Solution};
+use grid::{Direction, Point, Vector};
+
+/// N is the length of the rope.
+fn simulate_rope<const N: usize>(moves: &[(Vector<i32>, usize)]) -> usize {
+    let (mut tailpos, _) = moves.iter().fold(
+        (Vec::<Point<i32>>::new(), [Point::default(); N]),
+        |(mut tailpos, mut pos), &(step, steps)| {
+            for _ in 0..steps {
+                pos[0] += step;
+                for i in 1..N {
+                    let offset = pos[i - 1] - pos[i];
+                    if offset.chebyshev() > 1 {
+                        pos[i] += offset.signum();
                     }
-                    tailpos.push(pos[N - 1]);
                 }
-                (tailpos, pos)
-            },
-        );
-        tailpos.sort();
-        tailpos.dedup();
-        return tailpos.len();
+                tailpos.push(pos[N - 1]);
+            }
+            (tailpos, pos)
+        },
+    );
+    tailpos.sort();
+    tailpos.dedup();
+    tailpos.len()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Motions of the head as (step, number of steps).
+    type Parsed<'a> = Vec<(Vector<i32>, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Vector<i32>, usize)>, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (dir, steps) = parse::split_once(line, " ")?;
+                let dir = dir
+                    .parse()
+                    .ok()
+                    .and_then(Direction::from_letter)
+                    .ok_or_else(|| ParseError::at(dir, "R, L, U or D"))?;
+                Ok((Vector::from(dir), parse::number(steps)?))
+            })
+            .collect()
+    }
+
+    fn part_1(moves: &Vec<(Vector<i32>, usize)>) -> usize {
+        simulate_rope::<2>(moves)
     }
 
-    fn part_1(input: &str) -> usize {
-        simulate_rope::<2>(input)
+    fn part_2(moves: &Vec<(Vector<i32>, usize)>) -> usize {
+        simulate_rope::<10>(moves)
     }
 
-    fn part_2(input: &str) -> usize {
-        simulate_rope::<10>(input)
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let moves: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{} {}", rng.pick(&['U', 'D', 'L', 'R']), rng.range(1..=20)))
+            .collect();
+        moves.join("\n")
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 13);
-        assert_eq!(part_1(INPUT), 5695);
+        assert_eq!(Solver::solve_1(EXAMPLE), 13);
+        if let Some(input) = common::input::for_test(2022, 9) {
+            assert_eq!(Solver::solve_1(&input), 5695);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 1);
-        assert_eq!(part_2(EXAMPLE_2), 36);
-        assert_eq!(part_2(INPUT), 2434);
+        assert_eq!(Solver::solve_2(EXAMPLE), 1);
+        assert_eq!(Solver::solve_2(EXAMPLE_2), 36);
+        if let Some(input) = common::input::for_test(2022, 9) {
+            assert_eq!(Solver::solve_2(&input), 2434);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -784,2008 +813,5 @@ R 17
 D 10
 L 25
 U 20
-";
-
-    const INPUT: &str = "
-D 2
-U 2
-L 2
-R 1
-L 1
-U 1
-L 2
-D 1
-L 2
-D 1
-L 2
-D 2
-L 1
-R 2
-U 1
-R 1
-U 2
-R 1
-U 2
-D 1
-L 2
-U 1
-D 1
-L 1
-D 1
-U 1
-R 2
-D 2
-L 1
-R 1
-L 2
-D 1
-R 2
-D 1
-U 2
-D 2
-R 2
-L 2
-U 2
-R 2
-L 1
-D 1
-R 1
-U 1
-R 2
-L 1
-D 1
-R 1
-L 2
-D 2
-U 1
-L 1
-R 1
-U 1
-L 2
-D 2
-L 2
-U 1
-L 2
-R 1
-L 1
-R 1
-D 1
-R 2
-D 1
-R 1
-D 1
-U 1
-L 1
-R 1
-L 1
-R 1
-D 2
-L 1
-D 2
-R 1
-D 1
-U 2
-R 2
-U 2
-L 1
-U 1
-R 1
-L 2
-D 2
-R 1
-U 1
-L 1
-U 2
-L 1
-D 2
-R 1
-U 1
-R 1
-U 2
-R 1
-U 2
-L 1
-R 1
-U 2
-R 2
-U 1
-L 2
-R 1
-L 2
-U 2
-L 2
-U 2
-R 2
-D 1
-U 2
-L 2
-D 3
-U 1
-L 1
-R 1
-D 3
-U 1
-L 3
-R 1
-U 1
-R 3
-D 2
-U 1
-R 2
-L 2
-U 1
-D 1
-U 1
-D 1
-L 2
-U 2
-R 3
-D 2
-L 2
-D 2
-U 3
-D 3
-U 2
-L 1
-U 2
-R 3
-D 2
-R 2
-D 1
-U 3
-L 3
-D 1
-U 3
-R 3
-D 1
-L 1
-D 3
-U 3
-D 1
-U 2
-R 1
-D 2
-U 3
-D 1
-R 2
-D 3
-L 3
-R 1
-D 3
-L 2
-D 3
-U 3
-R 1
-U 3
-L 2
-U 2
-L 2
-D 3
-L 3
-U 2
-R 2
-L 3
-R 2
-U 3
-R 3
-L 1
-U 3
-D 3
-L 2
-D 2
-U 2
-D 3
-L 3
-D 2
-L 1
-U 3
-D 2
-R 3
-L 3
-R 1
-U 1
-D 1
-R 2
-L 3
-U 2
-D 1
-R 3
-L 1
-R 1
-L 2
-R 1
-U 3
-R 2
-U 1
-L 1
-R 3
-D 3
-U 2
-R 2
-D 1
-R 3
-D 2
-L 3
-R 1
-U 3
-R 2
-U 4
-L 2
-D 1
-U 1
-R 4
-U 2
-R 2
-U 3
-R 1
-L 3
-U 3
-R 3
-U 4
-R 4
-D 1
-U 2
-R 1
-L 1
-R 2
-U 4
-L 1
-U 2
-L 1
-R 3
-L 2
-D 4
-R 4
-D 3
-R 4
-U 1
-D 4
-R 2
-D 1
-R 4
-U 3
-R 4
-L 1
-U 4
-D 2
-L 1
-D 3
-L 1
-R 2
-L 2
-D 4
-U 3
-D 1
-L 3
-D 3
-U 3
-L 4
-R 3
-U 1
-R 2
-D 1
-U 4
-D 4
-R 4
-U 2
-R 4
-L 4
-D 2
-L 4
-D 2
-U 3
-D 2
-U 4
-R 1
-U 1
-L 1
-U 1
-L 1
-D 3
-U 4
-L 3
-U 1
-L 4
-R 4
-L 3
-R 2
-U 2
-L 3
-D 2
-U 1
-D 1
-U 4
-R 2
-L 2
-D 1
-L 4
-D 3
-U 3
-L 2
-U 4
-R 4
-L 2
-R 3
-L 1
-D 4
-U 1
-L 2
-D 4
-L 2
-D 3
-U 4
-R 4
-U 2
-R 2
-U 4
-D 3
-U 3
-L 4
-D 2
-R 1
-U 4
-R 1
-U 1
-D 3
-U 1
-L 5
-U 5
-R 2
-D 3
-U 5
-D 5
-U 1
-D 2
-L 2
-U 5
-L 1
-R 5
-L 1
-D 5
-L 4
-D 4
-R 4
-L 1
-R 1
-U 1
-R 4
-U 2
-R 5
-L 4
-R 3
-U 4
-R 2
-U 4
-L 4
-U 3
-L 3
-U 1
-L 4
-R 1
-U 3
-R 3
-L 5
-U 5
-L 4
-D 1
-L 2
-U 1
-D 2
-L 1
-U 5
-D 4
-U 1
-R 2
-D 2
-U 5
-R 5
-U 1
-L 3
-U 5
-L 4
-R 3
-U 4
-L 2
-R 4
-D 2
-L 2
-R 4
-U 2
-D 4
-R 3
-D 4
-U 1
-L 3
-R 4
-L 3
-U 1
-R 2
-U 3
-L 3
-D 2
-U 4
-R 1
-L 3
-D 4
-L 1
-R 1
-D 5
-R 1
-U 2
-D 1
-U 4
-L 2
-U 2
-R 4
-U 3
-R 3
-D 2
-R 5
-D 2
-U 5
-D 5
-U 2
-D 1
-R 5
-U 3
-L 3
-U 1
-R 5
-L 4
-D 6
-L 1
-U 2
-D 4
-U 6
-D 6
-R 1
-U 3
-L 5
-R 5
-U 2
-R 2
-L 5
-D 4
-L 3
-R 1
-U 4
-D 3
-L 2
-D 5
-L 3
-R 3
-D 2
-L 1
-U 5
-L 5
-D 6
-U 3
-R 5
-L 5
-U 2
-L 3
-U 5
-D 6
-R 4
-L 5
-U 6
-L 2
-R 1
-L 1
-U 5
-D 3
-U 2
-R 1
-U 2
-R 2
-D 5
-R 1
-D 5
-R 5
-U 2
-L 3
-R 1
-U 5
-D 5
-R 2
-L 5
-D 6
-L 1
-U 1
-D 6
-U 4
-D 5
-L 5
-D 3
-L 4
-D 1
-L 4
-D 6
-R 5
-D 6
-U 6
-D 2
-L 3
-D 3
-R 5
-U 6
-D 2
-U 4
-L 1
-U 6
-L 2
-U 4
-D 6
-R 1
-D 1
-U 2
-L 3
-D 3
-R 2
-L 6
-U 3
-D 5
-U 3
-L 4
-U 5
-L 5
-U 6
-L 3
-R 1
-U 3
-R 1
-U 4
-L 4
-D 5
-L 6
-R 1
-L 3
-D 4
-L 3
-R 1
-L 4
-R 3
-D 3
-L 1
-U 4
-D 2
-U 5
-L 3
-D 6
-U 2
-R 3
-D 5
-L 1
-D 1
-L 3
-U 2
-R 3
-L 3
-U 7
-R 2
-D 1
-L 3
-R 4
-D 2
-R 5
-L 1
-D 5
-L 2
-R 2
-U 7
-L 7
-D 2
-L 7
-D 5
-U 2
-L 2
-U 2
-D 4
-U 1
-D 4
-L 1
-D 2
-R 5
-D 3
-L 2
-U 4
-R 7
-D 4
-R 3
-U 2
-R 4
-L 1
-U 4
-L 4
-U 7
-R 1
-L 6
-D 5
-R 5
-L 1
-R 4
-L 1
-U 3
-L 7
-R 7
-D 4
-L 3
-R 3
-D 1
-U 7
-R 3
-D 2
-U 4
-D 2
-R 3
-D 1
-R 3
-D 3
-U 1
-R 6
-L 2
-D 2
-U 4
-D 5
-L 7
-R 1
-L 7
-U 6
-D 2
-R 3
-D 5
-U 1
-L 7
-D 6
-U 3
-D 6
-L 1
-R 2
-L 6
-D 7
-R 5
-U 1
-L 7
-U 2
-D 2
-L 5
-R 7
-D 4
-U 3
-R 7
-L 4
-U 8
-D 8
-R 2
-U 2
-R 7
-U 1
-D 7
-R 1
-L 7
-R 7
-L 8
-D 3
-R 5
-L 4
-D 3
-L 6
-R 6
-D 6
-L 3
-D 5
-R 1
-U 5
-D 7
-R 8
-U 3
-D 5
-U 8
-L 8
-R 6
-D 8
-U 5
-R 1
-D 1
-R 5
-U 2
-R 8
-D 7
-L 3
-D 3
-U 7
-D 3
-U 7
-R 7
-L 4
-D 7
-U 2
-L 3
-U 7
-L 1
-D 3
-L 1
-R 4
-D 8
-L 5
-U 5
-D 3
-L 5
-D 6
-L 1
-R 5
-U 4
-R 4
-D 1
-R 6
-L 1
-D 7
-R 4
-L 2
-R 3
-U 4
-L 8
-D 1
-R 6
-L 6
-R 8
-D 8
-U 4
-R 1
-D 2
-R 5
-D 1
-U 8
-L 5
-D 5
-U 5
-R 8
-L 6
-R 4
-U 1
-R 5
-U 8
-D 1
-U 1
-D 5
-L 7
-R 6
-L 3
-R 1
-U 2
-L 6
-R 5
-D 4
-R 6
-D 4
-R 2
-L 7
-R 8
-L 6
-D 2
-L 8
-U 4
-R 4
-U 3
-D 8
-U 8
-L 8
-D 5
-R 2
-D 5
-U 7
-L 9
-R 8
-U 9
-R 9
-U 6
-L 6
-D 1
-U 3
-L 1
-D 7
-R 6
-L 6
-D 7
-U 1
-R 9
-D 7
-U 8
-R 6
-U 1
-D 5
-U 5
-D 1
-L 1
-D 9
-U 1
-R 6
-U 9
-R 6
-U 6
-D 7
-R 1
-L 1
-D 8
-L 3
-D 7
-U 8
-L 7
-D 2
-U 6
-D 4
-L 8
-U 3
-D 1
-U 6
-D 3
-R 8
-L 9
-R 3
-L 6
-U 9
-D 9
-L 3
-U 1
-D 2
-R 4
-L 5
-R 2
-U 3
-D 5
-R 5
-L 4
-U 3
-L 7
-U 5
-D 9
-U 6
-R 5
-D 3
-U 8
-L 6
-D 5
-R 9
-U 8
-L 5
-D 1
-R 8
-L 4
-R 2
-L 3
-R 9
-D 9
-R 7
-D 5
-R 6
-U 2
-D 4
-L 8
-D 2
-L 1
-D 2
-U 3
-L 2
-R 3
-D 9
-U 3
-R 4
-D 1
-L 8
-R 3
-U 4
-L 4
-R 3
-L 5
-D 4
-R 2
-D 4
-R 4
-U 4
-R 7
-L 6
-R 9
-D 1
-R 8
-L 5
-U 4
-D 10
-L 6
-D 3
-R 2
-U 1
-D 5
-R 2
-U 6
-L 9
-U 7
-L 10
-U 4
-L 8
-D 3
-U 7
-D 7
-U 9
-L 2
-R 6
-U 4
-R 9
-L 5
-U 3
-L 4
-R 1
-D 8
-U 10
-D 10
-R 3
-U 3
-D 2
-L 5
-U 3
-D 4
-U 1
-R 9
-L 3
-D 8
-L 5
-U 8
-R 3
-U 10
-L 10
-R 4
-D 5
-U 9
-R 8
-L 5
-U 9
-L 6
-U 10
-D 8
-U 2
-L 8
-D 7
-U 2
-D 5
-U 8
-L 2
-D 3
-U 8
-R 8
-D 10
-R 3
-L 3
-U 4
-R 3
-U 4
-L 6
-U 4
-R 3
-D 9
-L 9
-U 5
-D 1
-R 3
-D 2
-L 9
-U 9
-R 9
-U 6
-R 7
-L 8
-U 2
-L 4
-D 10
-L 4
-D 9
-L 9
-R 10
-L 3
-R 7
-U 2
-L 3
-R 1
-D 4
-L 2
-R 10
-D 5
-L 2
-D 2
-L 4
-U 6
-D 9
-R 6
-U 9
-D 4
-L 6
-U 8
-D 1
-R 1
-L 10
-U 4
-R 6
-U 11
-R 8
-U 1
-D 5
-R 7
-D 8
-L 5
-R 6
-U 5
-R 1
-U 7
-L 4
-D 6
-U 8
-R 10
-D 1
-L 8
-R 3
-U 7
-R 2
-U 9
-D 9
-R 9
-L 3
-R 4
-U 10
-L 7
-R 1
-L 5
-D 11
-R 4
-U 10
-R 10
-L 3
-D 2
-L 4
-U 9
-L 1
-D 10
-L 7
-R 11
-D 5
-U 3
-D 10
-U 5
-L 2
-R 9
-D 10
-U 1
-R 10
-U 1
-L 11
-R 10
-L 8
-D 10
-L 5
-R 2
-L 3
-U 4
-L 10
-R 3
-D 5
-R 8
-L 2
-U 1
-D 7
-U 7
-L 1
-U 11
-D 2
-L 2
-D 11
-L 6
-R 9
-U 10
-R 7
-L 11
-R 7
-D 1
-R 3
-U 6
-R 7
-D 8
-U 3
-L 1
-D 11
-U 7
-R 1
-L 7
-R 2
-L 7
-D 3
-U 10
-D 9
-U 10
-R 5
-D 4
-U 7
-L 11
-U 1
-L 12
-R 10
-U 7
-R 3
-L 2
-U 11
-R 6
-U 8
-D 2
-R 2
-L 8
-U 7
-D 10
-U 3
-D 1
-U 8
-R 2
-D 9
-L 8
-R 10
-L 1
-U 8
-R 11
-U 8
-R 12
-D 10
-L 5
-U 6
-L 11
-D 12
-L 5
-U 4
-L 8
-U 11
-D 2
-U 4
-L 6
-R 1
-L 4
-U 2
-L 5
-R 2
-D 7
-L 12
-R 4
-L 1
-D 7
-L 11
-U 3
-D 9
-R 12
-U 10
-D 9
-U 10
-L 6
-R 6
-D 10
-R 10
-D 5
-U 9
-R 2
-D 4
-L 9
-U 2
-R 5
-L 8
-R 1
-D 9
-L 7
-D 11
-R 3
-L 8
-U 3
-D 7
-U 3
-R 8
-U 7
-L 1
-U 10
-D 8
-R 11
-U 10
-D 11
-U 8
-R 12
-U 9
-D 12
-U 4
-D 11
-R 8
-D 1
-L 4
-R 9
-L 7
-U 8
-R 6
-D 5
-R 8
-D 2
-R 6
-L 4
-D 7
-L 3
-U 11
-R 2
-L 10
-D 13
-R 2
-D 10
-U 9
-D 5
-U 5
-D 5
-L 11
-U 3
-R 5
-U 7
-L 6
-D 4
-L 3
-R 2
-D 9
-U 7
-D 9
-U 1
-D 8
-L 11
-D 7
-U 7
-R 3
-L 6
-D 6
-R 5
-L 6
-R 3
-U 10
-L 13
-U 9
-R 11
-L 13
-R 9
-D 3
-U 3
-L 12
-U 4
-L 3
-D 12
-R 13
-D 7
-U 6
-L 11
-U 4
-L 2
-D 7
-L 8
-R 10
-U 13
-R 9
-U 8
-R 11
-U 8
-R 7
-U 11
-L 8
-D 3
-L 4
-U 6
-D 12
-R 13
-U 10
-L 4
-R 12
-U 13
-L 11
-R 10
-L 8
-U 6
-L 1
-D 11
-U 12
-L 8
-U 2
-L 6
-U 2
-L 1
-U 3
-D 5
-U 2
-D 10
-U 11
-L 9
-D 9
-R 7
-L 3
-U 3
-D 2
-U 2
-R 10
-D 1
-R 5
-L 1
-D 3
-R 8
-U 4
-R 1
-D 13
-L 12
-U 1
-D 13
-R 4
-D 3
-R 8
-D 2
-U 3
-D 8
-L 3
-U 9
-R 4
-D 8
-U 9
-D 3
-L 8
-D 11
-L 8
-U 1
-D 13
-U 14
-D 10
-U 9
-R 4
-D 14
-L 10
-D 10
-U 6
-D 7
-R 8
-U 5
-L 8
-R 9
-D 12
-L 9
-D 8
-R 13
-L 7
-U 11
-R 8
-D 5
-U 14
-D 5
-U 9
-L 7
-D 12
-R 2
-L 14
-U 1
-R 9
-U 10
-R 4
-U 4
-R 14
-D 10
-L 3
-R 4
-L 13
-U 3
-R 6
-L 2
-U 6
-R 2
-D 10
-U 4
-L 10
-U 8
-L 6
-D 2
-R 1
-D 5
-U 14
-L 4
-D 5
-L 5
-U 10
-D 5
-R 13
-D 14
-U 14
-R 4
-D 13
-U 10
-R 13
-D 12
-R 10
-L 12
-U 8
-D 10
-U 12
-D 5
-L 11
-D 13
-R 8
-D 10
-R 13
-L 13
-D 1
-U 10
-L 4
-U 14
-D 12
-U 4
-D 5
-R 10
-L 8
-R 13
-D 13
-U 5
-D 4
-L 1
-D 9
-L 14
-U 14
-R 3
-U 6
-R 14
-L 4
-R 7
-D 3
-R 8
-D 5
-L 2
-U 5
-D 8
-U 3
-R 7
-D 13
-U 14
-R 11
-U 6
-L 15
-R 4
-U 12
-R 1
-L 8
-U 15
-R 5
-L 2
-D 1
-R 12
-D 1
-R 12
-D 1
-L 1
-R 9
-U 11
-D 2
-U 12
-D 3
-U 6
-D 12
-U 11
-R 6
-D 11
-L 5
-R 3
-D 1
-U 7
-L 10
-D 4
-L 7
-R 2
-U 8
-D 11
-L 12
-R 6
-L 7
-D 13
-U 11
-R 5
-D 1
-L 11
-D 2
-U 15
-L 11
-R 3
-L 9
-D 12
-U 8
-D 9
-L 14
-D 10
-L 12
-R 13
-D 3
-L 9
-D 13
-R 8
-D 15
-U 14
-R 9
-U 12
-R 2
-D 3
-U 5
-L 3
-R 7
-U 15
-R 10
-L 6
-R 6
-D 12
-R 14
-L 7
-U 8
-L 11
-R 13
-U 8
-L 8
-R 5
-D 3
-U 8
-D 4
-L 1
-D 11
-U 15
-L 14
-R 8
-L 1
-R 13
-L 2
-R 13
-U 9
-D 12
-L 14
-R 13
-L 5
-R 7
-D 13
-U 15
-R 2
-L 12
-D 12
-R 13
-U 2
-D 4
-R 14
-L 1
-U 12
-D 3
-U 6
-R 6
-L 6
-U 9
-L 16
-U 16
-D 8
-R 8
-U 1
-D 14
-R 13
-D 3
-U 14
-R 7
-U 7
-R 7
-D 12
-U 1
-D 2
-U 2
-R 7
-D 11
-U 4
-R 13
-U 2
-L 16
-U 12
-L 15
-D 10
-U 11
-L 1
-D 1
-L 3
-D 15
-L 12
-R 2
-D 12
-U 16
-R 16
-U 11
-D 15
-R 11
-U 13
-L 3
-R 15
-D 16
-R 9
-D 1
-L 3
-U 9
-D 12
-U 11
-L 13
-R 10
-D 4
-U 12
-L 2
-R 8
-L 1
-U 9
-L 1
-D 7
-R 14
-U 12
-L 9
-R 5
-D 7
-R 10
-D 14
-L 2
-U 2
-L 4
-D 15
-U 7
-D 9
-L 4
-R 1
-L 8
-D 14
-U 9
-D 1
-R 15
-U 9
-R 10
-D 11
-L 14
-R 12
-L 12
-U 13
-R 12
-L 1
-R 10
-D 14
-R 3
-U 5
-D 10
-L 13
-U 11
-R 16
-L 14
-U 6
-D 5
-R 16
-D 7
-L 16
-U 12
-R 14
-U 7
-R 6
-U 17
-R 3
-U 15
-R 17
-U 3
-R 4
-U 6
-D 13
-U 7
-R 12
-U 10
-R 7
-L 13
-U 16
-D 13
-U 2
-L 13
-R 2
-D 1
-U 10
-R 6
-D 11
-R 10
-D 6
-R 2
-L 1
-R 1
-L 5
-D 16
-U 1
-L 13
-U 3
-R 9
-L 10
-D 12
-R 9
-U 17
-R 10
-U 1
-D 13
-L 13
-R 6
-L 5
-D 5
-R 15
-D 15
-L 7
-U 11
-D 1
-U 11
-L 17
-R 5
-L 11
-R 15
-U 16
-D 2
-L 5
-D 9
-R 13
-D 14
-U 6
-L 10
-U 15
-D 15
-R 5
-D 4
-L 13
-R 11
-D 15
-U 13
-R 8
-U 6
-R 8
-U 3
-R 12
-U 11
-L 6
-U 9
-D 6
-L 1
-U 4
-L 6
-R 4
-D 2
-L 6
-U 11
-L 1
-U 10
-R 2
-U 6
-R 1
-U 7
-R 16
-L 12
-U 9
-D 9
-U 6
-R 13
-U 8
-L 6
-U 2
-R 17
-L 6
-U 5
-D 14
-R 15
-D 13
-L 5
-U 18
-R 9
-L 10
-R 9
-D 6
-L 8
-U 3
-D 8
-R 17
-L 4
-R 9
-L 13
-U 3
-R 12
-D 4
-R 11
-U 13
-L 15
-R 13
-D 18
-R 8
-L 7
-R 10
-D 11
-U 8
-R 4
-L 7
-U 15
-L 3
-D 15
-R 3
-U 6
-L 17
-D 17
-L 12
-D 16
-R 14
-L 1
-R 7
-D 15
-U 2
-L 5
-U 16
-D 7
-L 11
-U 1
-R 2
-D 4
-L 13
-U 17
-R 5
-D 17
-R 14
-L 1
-D 11
-U 11
-R 8
-U 12
-D 12
-U 17
-R 15
-L 4
-D 7
-U 8
-R 4
-L 14
-U 11
-L 3
-R 1
-L 14
-U 3
-L 3
-D 15
-L 14
-D 7
-R 2
-L 6
-U 6
-R 12
-U 17
-D 1
-R 15
-D 1
-U 14
-D 6
-U 4
-R 4
-L 3
-U 14
-L 1
-R 4
-U 13
-D 18
-L 11
-U 4
-D 9
-R 10
-U 17
-R 8
-D 12
-R 9
-L 5
-D 6
-L 9
-R 9
-L 17
-D 9
-R 2
-L 4
-R 8
-D 8
-R 8
-D 13
-R 12
-L 13
-U 8
-D 8
-L 16
-D 15
-U 11
-L 8
-R 7
-D 2
-R 6
-U 19
-D 4
-U 7
-L 17
-R 9
-U 18
-D 4
-R 19
-L 14
-U 8
-D 19
-L 12
-R 14
-U 13
-D 3
-L 8
-D 4
-R 9
-U 18
-D 1
-U 2
-L 16
-U 2
-D 12
-U 5
-L 4
-D 6
-U 14
-R 1
-L 7
-D 7
-L 19
-D 5
-U 16
-D 15
-R 14
-U 19
-D 19
-L 6
-R 3
-L 14
-R 16
-L 1
-U 4
-R 19
-U 2
-L 3
-D 19
-L 17
-U 13
-D 5
-L 10
-U 18
-R 13
-D 6
-L 17
-D 9
-R 10
-D 8
-R 19
-L 6
-D 7
-R 18
-U 8
-L 6
-U 18
-L 18
-D 16
-R 8
-L 10
-R 6
-D 1
-L 6
-U 8
-L 19
-D 18
-U 3
-D 17
-U 9
-R 13
-D 18
-R 2
-L 10
-D 17
-L 17
-U 8
-L 13
-D 5
-L 1
-U 10
-L 3
-R 3
-D 12
-U 11
-L 15
-D 15
 ";
 }
@@ -0,0 +1,630 @@
+//! Tests of the examples in the puzzle statements, generated by
+//! `cargo run -p aoc -- examples`. Do not edit, run that again instead.
+
+use common::{Answer, Solution};
+
+#[test]
+fn day_1_part_1() {
+    let input = r"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+    assert_eq!(
+        Answer::from(crate::day_1::Solver::solve_1(input)).to_string(),
+        "24000"
+    );
+}
+
+#[test]
+fn day_1_part_2() {
+    let input = r"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+    assert_eq!(
+        Answer::from(crate::day_1::Solver::solve_2(input)).to_string(),
+        "45000"
+    );
+}
+
+#[test]
+fn day_2_part_1() {
+    let input = r"A Y
+B X
+C Z";
+    assert_eq!(
+        Answer::from(crate::day_2::Solver::solve_1(input)).to_string(),
+        "15"
+    );
+}
+
+#[test]
+fn day_2_part_2() {
+    let input = r"A Y
+B X
+C Z";
+    assert_eq!(
+        Answer::from(crate::day_2::Solver::solve_2(input)).to_string(),
+        "12"
+    );
+}
+
+#[test]
+fn day_3_part_1() {
+    let input = r"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+    assert_eq!(
+        Answer::from(crate::day_3::Solver::solve_1(input)).to_string(),
+        "157"
+    );
+}
+
+#[test]
+fn day_3_part_2() {
+    let input = r"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+    assert_eq!(
+        Answer::from(crate::day_3::Solver::solve_2(input)).to_string(),
+        "70"
+    );
+}
+
+#[test]
+fn day_4_part_1() {
+    let input = r"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+    assert_eq!(
+        Answer::from(crate::day_4::Solver::solve_1(input)).to_string(),
+        "2"
+    );
+}
+
+#[test]
+fn day_4_part_2() {
+    let input = r"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+    assert_eq!(
+        Answer::from(crate::day_4::Solver::solve_2(input)).to_string(),
+        "4"
+    );
+}
+
+#[test]
+fn day_5_part_1() {
+    let input = r"    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+    assert_eq!(
+        Answer::from(crate::day_5::Solver::solve_1(input)).to_string(),
+        "CMZ"
+    );
+}
+
+#[test]
+fn day_5_part_2() {
+    let input = r"    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+    assert_eq!(
+        Answer::from(crate::day_5::Solver::solve_2(input)).to_string(),
+        "MCD"
+    );
+}
+
+#[test]
+fn day_6_part_1() {
+    let input = r"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+    assert_eq!(
+        Answer::from(crate::day_6::Solver::solve_1(input)).to_string(),
+        "7"
+    );
+}
+
+#[test]
+fn day_6_part_2() {
+    let input = r"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+    assert_eq!(
+        Answer::from(crate::day_6::Solver::solve_2(input)).to_string(),
+        "19"
+    );
+}
+
+#[test]
+fn day_7_part_1() {
+    let input = r"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+    assert_eq!(
+        Answer::from(crate::day_7::Solver::solve_1(input)).to_string(),
+        "95437"
+    );
+}
+
+#[test]
+fn day_7_part_2() {
+    let input = r"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+    assert_eq!(
+        Answer::from(crate::day_7::Solver::solve_2(input)).to_string(),
+        "24933642"
+    );
+}
+
+#[test]
+fn day_8_part_1() {
+    let input = r"30373
+25512
+65332
+33549
+35390";
+    assert_eq!(
+        Answer::from(crate::day_8::Solver::solve_1(input)).to_string(),
+        "21"
+    );
+}
+
+#[test]
+fn day_8_part_2() {
+    let input = r"30373
+25512
+65332
+33549
+35390";
+    assert_eq!(
+        Answer::from(crate::day_8::Solver::solve_2(input)).to_string(),
+        "8"
+    );
+}
+
+#[test]
+fn day_9_part_1() {
+    let input = r"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+    assert_eq!(
+        Answer::from(crate::day_9::Solver::solve_1(input)).to_string(),
+        "13"
+    );
+}
+
+#[test]
+fn day_9_part_2() {
+    let input = r"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+    assert_eq!(
+        Answer::from(crate::day_9::Solver::solve_2(input)).to_string(),
+        "36"
+    );
+}
+
+#[test]
+fn day_10_part_1() {
+    let input = r"addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+    assert_eq!(
+        Answer::from(crate::day_10::Solver::solve_1(input)).to_string(),
+        "13140"
+    );
+}
+
+#[test]
+fn day_11_part_1() {
+    let input = r"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+    assert_eq!(
+        Answer::from(crate::day_11::Solver::solve_1(input)).to_string(),
+        "10605"
+    );
+}
+
+#[test]
+fn day_11_part_2() {
+    let input = r"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+    assert_eq!(
+        Answer::from(crate::day_11::Solver::solve_2(input)).to_string(),
+        "2713310158"
+    );
+}
+
+#[test]
+fn day_12_part_1() {
+    let input = r"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+    assert_eq!(
+        Answer::from(crate::day_12::Solver::solve_1(input)).to_string(),
+        "31"
+    );
+}
+
+#[test]
+fn day_12_part_2() {
+    let input = r"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+    assert_eq!(
+        Answer::from(crate::day_12::Solver::solve_2(input)).to_string(),
+        "29"
+    );
+}
+
+#[test]
+fn day_13_part_1() {
+    let input = r"[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+    assert_eq!(
+        Answer::from(crate::day_13::Solver::solve_1(input)).to_string(),
+        "13"
+    );
+}
+
+#[test]
+fn day_13_part_2() {
+    let input = r"[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+    assert_eq!(
+        Answer::from(crate::day_13::Solver::solve_2(input)).to_string(),
+        "140"
+    );
+}
+
+#[test]
+fn day_14_part_1() {
+    let input = r"498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+    assert_eq!(
+        Answer::from(crate::day_14::Solver::solve_1(input)).to_string(),
+        "24"
+    );
+}
+
+#[test]
+fn day_14_part_2() {
+    let input = r"498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+    assert_eq!(
+        Answer::from(crate::day_14::Solver::solve_2(input)).to_string(),
+        "93"
+    );
+}
@@ -126,87 +126,116 @@ Starting with the same initial image, expand the universe according to these new
 the shortest path between every pair of galaxies. What is the sum of these lengths?
  */
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
-
-    fn get_dist_sum(input: &str, expansion: usize) -> usize {
-        let input = input.trim();
-        let cols = input.find('\n').unwrap();
-        let grid = input
-            .as_bytes()
-            .iter()
-            .filter_map(|&c| if c != b'\n' { Some(c) } else { None })
-            .collect_vec();
-        let rows = grid.len() / cols;
-        assert_eq!(grid.len() % cols, 0);
-        let galaxies = grid
-            .iter()
-            .enumerate()
-            .filter_map(|(i, c)| {
-                if *c == b'#' {
-                    Some((i / cols, i % cols))
-                } else {
-                    None
-                }
-            })
-            .collect_vec();
-        let heights = (0..rows)
-            .map(|row| {
-                if grid[(row * cols)..(row * cols + cols)]
-                    .iter()
-                    .all(|c| *c == b'.')
-                {
-                    expansion
-                } else {
-                    1
-                }
-            })
-            .collect_vec();
-        let widths = (0..cols)
-            .map(|ci| {
-                if (0..rows).map(|ri| ri * cols + ci).all(|i| grid[i] == b'.') {
-                    expansion
-                } else {
-                    1
-                }
-            })
-            .collect_vec();
-        (0..galaxies.len())
-            .map(|i| {
-                let (ri, ci) = galaxies[i];
-                ((i + 1)..galaxies.len())
-                    .map(|j| {
-                        let (rj, cj) = galaxies[j];
-                        (ri..rj).map(|r| heights[r]).sum::<usize>()
-                            + (usize::min(ci, cj)..usize::max(ci, cj))
-                                .map(|r| widths[r])
-                                .sum::<usize>()
-                    })
-                    .sum::<usize>()
-            })
-            .sum()
+use common::{random::Rng, ParseError, Solution};
+use grid::Grid;
+use itertools::Itertools;
+
+fn get_dist_sum(image: &Grid<u8>, expansion: usize) -> usize {
+    let galaxies = image
+        .cells()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| {
+            if *c == b'#' {
+                Some(image.coords(i))
+            } else {
+                None
+            }
+        })
+        .collect_vec();
+    let heights = image
+        .iter_rows()
+        .map(|row| {
+            if row.iter().all(|c| *c == b'.') {
+                expansion
+            } else {
+                1
+            }
+        })
+        .collect_vec();
+    let widths = image
+        .iter_cols()
+        .map(|mut col| {
+            if col.all(|c| *c == b'.') {
+                expansion
+            } else {
+                1
+            }
+        })
+        .collect_vec();
+    (0..galaxies.len())
+        .map(|i| {
+            let (ri, ci) = galaxies[i];
+            ((i + 1)..galaxies.len())
+                .map(|j| {
+                    let (rj, cj) = galaxies[j];
+                    (ri..rj).map(|r| heights[r]).sum::<usize>()
+                        + (usize::min(ci, cj)..usize::max(ci, cj))
+                            .map(|r| widths[r])
+                            .sum::<usize>()
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// The image, with '#' for galaxies.
+    type Parsed<'a> = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(input, "'#' or '.'", |c| {
+            matches!(c, '#' | '.').then_some(c as u8)
+        })
+    }
+
+    fn part_1(image: &Grid<u8>) -> usize {
+        get_dist_sum(image, 2)
     }
 
-    fn part_1(input: &str) -> usize {
-        get_dist_sum(input, 2)
+    fn part_2(image: &Grid<u8>) -> usize {
+        get_dist_sum(image, 1_000_000)
     }
 
-    fn part_2(input: &str, expansion: usize) -> usize {
-        get_dist_sum(input, expansion)
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(2);
+        // Some rows and columns are kept clear of galaxies, to be expanded.
+        let empty_rows: Vec<bool> = (0..side).map(|_| rng.chance(0.1)).collect();
+        let empty_cols: Vec<bool> = (0..side).map(|_| rng.chance(0.1)).collect();
+        let mut image = Grid::new(side, side, '.');
+        for pos in 0..image.len() {
+            let (row, col) = image.coords(pos);
+            if !empty_rows[row] && !empty_cols[col] && rng.chance(0.05) {
+                image[pos] = '#';
+            }
+        }
+        image.to_string()
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 374);
-        assert_eq!(part_1(INPUT), 10494813);
+        assert_eq!(Solver::solve_1(EXAMPLE), 374);
+        if let Some(input) = common::input::for_test(2023, 11) {
+            assert_eq!(Solver::solve_1(&input), 10494813);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE, 10), 1030);
-        assert_eq!(part_2(EXAMPLE, 100), 8410);
-        assert_eq!(part_2(INPUT, 1000_000), 840988812853);
+        assert_eq!(get_dist_sum(&Solver::parse(EXAMPLE).unwrap(), 10), 1030);
+        assert_eq!(get_dist_sum(&Solver::parse(EXAMPLE).unwrap(), 100), 8410);
+        if let Some(input) = common::input::for_test(2023, 11) {
+            assert_eq!(Solver::solve_2(&input), 840988812853);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -220,148 +249,5 @@ mod test {
 ..........
 .......#..
 #...#.....
-";
-
-    const INPUT: &str = "
-......#.....#.........................#...........#..........#.....#.......#..............#.................................................
-.....................#....................................................................................#........#........#.......#.......
-.........................................................#............................#..................................................#..
-.........................#.......#..............................#............................#.................#............................
-..................#...................................................................................#.....................................
-...........................................#....................................#.................................................#.........
-...........................................................#..........#..........................#..........................................
-............#...............#..............................................................#................................................
-...#.................#.............................................................................................#........................
-........#..........................#.................#....................#.................................................................
-.................#............................#..................................#..................#...........................#...........
-........................................#.................#............................#...................#...............................#
-...............................#..................#..................................................................................#......
-..............#.........#...................................................................................................................
-......................................................#....................................#.....#....................#.....................
-#..................#........#.............#......................#.................#.....................#.....................#............
-............................................................................................................................................
-........#....................................................................................................#......................#.....#.
-................................#............#...............#...........#............#.....................................................
-.................................................................................................................#..........................
-................#..........................................................................#................................................
-.....................#.................#..........#............................#.............................................#..............
-......#................................................#....................................................................................
-...................................#................................#..................#............#.........#........................#....
-..........................................#.................................................................................................
-............................................................................................#.........................#.....................
-.............................#..............................................................................................................
-#...............#......#.................................#..........................#.............................#.........................
-.................................#.......................................................................#...................#..............
-....#.....................................................................#....................#..........................................#.
-............................................#.........#..........................#...............................................#..........
-............................................................................................................................................
-.........................#....................................#........................................#................#...................
-.......#...........................#..................................................#.....#...............................................
-.................................................................................................................#...................#......
-.............#...............................................................#..................#...........................#...............
-..................#.........................................................................................................................
-.........#............................#........#.........#.........#................................................#...................#...
-............................................................................................................................................
-......................#..................................................................................#.......................#..........
-...........................................................................#........#............#..........................................
-............#..............#.........................................#......................................................................
-.....#............#.................................#......................................#...............................#...............#
-............................................................................................................................................
-#.........................................#.................................................................................................
-........................#..........#...........#..............#...................................#.........................................
-........................................................#..........................#.......................#....................#...........
-...........................................................................#................................................................
-..#....................................#...........................#...................#....................................................
-.......#............................................#................................................................#......................
-..............#............#...............................................................#..................#.............................
-......................................................................#.........#....................................................#......
-............................................................................................................................................
-..........#..........#.........................#............#...........................#........#..........................................
-...........................................................................#............................#....................#...........#..
-.............................#..................................................................................#...........................
-..........................................#.................................................................................................
-.....#..........#................#..................................................#......#.............................#......#...........
-............................................................................................................................................
-.#.........#..............#..................#........#..........#.....................................#....................................
-........................................#.....................................................#..........................................#..
-..............................#...........................................#................................#.........#......................
-............................................................................................................................................
-............................................................................................................................................
-......#...........................#.................#...........................#.......................#................#.............#....
-..........................#.............................................#...................................................................
-................................................................#...........................................................................
-........................................#...............#.............................#.....#......................................#........
-......................#...........................................................................#..............#..........................
-..................................................#.........#......#...........................................................#........#...
-..#..............#..........................#................................#.....#........................................................
-............................................................................................................................................
-....................................#.................................#........................#.......#......#..........#..................
-.......#...............................................................................#...................................................#
-...............#.......................................#........................................................................#...........
-...........................................#................................................................................................
-.......................#..............#............................................#................................................#.......
-............................................................................................................................................
-.#..........................#............................#..................................#..........#......#.............................
-............................................................................................................................................
-............................................................................................................................................
-............#......#.....#.......................#...............................................#..........................................
-..........................................................................................#..............#..................................
-.....................................................................#............................................#.........................
-................................................................................#.........................................#.................
-....#..........#..................................................................................................................#.....#...
-........................................................#...................................#......#........................................
-........#...........#......#......................#.....................#............#......................................................
-...................................#..............................#...........................................................#.............
-.................................................................................................................#..........................
-.........................................#............#....................#..................#......................................#......
-........................#.....................#...........................................................................................#.
-...................................................................................................#.....#..................................
-............................................................................................................................................
-.....................................#...................#...............................................................#.....#............
-.....................#........................................#.......................#.....................................................
-.............#............#.....#...........................................#......................................................#.......#
-.......#...................................................................................#..............#.................................
-#.......................................#.............................#.............................................#.......................
-...................................#............................#..............................#......................................#.....
-..............................................................................................................#.............#...............
-........................................................................................#..........#........................................
-...............#..........................#..............#..........#.......................................................................
-......#..................................................................#.......................................#.................#........
-................................................#...........................................................#.................#.............
-#...................#........#...................................................................#..........................................
-............................................#...................................#...........#.......................#.......................
-........#...................................................................................................................................
-.....................................#............#.........#......#................................................................#.......
-.................#....................................................................#..............#......................................
-................................................................................................#.........................#.................
-...........................#..............#.................................................................................................
-..........#.................................................................................................................................
-................................................#.......#...........................#........#....................#................#........
-.......................................#.........................#..................................#......#................................
-..................................#.................#....................................#.................................#...............#
-...........................................#................................................................................................
-......#.....................................................................................................................................
-......................#.......#......................................#......#...............................................................
-................................................#......#...............................................................#................#...
-..............#................................................................................#.............................#..............
-..........................................#.............................#........................................#..........................
-.........................#...........................................................................#..............................#.......
-....................................#.......................................................................................................
-....#...............#..............................................#....................#...........................#.......................
-..........................................................#.................................................................................
-..........................................................................#.......................#.........................................
-..............#.............................................................................................#...................#.........#.
-.......................#.....................#.......................#............................................#.........................
-...............................#...................................................#........................................................
-.................#......................................................................#............................................#......
-.......#...................................................#................#........................#..................#...................
-.#...........#..............#...............................................................................................................
-.....................#............................#.................#............#..............#...........................................
-...........................................#...........#.................#.................................#................................
-...............................#......................................................#....................................................#
-.............................................................................#..............................................................
-.................................................................................................................................#..........
-.......................#...........#..........#............#.....#........................#.............................................#...
-.#..........#...............#......................................................#......................#......#..........................
 ";
 }
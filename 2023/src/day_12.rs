@@ -1,105 +1,142 @@
 /**/
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
-    use std::collections::HashMap;
+use common::{memo::Memo, parse, random::Rng, ParseError, Solution};
+use itertools::Itertools;
 
-    fn count<'a>(
-        record: &'a str,
-        current_run: usize,
-        runs: &'a [usize],
-        cache: &mut HashMap<(&'a str, usize, &'a [usize]), usize>,
-    ) -> usize {
-        if let Some(cached) = cache.get(&(record, current_run, runs)) {
-            return *cached;
+/// The rest of a record, the length of the run of damaged springs just
+/// before it, and the runs left to match.
+type State<'a> = (&'a str, usize, &'a [usize]);
+
+fn count<'a>(memo: &mut Memo<State<'a>, usize>, (record, current_run, runs): State<'a>) -> usize {
+    if runs.is_empty() {
+        if record.contains('#') {
+            0
+        } else {
+            1
         }
-        let out = if runs.is_empty() {
-            if record.contains('#') {
-                return 0;
-            } else {
-                return 1;
-            }
-        } else if record.is_empty() {
-            if current_run == runs[0] && runs.len() == 1 {
-                return 1;
-            } else {
-                return 0;
-            }
-        } else if current_run > runs[0] {
-            return 0;
+    } else if record.is_empty() {
+        if current_run == runs[0] && runs.len() == 1 {
+            1
         } else {
-            match record.chars().next().unwrap() {
-                '#' => count(&record[1..], current_run + 1, runs, cache),
-                '.' if current_run == runs[0] => count(&record[1..], 0, &runs[1..], cache),
-                '.' if current_run == 0 => count(&record[1..], 0, runs, cache),
-                '.' if current_run < runs[0] => 0,
-                '?' => {
-                    count(&record[1..], current_run + 1, runs, cache)
-                        + if current_run == runs[0] {
-                            count(&record[1..], 0, &runs[1..], cache)
-                        } else if current_run == 0 {
-                            count(&record[1..], 0, runs, cache)
-                        } else {
-                            0
-                        }
-                }
-                _ => panic!("Invalid"),
+            0
+        }
+    } else if current_run > runs[0] {
+        0
+    } else {
+        match record.chars().next().unwrap() {
+            '#' => memo.call((&record[1..], current_run + 1, runs)),
+            '.' if current_run == runs[0] => memo.call((&record[1..], 0, &runs[1..])),
+            '.' if current_run == 0 => memo.call((&record[1..], 0, runs)),
+            '.' if current_run < runs[0] => 0,
+            '?' => {
+                memo.call((&record[1..], current_run + 1, runs))
+                    + if current_run == runs[0] {
+                        memo.call((&record[1..], 0, &runs[1..]))
+                    } else if current_run == 0 {
+                        memo.call((&record[1..], 0, runs))
+                    } else {
+                        0
+                    }
             }
-        };
-        cache.insert((record, current_run, runs), out);
-        return out;
+            _ => unreachable!("Records are validated while parsing"),
+        }
     }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Every row of springs with its list of damaged group sizes.
+    type Parsed<'a> = Vec<(&'a str, Vec<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    fn part_1(input: &str) -> usize {
+    fn parse(input: &str) -> Result<Vec<(&str, Vec<usize>)>, ParseError> {
         input
             .trim()
             .lines()
             .map(|line| {
-                let (rec, numstr) = line.split_once(' ').unwrap();
-                count(
-                    &rec,
-                    0,
-                    &numstr
+                let (rec, numstr) = parse::split_once(line, " ")?;
+                if let Some(i) = rec.find(|c| !matches!(c, '#' | '.' | '?')) {
+                    return Err(ParseError::at(&rec[i..], "'#', '.' or '?'"));
+                }
+                Ok((
+                    rec,
+                    numstr
                         .split(',')
-                        .map(|nstr| nstr.parse::<usize>().unwrap())
-                        .collect_vec(),
-                    &mut HashMap::<(&str, usize, &[usize]), usize>::new(),
-                )
+                        .map(parse::number)
+                        .collect::<Result<_, _>>()?,
+                ))
             })
+            .collect()
+    }
+
+    fn part_1(records: &Vec<(&str, Vec<usize>)>) -> usize {
+        records
+            .iter()
+            .map(|(rec, runs)| Memo::new(count).call((rec, 0, runs)))
             .sum()
     }
 
-    fn part_2(input: &str) -> usize {
-        input
-            .trim()
-            .lines()
-            .map(|line| {
-                let (rec, numstr) = line.split_once(' ').unwrap();
-                count(
-                    &std::iter::repeat(rec).take(5).join("?"),
-                    0,
-                    &std::iter::repeat(numstr)
-                        .take(5)
-                        .map(|nums| nums.split(',').map(|nstr| nstr.parse::<usize>().unwrap()))
-                        .flatten()
-                        .collect_vec(),
-                    &mut HashMap::<(&str, usize, &[usize]), usize>::new(),
-                )
+    fn part_2(records: &Vec<(&str, Vec<usize>)>) -> usize {
+        records
+            .iter()
+            .map(|(rec, runs)| {
+                let rec = std::iter::repeat_n(rec, 5).join("?");
+                let runs = std::iter::repeat_n(runs, 5)
+                    .flatten()
+                    .copied()
+                    .collect_vec();
+                Memo::new(count).call((&rec, 0, &runs))
             })
             .sum()
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let records: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                // The springs as they really are, with at least one damaged.
+                let springs = loop {
+                    let len = rng.range(1..=20);
+                    let springs = rng.string("#.", len);
+                    if springs.contains('#') {
+                        break springs;
+                    }
+                };
+                let runs = springs
+                    .split('.')
+                    .filter(|run| !run.is_empty())
+                    .map(str::len)
+                    .join(",");
+                let record: String = springs
+                    .chars()
+                    .map(|c| if rng.chance(0.35) { '?' } else { c })
+                    .collect();
+                format!("{record} {runs}")
+            })
+            .collect();
+        records.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 21);
-        assert_eq!(part_1(INPUT), 7260);
+        assert_eq!(Solver::solve_1(EXAMPLE), 21);
+        if let Some(input) = common::input::for_test(2023, 12) {
+            assert_eq!(Solver::solve_1(&input), 7260);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 525152);
-        assert_eq!(part_2(INPUT), 1909291258644);
+        assert_eq!(Solver::solve_2(EXAMPLE), 525152);
+        if let Some(input) = common::input::for_test(2023, 12) {
+            assert_eq!(Solver::solve_2(&input), 1909291258644);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -109,1008 +146,5 @@ mod test {
 ????.#...#... 4,1,1
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1
-";
-
-    const INPUT: &str = "
-.??.?#??##???. 1,6
-?#???.#??#?????.? 3,1,3,1,1
-??#????#??#???.?? 4,7,1
-??#?.?#?#???#?#?? 1,11
-?????????? 1,4,1
-?#?#????..?????? 6,4
-?#?#?.#?#???????..? 5,1,1,3,2,1
-?.#??#??.??????.? 6,1,1,1
-##???###?.##?? 2,4,2
-???.#??#????.??? 3,7,2
-????#??..?# 1,3,1
-.?..#????????#????? 1,5,6
-?#..??##??.??? 1,5,2
-?#???#???# 1,1,2
-#???#??..?????.??.?. 5,1,1,1,2,1
-??#??.?#??????. 2,2,1,1,1
-??????.???..??#?? 1,2,1,4
-.?#??#???#??#?????. 12,3
-??#???#?.?##?.?? 6,3
-?#?#?.??#???? 3,1,1,1
-??#???.#??.#. 3,2,1
-??#...??.. 3,2
-#?#.??????#.??????? 3,2,1,1,7
-?#?#.?????#???? 3,5
-?#??.???.?.?.???? 4,1,1,3
-????????.##?#.???? 2,1,4,2
-?#??#.??????????#? 4,3,1,3
-.#?#?.???..? 3,1,1,1
-?..##.???#????. 2,2,2
-.#??#?#??#?.? 9,1
-???.??..?#??#?? 1,1,2,1,1
-.??#??#..#????? 2,1,1,2
-.???#??..??????####? 4,2,7
-????#?.??. 1,1,2
-?#??#?#?..???#?.?.?# 1,5,1,1,1,2
-??????#???.?.??.?#?? 10,1,2,1,1
-???.????..? 2,1,2
-??.#???????? 1,1,4
-??.??#???.?????#? 1,1,1,1,6
-?..????##.##?#?????? 6,6,2
-##???#?.??#.???? 7,2,3
-????#??#??#? 1,6,2
-.???.??..#..?#??? 1,1,1,1
-?.???.?.???????? 1,1,1,3,4
-?.?????#?#.?????#??# 7,1,5
-?????##?#?? 2,6
-???.#?#????#. 1,5,2
-????...?#?? 1,2
-.????.?????#. 2,3
-?.?????##??###?##??# 1,1,11,2
-#??..?#?.???#??. 2,1,3
-#?.???????#???#. 2,1,1,1,3
-?#???..#?? 5,3
-????#???#.?#???#?.#? 2,2,1,1,4,2
-.????#??##???#?#. 8,4
-???????????#?.??#. 2,1,4,1,1
-?.#?#??.??####??? 5,8
-?#?#?#???.#.?#. 8,1,1
-?.??.????#?.??.? 1,5,2
-#??.?#...? 1,1,1
-??#?#???#??#.????.?# 8,2,4,1
-.?#?####??? 7,2
-???#??#?.??? 4,3
-..????##????????.? 8,2,1
-???#?#?##?.???.? 7,2
-?#???#?#.?.??#.?. 6,1,1,3,1
-???.?#?.?? 1,1,1
-???????#..# 4,2,1
-?.?#.?????? 1,1,2
-.????????#???##?#? 4,1,4,4
-???#?#?????#?#?? 1,13
-#????#????.#?#???#? 2,5,8
-.????#.#??. 4,2
-??#????#??? 8,1
-?.##.??#??? 2,4
-#????#?#?#?.?????#? 1,1,1,4,1,3
-????#????.?????##??? 2,5,1,1,2,1
-..???#??..?#? 6,2
-?#?.?.##???????? 3,1,2,4,1
-.?.???##?????# 1,5,1,1
-?.#????.??? 2,1,1
-?#?#?#.???..???.??? 5,1,3,3
-.?.???#??#?.???#? 1,6,4
-?#???.?#?#.????#? 5,4,1,2
-.?#????????...#??? 2,2,4,4
-?????.???#? 1,4
-????#?.????.?#??.??? 5,1,2,1,3
-????????.? 2,2
-..??#???#?#.?#? 9,1
-????????#????#????? 5,3,1,1
-#?????#??????. 1,1,3,1
-?..#?.#???? 2,4
-..#?????????#?#???? 1,13
-?.?#.???#?? 1,2
-#?.#??????????#? 1,2,1,2,3
-.???????.?#.?? 1,3,2,1
-.????.?#?????##?? 3,3
-????###?##??.??#???? 1,8,5
-#?.?####????.? 2,6,1,1
-??.##???#?????? 1,6,2
-??.??#???#?#? 1,3,1,4
-.??.???.?#?#??? 1,2,5
-??.#.?#???.##??? 2,1,1,1,5
-???.???.??.?????#? 1,1,1,1,3,2
-?#?.?#?##???#???#??? 1,11,2
-??#????.?. 1,1
-.?#??##??#???.?? 10,1,2
-?#?#?.?#.? 1,1,2
-????#???????#. 3,1,1,1
-.??#?..??.???.??? 3,1,2,1,1
-.?#??.???#? 2,1,1
-?.?#.??##?????????? 1,1,8,1,1
-?.?????.??#?? 4,1,1
-?.??.#???? 1,4
-???#???..???.? 2,1
-.??????#?#??.?.? 2,5,1,1
-#??#??..?.?.???.#?? 6,1,1,3
-.???.?#?#??.??#???? 3,1
-.??#?..???? 4,2
-?.##???#???#??#.?? 2,8,1
-????#.#.???.???# 1,1,1,2,4
-#???????????.???#.? 6,1,3,1,1
-?#?????.??##??. 1,1,1,1,2
-?..????..?#? 1,1,1,3
-.??..????.?#?? 2,1,1,3
-????????????. 1,1,7
-???.?.??????#.?? 1,1,1,3,1
-?##...?..##??. 2,3
-.???##?????? 1,5,1
-.??#?.??????#???#?? 4,7
-.?#???.?.?? 4,1
-.???###???#??..???? 8,1
-???.?????#??##?.. 3,5
-??##??#??????? 5,2
-.?.???.#..? 1,1
-??##.??#??#???. 4,6
-?#????#..##?#? 1,3,4
-.?.?.??..? 1,1,1
-??????????#??#. 1,1,1,1,2
-??##???.???.????. 1,5,3,1,1
-???????.?????#?##??? 7,9
-##..#???.?#???#?? 2,1,1,4,2
-????#??#??????? 8,1,2
-.##?##???????.???# 7,1,2,1,1
-#.?..????#?#. 1,1,1,5
-???#??#????.??? 2,1,1,1,1
-#.??#.???#?##?#????? 1,1,1,3,4,2
-???###?#?.???.??? 6,2,1,3
-???#?#???????? 8,1
-??????.#???.?..??.? 3,1,3,1,1,1
-???#?##??#?.??.#.?? 1,8,2,1,1
-??????##???.#??#?? 8,2,3
-??.??#??.??.# 2,1
-??#.???#??????#? 3,3,4,2
-#?..???????#??#???? 1,5,6,1
-.??####??.???. 5,2
-.??#???????#? 1,1,4
-.?????.#?#???#?# 1,1,3,1,3
-.??#??#???..#.?.?# 1,1,4,1,1,1
-??.??????????.? 2,2,3,1
-???#.?.???##?## 4,8
-.?###??????#??#??? 5,7,1
-??.???.??#????#??? 2,2,1,1,5
-#???????.?#.#???### 3,1,2,1,3
-.????#???..?.#?? 4,1
-???????????.?#.?? 3,2,4,1,2
-.??#.#?.?.??##?.? 3,2,1,4
-??#??.?##???#? 2,3,2
-?????###?.? 2,5,1
-#?#.?##????#???? 1,1,3,1,5
-???#??#????????## 9,2
-?#??##??##???##??.?? 2,11,1,1
-???.?#.???? 1,1,1
-#???#????????.#? 1,4,1,2,1
-?#??.??##? 2,4
-.###??#??#??#?# 3,10
-#.????#?#??????#?? 1,2,4,2,2
-??..????.? 1,1,1
-#??##?..??????? 5,1,1,1
-##????.?.##???. 3,1,5
-???##.????#? 5,1,3
-?.????.##.? 1,2,2
-?#????##??????##???? 1,2,3,8,1
-???#?????.?..? 3,1
-?#?#??.#??#?.? 5,4
-??.?###??????? 1,4,1,1
-..##????#???#???? 4,6,3
-?????#??????.???# 7,3,1,1
-??#???????. 3,1,1
-??????##??????. 1,1,2,1,1
-?????.??????. 1,1,2,1
-?.?.??.?#?????#?##.# 1,1,1,8,2,1
-?.#???????#?? 2,1,3,1
-.#????..??#?..?# 4,1,1
-.??.??#??? 1,1,1
-##??###??.??? 8,1
-????#???????.????? 6,1,1
-#?#??#??..????? 1,2,1,1,5
-.?#????.?.??#? 6,1,1,2
-????..?##.?.?.????#? 4,3,1,1,1,3
-.#???????##??.???#?# 5,6,4
-##??????#?..#??? 2,1,5,1,1
-#????????#?#.#?.? 1,1,1,4,1
-#??#???#.?????#???? 8,1,1,5
-#?.?#??#?.? 1,2,2
-?.#?#??.?????..? 4,3
-?#?#???.?? 4,1,1
-???????#????#???.? 1,2,2,2,4,1
-????#??..?.??#?#?. 5,3
-..??#?#????????#???. 5,1,3,1,1
-?.#???.???. 1,2
-??????????.?.??? 6,1,1,1,1
-?#??????.? 3,2,1
-.?.##???## 1,7
-?.?????#??##??##???? 1,16
-??#????##?#??? 1,1,7,1
-.?????????? 5,1
-??????????.? 2,1,1,1
-##?.?????. 3,1
-?#?..?.???? 2,1,1
-#..???#??#???# 1,2,8
-??##?????#????? 3,9
-???#?.?##. 3,3
-??.??#?#??##?#.# 9,1,1
-#.??#?#??. 1,3
-.??#????????????? 3,1,2,3
-??#??#???.?.?? 7,1
-??#..##????#??#????? 2,10,2
-#?###????##?..??? 11,2
-.???#.??#.?##.?? 1,1,1,3,1
-?#??????#? 5,1,1
-?.?##??#??????? 5,4
-.?????.?#??.. 2,2
-.??.?#####?????##? 7,4
-??????.#?.. 1,1,2
-??#??????..?? 3,1,2
-??????????# 4,2
-?.?######???. 1,7,1
-????#?????????# 10,2
-.???#?#?#? 1,2,4
-?##????.???#?#?? 5,5
-.??#??.?#?#?.##? 1,4,2
-#.????????#????. 1,6,2,1
-?###????.?#?#??#??.? 6,9,1
-?..??..??###. 1,2,5
-?????#?????????.??. 1,1,3,6,2
-?.?#??.??? 1,1,2
-.??.?????????. 1,5,1,1
-????#??.?????## 3,6
-##?#??.?????. 4,1,1,2
-??#????#??.????#?. 8,1,2
-.???????#.?.?#??#??? 1,2,2,1,7
-?#.#???.?#?????# 1,1,1,1,5
-??#?##?.??# 6,3
-??????.##??#??###? 1,1,1,5,4
-???#???????##??. 2,1,1,2,1
-?????#..????#??##? 6,1,3,3
-????#??#??#??????##? 11,3
-.##??##?????.#?#?? 11,1,1,1
-?.?#?.?????.?? 3,4
-###?.????#?? 3,1,3
-????..?##?.??????#?? 3,2,9
-?#?.???#??#. 2,1,4
-??##?#?.#.???#??? 6,1,1,1,1
-#??????.???.#?#??. 4,2,1,1,3
-.?#???????.##? 7,2
-???#???.?.?#?#?. 4,1,5
-?.?????#.??#???# 6,3,1
-.??#??#??.?#??? 6,1,1
-??#????????. 5,1
-????????????? 1,4,1
-?#??#?####?.??.?#... 10,1,2
-#????#??????#???.?? 1,1,4
-?.???.???### 1,1,1,4
-?####??.?.??????. 7,4
-#???.#?#?#?#.?#?? 2,7,2
-???..?.??#???. 1,1,6
-???????.????####??.? 4,1,10,1
-??.?#?????????..?.# 2,11,1
-???.???#??.. 1,1,3
-?.?#?#??#????#?? 1,2,2,3
-???#????.?#??. 3,1,1,1
-.????????? 1,1,1
-?.?.????##?###??.?? 1,11,1
-?#????.#?#??? 1,1,1,2
-#.?.?..#?#?#????? 1,1,3,4
-?#?.??..?##?##????? 3,10
-?????????#????.#???? 1,7,1,2,1
-?#????????.#?# 1,4,2,3
-.????????#?. 6,2
-?.?#.??##.#??.?#?? 1,2,1,2,1,2
-.?????#??.?#?? 2,3,2
-#?.##?..#.???????? 2,2,1,5
-?????.?#????? 1,1,1,3
-???????#?##??#??? 1,10
-???##?..#? 1,3,2
-???#???.???.?? 2,1,1
-#?.#..?.?? 1,1,1
-????#???#?. 2,6
-##.?.????#??.?#?## 2,1,1,1,1,5
-??...?#??##.#???? 2,5,1
-??????..?#???? 3,1,1,1
-?#?.?##???#?.???? 1,8,1
-???##?#???????.#? 5,6,1
-?????..?#????# 1,1,2,2
-?###..??#? 3,4
-..?#??#????# 5,3
-???.???#????. 1,1,6
-??#?.??#?.???. 1,3
-?..????...?.????? 4,1
-#.??#??#?.#??#??#?# 1,6,1,7
-?.????..?.?? 1,3,1,2
-.??#???#??????#?.?# 1,1,10,1
-.#.?.??#?.?.#?? 1,1,2,1
-?.?.??.#???#? 1,1,1,3
-?.???.???.?? 1,3,2,2
-.????.??????. 1,1,1,2
-?.?#??????.??#? 1,5,1,1,1
-?????????#. 1,2,1
-.?.?#??##???? 1,6,1,1
-?#??#??.#? 1,1,1
-??.???????#??#.? 2,1,1,5
-#?.#??.?.##..##?.?. 1,3,2,3,1
-????????#?.##?#? 2,1,2,5
-?#?????.#?#.??#? 3,1,1,1,1
-????#?#?????#? 2,3,4
-?.???????#. 1,3,1
-???????##?#???.????. 14,3
-??????#?.#?#?? 6,4
-?##?#.??##?.# 5,4,1
-#??.???###?????? 3,1,3,1,1
-?#?#??.???#?#??? 5,2,4
-.???#?.#?####. 1,1,6
-.???.?#?#??.# 2,1,2,1
-#.#??#????? 1,6,1
-?.??.???.. 2,1
-?.??.#?##.?????#???. 1,4,8
-.#?#?#?#???..?#??# 9,4
-.#.?#?#??.# 1,6,1
-#?#.???#?????? 3,1,7
-??#?###??#?#??#??? 1,6,1,1,2,1
-??.?##???#?#?.? 1,10
-.#?##???#???##???..# 1,11,1,1
-??.#?.????#???# 1,1,7,1
-#????????#? 1,6,1
-????.#?.??#???#.?# 1,1,1,4,1,1
-.???#?#??.?.???# 7,4
-???##??????..## 8,2,2
-?#?#?.?.?##?## 5,5
-?.?..???.. 1,2
-.?.????..# 1,3,1
-#??????#??.?#?###??? 1,1,1,1,1,8
-.??#??.?.???? 4,1
-??????##?.. 2,3
-?#.????.#?# 1,1,3
-.?????..?????#. 2,1,2
-????#?#?##.????? 10,1
-?????.????#?? 3,1,1,1
-.??????.??.??..? 6,1
-?????????#??#???# 1,2,3,5
-#??####??..??#?.. 9,1,2
-?####??##?.????? 9,1
-?##??#??.???#. 6,4
-#.#?????????.#????? 1,1,1,1,1,5
-??#???????###??#??? 5,8
-#??##?????#????#? 1,7,1,1,1
-????????#?.????.???. 1,1,3,1,3,3
-??.??????? 1,2,1
-.?####???.???#?.? 4,1,3
-.???##??#?????.???? 13,1
-???.??#??#?????????? 1,1,3,7
-?????..??.?? 1,1,1,2
-?#???#??#??#???.??? 7,6,1
-???#???#?#??????. 6,1,2,1,1
-???????.?????? 1,3,2,1
-..#???#???#????#? 1,10
-??##??##???.???# 6,1,1
-#?#???###?##?.?????? 1,1,1,7,1,1
-??#?.?#?.???? 1,2,2
-.?..???#.? 1,4
-#??????#.?#?.##???## 3,1,2,7
-...#??#??#?##??.. 10,1
-.#??.??????????? 3,2,1,1
-?#??#?#?#????###??. 2,15
-?#????#??.?????? 2,4,6
-??.??.????##. 1,1,1,4
-.??#???.?.?????? 4,1
-?#??.??##??#??.? 2,8
-????#?#??#???##????. 1,15
-.???????#..?#?? 2,4,2
-?.?#?#?.#.?.??? 1,2,1,1,1
-???????#?##?#??? 1,6,2,1,1
-#???#####?.???#??. 10,4
-??????#.?????.? 2,3,4
-?.?#?#?????.? 4,1
-?.#?#?..????????#. 1,4,1,3,1
-.?????????#??##?? 3,1,7,1
-#.?.?.???##.??? 1,1,1,2,1
-?#.??##??.?..#? 2,3,1,2
-?#.?.????????#? 1,2,6
-???#?.??##?#???#??. 1,2,1,10
-???????.?#????? 6,3,2
-????#?.????.??.? 1,2
-#???.??#.??? 1,1,3,2
-???.??#??#????? 1,2,3,1
-?#?#????#?.?? 2,1,4,1
-.#???????#??#? 3,1,5
-?#.???.#.????#? 1,2,1,5
-.???#???##.??????.# 6,2,1,1,1,1
-.??????????? 1,1,4,1
-..??????????#?#?#?#? 6,10
-???.#????#.# 1,3,1,1
-#???????##?#..?? 1,1,5,1,1
-??#???#?#?.??...??# 10,1,2
-?#???#??..?????#???. 7,3,2
-???????????#??. 1,2,1,1,1
-#????#???##??.?.?#.? 1,11,2
-?????.??.???.?# 5,1,3,1
-?.#?#?#?#?#?#??? 1,1,1,3,5
-??.????.???? 2,3
-???????#??#? 1,3,2,1
-???.??#???#? 1,4,2
-??.??#?#.#????? 1,1,1,2,3
-???#????.?????.?.??? 5,1
-???#?.???##???####. 2,10
-.??#..?.???? 2,2
-?#.???#??##??? 1,7
-?????????##?? 3,1,3
-.?#?#?.?.#? 4,1,2
-.?.?#??.????#?? 1,2,1,3
-?#?#?????.??#?.??? 7,3,1
-.#???????#????.#..?? 3,4,3,1,1
-?.?#.????.#??#???# 1,1,1,1,6
-??##??#???#?#..# 4,7,1
-????#????. 5,2
-??#?#?.?.?..??? 2,1,1,1,1
-##????#?##??? 2,1,1,5
-??#..?#?##??? 2,8
-?.??#??????#?? 6,2
-??.?.???.??? 1,1,1,1
-?.?????#??? 1,4
-??#???????#??????? 1,7,1,1,1,1
-????..??.?????.#?? 2,1,1,1,1,3
-?#?#??.???????? 2,2,6,1
-??.??#?????##?? 2,2
-???.???????????.?? 1,2
-??##??????#?#??.# 2,1,2,3,1
-??????#??#? 3,1,1
-??.?????##????##??? 2,14
-?#?.?..??? 1,3
-?.??????#? 2,1,1
-??.??##.?? 2,2,1
-.??.??.??? 1,1
-.?#????#??. 2,3
-???????#???.#? 3,2,1
-??#???..?? 6,1
-...?#???###????? 2,4
-#?.?.???????? 1,1,1,2
-?????#????#??.?? 7,2
-.?????#??.???????? 4,3
-?###??.?.?.?##??#?? 3,1,1,7
-??#?#?##??...??? 9,1
-?????#?#?????.?##? 12,3
-???????????..#??. 8,3
-?#??#?##???#??? 7,1
-??????###??## 1,10
-?#??????#????#####.? 2,2,1,8,1
-.?###???..??#???.? 4,1,3
-?.???????? 1,6
-???#??#.??#?##??.? 4,2,7
-??.#??#??##??#?##. 2,2,11
-??.??#?#... 1,4
-.?.#?#?.?????#? 1,4,5
-.?????.???#?#?#??#? 4,9
-??.#?????#?#????? 1,12,1
-???#???#????.#???? 1,7,1,1,1,1
-??.?????##?.?..#?#.# 1,1,3,1,3,1
-.?#??##?????###?# 1,7,3,1
-#???????????#????#?? 1,2,1,1,1,7
-####??##???#??. 9,4
-???#???#?.??#? 4,3,1,2
-???..#???? 1,1,1
-?#????#?.????.??#? 3,1,1,2,1
-.?#????##? 4,4
-??.???.??? 1,2,1
-.??##??.????#??? 3,1,8
-????????#? 1,4
-.#????#???????.?.? 6,1,2,1
-??.??????##??#?#?#? 1,1,8,3
-??????###? 1,3
-???#..#??#.??.?#? 4,1,2,1,1
-?.##??#????..##?? 7,2
-#??#????#?? 5,2
-.#??.?#?#???? 2,3
-?.??#??##?????.??#? 3,2,2,3
-?.#???##????.? 10,1
-.##???.#??? 5,2
-??#?????.?#.???.?# 2,2,1,1,3,1
-?#?????.#??#???#???? 1,10
-?##?#..???#??#??#??? 3,1,1,6,1,1
-?.?????#?????????.. 2,3
-????#??#????#???. 1,12
-.#?????#????????###? 1,11,3
-#?#??.??###?? 3,1,3
-.#?????.??##???#??? 3,10
-.#?.??#??? 2,1,1
-.???##?..?. 5,1
-?.??#??.???? 2,1,2
-?.??.#?.????#?????# 1,1,2,1,9
-.??????#?#????.?#?. 8,1,3
-?##???????.#. 3,3,1
-#???????#.???? 9,3
-?#???.##??#??? 4,7
-????.?#??.?.#?#??? 2,4,5
-.?????#?#??#?????# 7,1,1,1
-#??..???#??#? 3,4
-???.??##?????##??#?# 3,7
-?#?#.??.?#.?#.? 1,1,1,1,1
-#.??????????? 1,4,3
-...???????##??#??#.. 1,12
-.???###????#??##???# 1,15,1
-???.?.?#?##..#? 3,5,2
-..??????.??#??. 2,2,4
-##???#?#?????? 8,3
-???.???#??? 1,4
-???.??.?#?????.#???? 2,1,3,2,1,1
-???#?#??#.? 1,6,1
-??###?????#?##.???# 10,2,1,1
-???##???..#??? 4,2
-?##??#.?#?##? 5,5
-??????.?#.?.?.? 1,1,2,1,1
-???.?#?#?.?.? 1,1,2,1
-???.??#?.?? 1,2,1
-?#????.?#.??? 5,1,1
-?.???.??#? 2,2
-??.??#??????#????##? 1,16
-????.????.??.?.#?#?? 1,4,1,1,1,1
-.??#??????? 1,3
-???.?????.. 1,1,3
-#??#?..???#?..?? 4,1,2,1
-??????#.????? 7,1,2
-?###???..?#?? 6,2
-?????????#??#.?? 1,1,4
-.????#????. 4,2
-.?#.?..??#.?##??# 2,1,2,2,1
-#?#?????#??#?.?#.#? 6,1,2,1,1
-????.#???#???? 2,1,2,1
-???#????????..#?? 8,2
-??##?#???.?#?..???. 6,3,2
-????.??#??#.?#??? 4,6,3
-???##??##?? 5,3
-???????.#..#???# 3,1,1,1,3
-?????????? 2,1,1
-#??#????#?#?????#? 11,1,3
-#??#??#?.???##??? 1,2,2,7
-#?.???#?##?##?? 2,1,9
-..??..??..?? 1,2
-.????.?#?#?.?. 4,5
-#???#??#???.???#??? 2,4,1,1,1,1
-.??#..??????#???#?. 3,1,9
-#???????#.????? 2,1,1,2,1
-??????##????#??.??. 8,1
-???.??#?...?#?#? 3,4
-?.??.#??#? 1,1,4
-?.??#?#??.??? 1,4,2
-?##??#??.????.??? 6,1,4,1
-??.?????#??? 1,3,1,1
-?.?????????#?#??? 5,8
-?.?????.#?##?. 1,1,1,4
-.?????##?#??.? 11,1
-.?.??#?????????# 1,1,1,5
-#?#????..?#??#??? 6,5,2
-?#??#??#?#?.???#??? 10,2,1
-.??????#.? 4,1,1
-????.?#.????? 2,1,4
-???????.?##? 2,4
-?#????#???? 4,4
-?.?#.???.?## 2,1,3
-?.????#...???..??. 4,1,1
-?#???#????#. 8,1
-?#.??????.?####?. 2,2,2,5
-??#?????#.?? 5,3,1
-?#????#??????#..?# 7,4,1,1
-#?????.???#?##?? 1,1,1,2,4
-##?#????.???. 8,1
-?.???????##??? 3,6
-???????##??#???. 1,7,1,1
-#?????#?.??##??????# 1,5,7,3
-.???????.# 2,1,1
-??????.?#?? 3,2,3
-??????#????. 7,1
-??#?.??.??? 3,2
-..??????#?#???#.? 9,1
-##?#??..#.? 5,1,1
-?#??#.????.???? 4,1,1,1,1
-?????#.#.?.??? 2,1,1,2
-???###?..??. 4,1
-??####?#?????#? 7,1,1
-??###?#???.#???????? 9,1,2,2
-??.??.?..####?#??? 1,6
-.???#?????????????# 3,2,1,1,1
-??#.????.????????? 2,3,5,1
-.?..?#?#??????#??. 1,10,1
-#?.???#..???. 1,4
-?#????????#?.. 5,5
-???#.#??#?##???##. 3,7,4
-.??###?#?.?????..?? 6,4
-???.???##???.??# 3,1,5,1,1
-..????.???? 2,1
-?#..?##??.#??#? 1,4,2,2
-????..??#?#?? 1,1,2,3
-#???.???????? 2,4
-..??????#??.???.??? 7,2,1
-?.#???????.. 3,2
-??????##????? 9,2
-??.#.??.?#???#?# 1,1,3,3
-????.#.???? 3,1,1
-#??.?##??#??#.?? 1,1,9,1
-?.????..??.???? 1,3,1,1,2
-??.???##??.#????? 1,5,4,1
-???.??.?????.?.???# 3,1,2,1,1,4
-?#.#???..??#??#? 2,1,1,4,2
-?.??.?..?????.???. 1,1,1,5,1
-.?#???.??.????????? 4,1,1,3,1,1
-???.?#.???. 2,1,3
-???.?#??????# 1,3,1,2
-????????.?????. 5,4
-??#.??.??? 1,1,3
-??#?..?#???#?.? 3,4,2
-????#??##???????? 7,1
-#??????##?.## 1,1,3,2
-???#.?????# 3,4,1
-#?#?.??????# 1,2,3,1
-?.??#..#..?? 2,1
-?????????.?????..? 7,1,1,1
-?????.???.??#??.??# 2,1,1,1,3,2
-?.?.??#?.#?# 1,1,1,3
-????????#.? 6,1
-#.????##???????? 1,5,3,1
-..???#.?.#?#??#??# 4,1,4,1,2
-.?.#?.??.??? 1,2,1,2
-??#??.?#???.. 2,3
-???#??#????#? 7,2
-??##?.??#? 3,4
-?.????#????.#?#? 1,2,3
-.???.???#?#????? 2,5,1
-.##.??.#.##? 2,1,1,2
-??????#??????##?.? 1,4,3,4,1
-???????#???.?#?# 6,4
-??..?????????.#??#. 1,8,1,2
-????????.? 1,2,1
-??##??##???#?.??#??? 3,6,6
-.??#?#???????? 3,2
-.??????.??##???..? 5,4
-????????..? 2,4,1
-.#?????#?#??????? 1,9,2
-?#####??#???.#?? 7,1,1,1
-#???.?#.#.?#?#?###?? 1,2,1,1,8
-.?#?????.??????? 6,4
-????.?.??##.????#?. 2,1,3,1,2
-???????????????#. 5,1,4
-.?..?.?##????? 1,1,4,2
-..???????? 2,2
-??.??#?#.? 1,5
-?#???.#####??? 3,5
-??..#?#?#???#??? 1,3,8
-.????????.???#? 2,1,1,1,2
-?????.#???????#.#. 1,2,1,2,1,1
-????#???#??#.?? 6,5
-??.?#??#??#?.??#?#?? 5,2,4
-???###??#?#????. 8,5
-.??????##.????#??.# 1,5,1,3,1,1
-.?????..?????? 1,1
-??##?.?.?? 3,1,1
-???#??###??????##??? 1,6,8
-.????#.???????.?#?#? 1,1,1,6,1,1
-???#..?#.?.?? 4,2,1
-?.?.#.?#?## 1,1,5
-?#???.?#?? 4,2,1
-?#?#?#???#?.??#.??# 5,2,1,1,1
-.???#?.?##?? 1,5
-?#???.?#.? 4,1
-.##??#?#...?? 7,1
-?##?.?.?.?#?..??. 2,3
-??#?#.??#?????? 4,2,2
-?#?###?.????.? 7,1,2
-#?.#?#?.?#?.#? 1,4,1,1
-...#?#???.???? 4,1
-.#?????#.?##??? 1,1,3,3,1
-.??#????###?????? 3,8,1
-?.???#??????????#??? 4,9
-.##?????#?? 5,1
-??.????#?????.?.. 1,10,1
-.??#?#?????..#.?.??? 2,3,1,1,1,2
-??????#???? 2,3,2
-??#??????#?????# 6,1,1,4
-???????.?#.??#?# 2,2,2,1,1
-?#.????#?? 2,6
-??#????????.. 5,1,2
-?????#????#??#?.?#?. 8,5,3
-??##????#?..? 5,3
-.?????.????#? 3,4
-...?.?????????#???. 1,8
-#?????#??.. 7,1
-.??#????????? 4,1,1,1
-?#.??????.???? 2,1,2,1
-#???#?#?#?????????? 9,1,1,1,1
-??.?.??..??#.?# 2,1,1,3,2
-?#?#??#???#.????.?? 10,1,2,1
-.???#.?????.? 3,3
-???#??##??.????#??#? 8,1,1,1,1
-????..??????#?? 1,2,2
-?#.?.?#..#??#?.? 2,1,1,5
-????###.#..? 1,4,1
-.????????? 1,1,1
-?.?...????? 1,2
-??#??.??##?? 3,6
-?.????#??#?.#?? 1,9,2
-?###??????#.???. 11,1,1
-?????#.?????? 3,1,1,1
-?.##..?????.?#..?? 2,1,2
-??#??#?????..? 4,1,1
-?##?#?#??.??#. 5,3,3
-.??.#????#???#???? 2,1,7,2
-?.#???????????????? 1,1,9,3
-.???#????##.? 1,2,2,1
-?#?.?.???#??#?..?.?? 1,1,6,1,2
-??#?????????#??? 4,1
-??????.??###?????.#. 3,1,1,3,4,1
-?????#.#.?.?#? 5,1,1
-????####..?#..#?# 7,2,1,1
-#???..??????...#?? 2,1,6,1,1
-?????#??.? 5,1
-.??#????#? 4,1
-???.#???.??#? 4,2
-.#????#??#?????? 3,3,1,2
-?????.??????.???? 1,1,3,1,2
-?#.##?#?.#####??# 1,4,8
-??.#????...?### 1,1,1,4
-#??#?#??#?.? 6,2,1
-????#???#?? 5,1
-#???#?#????##.?.? 13,1
-?###????#???. 4,6
-????????##???????. 1,4,2
-#?.????#.???? 1,1,2,2
-.????????#? 1,2,3
-?????##?#.??##?. 8,4
-?#??#?#????? 1,1,1,1
-????#??#?#? 1,5
-#??????#?#??????#??# 4,8,3,1
-?.?###??.????????? 3,7
-??#???#?????.?###?? 1,1,2,1,4,1
-?.??..?.??.????##.?? 2,1,1,6,1
-??#??????? 1,5
-???##??##???.#??#?# 8,1,6
-.?.??#?##?#?? 6,1
-????????##????#?# 1,1,9,1
-????.#?##?.?? 3,5,1
-??#??.????..?. 4,3,1
-#??#??????#??? 1,1,1,3
-###?#.?..#? 5,1,1
-?????#.??????#?#? 1,2,1,5
-#.???#??#. 1,3,1
-.??#?##????? 6,1
-?????#????..?? 7,1
-???#??#?#??#??.??? 1,2,3,2,3
-??????..#????#??.? 2,1,1,2,4,1
-?#.##?##??????.? 1,5,1,3,1
-??#??##????.????# 8,1,1
-???????#.#??#? 1,3,2,1
-?##?.?#???#???????? 2,2,3,2,1
-.#??..??.????#? 3,2,1,1
-???????.??????#.###. 3,3,1,5,3
-??#.#??#????. 2,4,3
-#.#??#????#??##????? 1,1,2,1,6,3
-##?#?.#?#.#.??? 5,1,1,1,1
-??????????##??#.?# 5,2,2,2,2
-??#.??#?????#?#?? 2,7,4
-???.???????.??#? 1,3,2,3
-?#.#??...?#.# 1,1,2,1
-???#?????#?#???#..## 3,9,2
-#.???????#. 1,7
-.??#????.?.????.?? 2,2,1,1,1,1
-?????..??. 1,1,1
-??#.???????#.? 2,5,1
-?????????? 3,1,3
-#.?.??##??.? 1,1,4,1
-??.????????#????? 1,2,3,1,3
-.#####????????? 6,1,2
-##????##?.?#.??? 9,1,2
-??#??????? 2,1,1
-????#?????????.?#. 14,1
-?##???##?.##??. 7,4
-#.?#?##??#?####???# 1,14,1
-.?##?.?????# 4,1,2
-.?#.#?.???# 1,1,2
-.?..???????#?.?# 1,1,6,2
-??##?#?????##??.???? 6,2,3,2
-??#??????.??#?? 7,2
-#.?.?..#?.??#?#?#. 1,1,1,1,5,1
-?.?#???????? 2,4
-????#??..???#?#?. 1,4,1,1,1
-???.?????# 2,6
-???#???##..?????..?? 2,3,3,1
-??##?##?#???###? 5,7
-.??#??##??#?. 2,5
-.#???#?#?????# 1,11
-?#?#?.?????.????#?? 2,1,2,1,7
-??#?????????# 3,1,1,1
-.??.????#? 2,2,1
-????????#????? 1,7
-?????.##???#?#? 1,3,3
-????#..??#??? 1,1,2
-?#?.?.#???# 2,2,1
-#..??.??????#. 1,2,1,4
-.##?.??#.?#.?? 2,3,1,1
-..?#???#???###.????? 12,1
-????.????#? 2,1
-???#.?.??? 1,1,1
-.??.????.# 1,1,1
-????.???.?.???##??? 3,1,6
-??#????.??.? 4,1
-#.?..###?? 1,1,5
-?????????..#.? 1,3,1,1
-???.###?#??.?. 1,6,1
-????????????#?##???? 3,1,9
-??.??.???? 1,2
-??#?.?????.???????? 4,5,2,4
-????#?#.?#####??. 5,7
-?.#?????#??? 1,3,1
-#????#??.?? 1,1,1
-.??????.????# 3,1,2
-??#?#?????#?.???#### 10,6
-?.?????#?..? 1,4
-???#??.#??#??? 2,5
-##?#?.#?##??##?????? 4,8,3
-???#???.??????## 3,1,6
-.???#??.??. 1,3
-.???#?.??.?#??? 1,2,2,1
-??#?.??#.?#.??##??? 3,2,1,5
-?###??#???###?#???.? 8,8,1
-###?.?.???..??.? 3,1,3,1,1
-?#?.?.##????#???? 1,1,4,2,1
-???????.?? 1,4,2
-.?#???###??? 1,4,1
-#.????.???#.# 1,1,4,1
-??#?#????.?#?.? 1,5,1,2,1
-.?#?#?#?##? 4,1,3
-????????????#?#????? 1,1,8,1,1
-#.????????? 1,2,2
-?##??.???? 3,1,1
-????..???.?.###?# 2,2,5
-#?#????##.#?? 5,2,2
-???.#????##?????..#? 1,1,6,2,1
-.#??.??.?? 1,1,1
-#????#??##??? 1,2,4
-?#????.??????.? 5,4
-??.?#???#?..?# 1,5,1
-?#???????????#???? 4,1,5,1
-???????#?.? 5,1
-?#??#?????.#??.#?? 5,1,1,1,3
-???.??????.?? 3,1,2
-?.???.#??? 2,3
-?.#.?????? 1,2,1
-#??#.??.#???. 1,2,3
-#???.#.?.?#?. 1,1,1,2
-?##??????.?#.?? 2,4,2,1
-.?#??#.?#??????? 4,4,3
-#????.???.??#???#.? 2,1,2,4,2,1
-?????.?????# 1,1,5
-#???#.#?.?????????? 2,2,2,9
-?#????????.?#.#? 7,1,1,1
-##?.????#?.?#??. 3,4,3
-???##??#????#?????? 11,5
-.??.??#??##.?? 1,7,1
-#??##???#.?.? 5,2,1,1
-???.??#????##??.?# 2,1,1,6,1
-??##?#????? 3,3
-#.?#??.????#??# 1,3,1,1,4
-..##?#??.?#? 5,2
-??.??#????. 1,4
-???#?.????? 4,2
-#?..????.?#??#?#?#? 1,3,9
-????##???#.?#?? 7,1,3
-?????#???????.?? 1,5
-??.??.????##??#???? 2,10
-??#?###?.?# 3,3,2
-???????#???.??.. 7,1
-??????????#.#?? 4,1,1,1,2
-?#?#?#??#??.??##??#. 9,5,1
-?.##?????.? 2,4,1
-#.???.???#??#?#?#??? 1,1,12
-?.?.?#???#?#?#.?#. 1,1,1,4,1,1
-???#?#???##????#??? 6,4,4
-?.????##..??.## 1,1,3,2,2
-.????#.?.??? 5,1
-?????..?.??#.?#? 1,2,1,2,1
-?.??#?????.?#? 1,5,1,2
-.????##?????#?? 1,4,2,3
-??.??.???.?#?? 1,3,3
-?.#????.???#??.#???? 1,1,1,6,1,1
-..#?????????##???## 1,4,4,3
-###??.##??#?????. 4,2,6
-?..?#????.??.?? 1,2
-.?#?.#????????.??? 3,1,3,1,1
-?#?????#?##?.###?#?? 3,7,5
-##??##??????#??..??? 13,1,1,1
-..#??.?#????#??? 1,2,6
-?????#?#?????##?.##? 1,7,1,3,3
-?##?#?#?##? 6,2
-.??#??..???? 4,2
-.#?##????.???. 1,3,1,1
-??##??.?#.? 4,2,1
-???.???#?# 3,2,1
-.????..?##???#??#. 2,10
-?##?????#??.## 5,2,2
-??????.??? 3,2
-??#???..?.??? 1,1,1,2
-#???????#?#?????# 1,1,11
-?##??????#?.??? 4,1,1,1,1
-?..??#???#????.?### 9,4
-?????????????#??.?? 1,1,1,4,2,1
-??#.#.??????.????? 2,1,1,1,4
-?.....?????? 1,1
-#???.??##? 3,2
-???.???.?#? 1,3,1
-??.???.??..? 1,1,2,1
-#??#???#?#??##??? 4,4,2,1
-.????????. 1,3
-.#?????.???#? 2,1,2
-?.?.???#..?. 1,2,1,1
-?###????#.??????? 7,1,1,1,2
-?##?#????###??.?. 6,3
-???#?.?#??. 3,1
-??#???#?.?.? 6,1,1
-?.??????????? 6,1
-...???##???.?. 5,2,1
-???.???.?.???## 1,1,1,1,3
-???.?##??#? 1,2,1
-###???.?#??#?? 6,5
-...?#???.??????#? 3,2
-??????#?????????? 8,2
-.?????#??#.??.#? 1,5,1,1,1
-###???#??#??????? 4,4,1
-.????###?# 1,3,1
-??#????#?#?.??? 1,3,1,1,2
-.#???#?##??#?#?# 1,12
-##.???#??.??..# 2,1,1,1,1
-???#.##???? 1,3,1
-???###???.?.##??.?# 1,7,1,2,1,1
-?.#.?#?#?..?..? 1,1,4,1
-???.??.?#?? 1,3
-??????#???#. 1,3,1,1
-?????#.#????#??? 5,4,1,1
-.?.????#???. 2,3
-???.??.?## 3,3
-?????#?.????#?#? 5,6
-???#?.???#?? 2,6
-...?????#.? 1,3
-#???#????#? 1,2,1
-??##????????###??. 6,1,4
-??#????#??? 1,3
-??#???#??????#?#? 7,7
-??####.?.?? 6,1,1
-.?.?.#?#??.?????? 4,2
-#???..#???.?? 4,3,1
-??..?#??#???##? 1,6,3
-??????#?#? 1,1,4
-?.???#?.???????? 1,3,2,1,1
-????????##????? 1,5,1
-????#?#?#???#.?.#? 11,1,1
-?????#??##??????# 1,3,3,3
-?.???.?#.????#?#??# 1,1,1,1,8
-.#?#??????#?????. 5,3,2
-.??###?????#??. 3,2,3
-?????#?##??#??? 1,1,1,2,4
-??#?.#..????#?.?#? 3,1,6,2
-?##??.????? 2,3
-?????#?###??#? 2,8
-.??#?#??.????.? 6,2,1
-????#?.?#???##??? 1,2,6,2
-??????..?#..? 2,1
-?.?????#???????? 1,4,2,1
-#.??????.?????#? 1,2,2,6
-#?.?.??#?.????? 2,1,1,2,3
-?.????#???#??????.#? 12,1
-?#????#??#.???#.##? 7,1,1,1,2
-?#???#????????.?? 5,2,4,1
-???????????# 3,1,1,3
-.???????.? 2,1,1
-?#????#???????? 2,1,3,1
-.##.?#??.#.?# 2,1,1,1
-??#???#???.????. 5,1,1,1
-..????..?????? 1,3
-#??#??#????.?.# 5,1,2,1,1
-??#.?#????.. 2,3,1
-?#.??????? 2,1,1
-????.?#???#?? 1,2,6
-????#?#?..?#? 4,1
-??#?#????#?#????. 8,7
 ";
 }
@@ -146,110 +146,203 @@ In each pattern, fix the smudge and find the different line of reflection. What
 summarizing the new reflection line in each pattern in your notes?
  */
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
+use common::{
+    combinator::{parse_all, sections},
+    random::Rng,
+    ParseError, Solution,
+};
+use grid::Grid;
+
+/// Number of cells that differ from their reflection about the line after
+/// `line` rows.
+fn differences(pattern: &Grid<u8>, line: usize) -> usize {
+    let rows: Vec<&[u8]> = pattern.iter_rows().collect();
+    rows[..line]
+        .iter()
+        .rev()
+        .zip(&rows[line..])
+        .map(|(a, b)| a.iter().zip(b.iter()).filter(|(x, y)| x != y).count())
+        .sum()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Every pattern of ash and rocks.
+    type Parsed<'a> = Vec<Grid<u8>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    fn part_1(input: &str) -> usize {
-        fn symmetry_rows(grid: &[&str]) -> usize {
-            match (1..grid.len()).find(|&i| {
-                let left = &grid[..i];
-                let right = &grid[i..];
-                let len = usize::min(left.len(), right.len());
-                let left = &left[(left.len() - len)..];
-                let right = &right[..len];
-                left.iter().zip(right.iter().rev()).all(|(l, r)| l == r)
-            }) {
-                Some(i) => i,
-                None => 0,
-            }
+    fn parse(input: &str) -> Result<Vec<Grid<u8>>, ParseError> {
+        let pattern = Grid::parser("'#' or '.'", |c| matches!(c, '#' | '.').then_some(c as u8));
+        parse_all(sections(pattern), input)
+    }
+
+    fn part_1(patterns: &Vec<Grid<u8>>) -> usize {
+        fn symmetry_rows(grid: &Grid<u8>) -> usize {
+            let grid: Vec<&[u8]> = grid.iter_rows().collect();
+            (1..grid.len())
+                .find(|&i| {
+                    let left = &grid[..i];
+                    let right = &grid[i..];
+                    let len = usize::min(left.len(), right.len());
+                    let left = &left[(left.len() - len)..];
+                    let right = &right[..len];
+                    left.iter().zip(right.iter().rev()).all(|(l, r)| l == r)
+                })
+                .unwrap_or_default()
         }
 
-        fn symmetry_cols(grid: &[&str]) -> usize {
-            match (1..grid[0].len()).find(|&i| {
-                let (lbegin, lend) = (0, i);
-                let (rbegin, rend) = (i, grid[0].len());
-                let len = usize::min(rend - rbegin, lend - lbegin);
-                let (lbegin, lend) = (i - len, i);
-                let (rbegin, rend) = (i, i + len);
-                grid.iter().all(|row| {
-                    row[lbegin..lend]
-                        .chars()
-                        .zip(row[rbegin..rend].chars().rev())
-                        .all(|(l, r)| l == r)
+        fn symmetry_cols(grid: &Grid<u8>) -> usize {
+            let grid: Vec<&[u8]> = grid.iter_rows().collect();
+            (1..grid[0].len())
+                .find(|&i| {
+                    let (lbegin, lend) = (0, i);
+                    let (rbegin, rend) = (i, grid[0].len());
+                    let len = usize::min(rend - rbegin, lend - lbegin);
+                    let (lbegin, lend) = (i - len, i);
+                    let (rbegin, rend) = (i, i + len);
+                    grid.iter().all(|row| {
+                        row[lbegin..lend]
+                            .iter()
+                            .zip(row[rbegin..rend].iter().rev())
+                            .all(|(l, r)| l == r)
+                    })
                 })
-            }) {
-                Some(i) => i,
-                None => 0,
-            }
+                .unwrap_or_default()
         }
 
-        let input = input.trim().lines().collect_vec();
-        input
-            .split(|line| line.is_empty())
-            .map(|lines| symmetry_cols(lines) + 100 * symmetry_rows(lines))
+        patterns
+            .iter()
+            .map(|grid| symmetry_cols(grid) + 100 * symmetry_rows(grid))
             .sum()
     }
 
-    fn part_2(input: &str) -> usize {
-        fn symmetry_rows(grid: &[&str]) -> usize {
-            match (1..grid.len()).find(|&i| {
-                let left = &grid[..i];
-                let right = &grid[i..];
-                let len = usize::min(left.len(), right.len());
-                let left = &left[(left.len() - len)..];
-                let right = &right[..len];
-                left.iter()
-                    .zip(right.iter().rev())
-                    .map(|(&l, &r)| l.chars().zip(r.chars()).filter(|(a, b)| a != b).count())
-                    .sum::<usize>()
-                    == 1
-            }) {
-                Some(i) => i,
-                None => 0,
-            }
+    fn part_2(patterns: &Vec<Grid<u8>>) -> usize {
+        fn symmetry_rows(grid: &Grid<u8>) -> usize {
+            let grid: Vec<&[u8]> = grid.iter_rows().collect();
+            (1..grid.len())
+                .find(|&i| {
+                    let left = &grid[..i];
+                    let right = &grid[i..];
+                    let len = usize::min(left.len(), right.len());
+                    let left = &left[(left.len() - len)..];
+                    let right = &right[..len];
+                    left.iter()
+                        .zip(right.iter().rev())
+                        .map(|(&l, &r)| l.iter().zip(r.iter()).filter(|(a, b)| a != b).count())
+                        .sum::<usize>()
+                        == 1
+                })
+                .unwrap_or_default()
         }
 
-        fn symmetry_cols(grid: &[&str]) -> usize {
-            match (1..grid[0].len()).find(|&i| {
-                let (lbegin, lend) = (0, i);
-                let (rbegin, rend) = (i, grid[0].len());
-                let len = usize::min(rend - rbegin, lend - lbegin);
-                let (lbegin, lend) = (i - len, i);
-                let (rbegin, rend) = (i, i + len);
-                grid.iter()
-                    .map(|row| {
-                        row[lbegin..lend]
-                            .chars()
-                            .zip(row[rbegin..rend].chars().rev())
-                            .filter(|(l, r)| l != r)
-                            .count()
-                    })
-                    .sum::<usize>()
-                    == 1
-            }) {
-                Some(i) => i,
-                None => 0,
-            }
+        fn symmetry_cols(grid: &Grid<u8>) -> usize {
+            let grid: Vec<&[u8]> = grid.iter_rows().collect();
+            (1..grid[0].len())
+                .find(|&i| {
+                    let (lbegin, lend) = (0, i);
+                    let (rbegin, rend) = (i, grid[0].len());
+                    let len = usize::min(rend - rbegin, lend - lbegin);
+                    let (lbegin, lend) = (i - len, i);
+                    let (rbegin, rend) = (i, i + len);
+                    grid.iter()
+                        .map(|row| {
+                            row[lbegin..lend]
+                                .iter()
+                                .zip(row[rbegin..rend].iter().rev())
+                                .filter(|(l, r)| l != r)
+                                .count()
+                        })
+                        .sum::<usize>()
+                        == 1
+                })
+                .unwrap_or_default()
         }
 
-        let input = input.trim().lines().collect_vec();
-        input
-            .split(|line| line.is_empty())
-            .map(|lines| symmetry_cols(lines) + 100 * symmetry_rows(lines))
+        patterns
+            .iter()
+            .map(|grid| symmetry_cols(grid) + 100 * symmetry_rows(grid))
             .sum()
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let patterns: Vec<String> = (0..size.max(1))
+            .map(|_| loop {
+                let (rows, cols) = (rng.range(5..=17usize), rng.range(5..=17usize));
+                // Rows are the same as their reflections about the line after
+                // `perfect` rows, and about the line after `smudged` rows,
+                // except for the pair with the smudge. Rows that must be the
+                // same are put in groups, which the smudge must keep apart.
+                let (perfect, smudged) = (rng.range(1..rows), rng.range(1..rows));
+                if perfect == smudged {
+                    continue;
+                }
+                let pairs =
+                    |line: usize| (0..line.min(rows - line)).map(move |i| (line - 1 - i, line + i));
+                let smudge = *rng.pick(&pairs(smudged).collect::<Vec<_>>());
+                let root = |group: &[usize], mut row: usize| {
+                    while group[row] != row {
+                        row = group[row];
+                    }
+                    row
+                };
+                let mut group: Vec<usize> = (0..rows).collect();
+                for (a, b) in pairs(perfect).chain(pairs(smudged).filter(|&pair| pair != smudge)) {
+                    let (a, b) = (root(&group, a), root(&group, b));
+                    group[a] = b;
+                }
+                let (a, b) = (root(&group, smudge.0), root(&group, smudge.1));
+                if a == b {
+                    continue;
+                }
+                let mut contents: Vec<Vec<u8>> = (0..rows)
+                    .map(|_| (0..cols).map(|_| *rng.pick(b"#.")).collect())
+                    .collect();
+                contents[b] = contents[a].clone();
+                contents[b][rng.range(0..cols)] ^= b'#' ^ b'.';
+                let cells = (0..rows)
+                    .flat_map(|row| contents[root(&group, row)].clone())
+                    .collect();
+                let mut pattern = Grid::from_vec(cells, cols);
+                if rng.chance(0.5) {
+                    pattern = pattern.transpose();
+                }
+                // Other lines may reflect by chance, which would make the
+                // answer ambiguous.
+                let transposed = pattern.transpose();
+                let lines: Vec<usize> = (1..pattern.rows())
+                    .map(|line| differences(&pattern, line))
+                    .chain((1..transposed.rows()).map(|line| differences(&transposed, line)))
+                    .collect();
+                let count = |n| lines.iter().filter(|&&d| d == n).count();
+                if count(0) == 1 && count(1) == 1 {
+                    break pattern.map(|&c| c as char).to_string();
+                }
+            })
+            .collect();
+        patterns.join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 405);
-        assert_eq!(part_1(INPUT), 28895);
+        assert_eq!(Solver::solve_1(EXAMPLE), 405);
+        if let Some(input) = common::input::for_test(2023, 13) {
+            assert_eq!(Solver::solve_1(&input), 28895);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 400);
-        assert_eq!(part_2(INPUT), 31603);
+        assert_eq!(Solver::solve_2(EXAMPLE), 400);
+        if let Some(input) = common::input::for_test(2023, 13) {
+            assert_eq!(Solver::solve_2(&input), 31603);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -268,1361 +361,5 @@ mod test {
 #####.##.
 ..##..###
 #....#..#
-";
-
-    const INPUT: &str = "
-#..#...#.#..#
-#..#####.####
-#..######....
-.##...####.#.
-.##.##.#.####
-#..#.###.##.#
-.....###.#.#.
-.##..##..##..
-....#.#####..
-....###..##.#
-....####.##.#
-
-..#.#......#.
-###.#.####.#.
-##.##.#..#.##
-##..#.####.#.
-...###.##.###
-###.##.##.##.
-###..##..#...
-###..##..##..
-####.#.##.#.#
-
-.#.####.#.#
-...####....
-....##....#
-.#.####.#.#
-.#..##..#.#
-##......#.#
-.#..##..#.#
-####..####.
-.########..
-.#..##..#.#
-..######..#
-##.####.###
-##.####.###
-
-.##.##.
-#...##.
-.######
-#..#..#
-#..#..#
-.######
-#...##.
-.##.##.
-.#..#..
-.#.....
-##.....
-#.#####
-#..####
-##.#..#
-#.#####
-
-###....#####.
-.########.##.
-#.#.#..#.##..
-..######...#.
-#.##..##.##.#
-#...##...#.#.
-#..####..#.##
-.#.####.#.##.
-..........#..
-..........#..
-.#.####.#.##.
-
-#....###.
-......##.
-......#.#
-.#..#....
-..##....#
-########.
-#.##.#.#.
-######.##
-.#..#..#.
-..##.....
-..##.....
-.####.##.
-.####.###
-
-.....##
-####...
-....#..
-....#..
-####...
-.....##
-.##...#
-#..###.
-#..#.#.
-#.##.##
-.##.#.#
-
-...##...#.#..#..#
-...##...#.#..#..#
-#..####...###....
-...#.#.##.#...##.
-.###.#..##.###..#
-.####..##....####
-....##....##.####
-.#...#..#.#######
-..##..#....#.#..#
-##..#.#.#########
-#....#...#..##..#
-#..###.###..#.##.
-...#..#...####...
-
-###..##.......#
-###.###.......#
-#.#..#..###..#.
-.##.#..###..#.#
-.##.#..###..#.#
-#.#..#..###..#.
-###.###.......#
-###..##.......#
-.##.#####..#.##
-#...#.#.#..#..#
-..##.#.##.##...
-.###.##..####.#
-.###..##.#...#.
-.####.####.##..
-.##.#...#..####
-
-.###..#..###.
-...#....#....
-...#....#....
-.###..#..###.
-#.#..#..#...#
-##.###....##.
-.#.###.###..#
-...#####.###.
-##.#..####...
-..#.#.......#
-.###..#######
-#####.#.###..
-#####.#.###..
-.###..#######
-..#.#.......#
-#..#..####...
-...#####.###.
-
-.##.#..
-####.##
-.....##
-#####..
-####...
-.##.###
-#..##..
-#..#...
-####.##
-.##..##
-#..##..
-#..##..
-....###
-####...
-.##.#..
-#..#.##
-.#..#..
-
-######.
-.#..###
-..###.#
-####.##
-####.##
-..###.#
-.#..###
-######.
-.#####.
-.#.#..#
-..##...
-..#.###
-..#.###
-..#....
-.#.#..#
-
-.#..#.#.##..#
-#.####..#####
-..##.###.#..#
-.#.####.#.##.
-.#.####.#.##.
-..##.###.#..#
-#.####..#####
-.##.#.#.##..#
-#..#.###.####
-#..#.###..##.
-#..#..#.#....
-
-.#....#..
-.##.###..
-##.##.###
-##.##.###
-.#.##.#..
-.#....#..
-#.#..#.##
-##.##.###
-#......##
-###..####
-#..##..##
-
-#.#..#.#..#.#
-#.####.####.#
-.#....#.##.#.
-.#.##.#.##.#.
-.##..##....##
-##.##.######.
-..#..#......#
-#..###.#..#.#
-#..##..#..#..
-########..###
-.######....##
-
-..##....##...
-########.....
-#####..##....
-##..#..#.####
-#..#...###...
-####.#..##...
-....#.##.#...
-....#.##.#...
-######..##...
-#..#...###...
-##..#..#.####
-
-#.#.##.##
-..###.##.
-..###.##.
-#.#.##.##
-..#..##.#
-....####.
-#.###.#..
-.#.###.##
-.#.###.##
-#.#.#.#..
-....####.
-..#..##.#
-#.#.##.##
-
-#....#......#..
-......######...
-#.##.########.#
-##..##......##.
-.####........##
-.......#..#....
-#.##.##....#..#
-..##....##....#
-.####..#..#..##
-
-#.#....##..####
-.#..###........
-###...###..#..#
-###.#.###..#..#
-.#..###........
-#.#....##..####
-##.#.....#.####
-##.##.....##..#
-..##..#....#..#
-
-####......#..##.#
-...#.###.##.#####
-...#.###.##.#####
-####......#..##.#
-##.######...##.#.
-##.###..#.......#
-.#####...###..#..
-###.###..#.#..#..
-.#.#######.##...#
-.#.#######.##...#
-###.###..###..#..
-.#####...###..#..
-##.###..#.......#
-##.######...##.#.
-####......#..##.#
-
-###.##.##..##
-###.##.##..##
-.#..#...#.#..
-...####.###..
-#.#..##.##...
-#..#.#..#.###
-#...#.##....#
-
-....##...#.##
-.##.####.#..#
-#..###.....##
-####..##.#.##
-####.##..#...
-.##.##..####.
-#..##...##.##
-######.###..#
-.##.###...#..
-#..######...#
-#..######....
-
-...#.#..#..
-..#..##...#
-..#..##...#
-...#.#..#..
-##.#..#..##
-.######.#.#
-###.#..##..
-###.#..##.#
-.######.#.#
-
-##.##.##...#..##.
-#########..#...##
-.#.##.#.##.#...#.
-.#.##.#.##.#...#.
-#########.##...##
-##.##.##...#..##.
-##.##.#####.....#
-..####..#....#.#.
-#.####.#...###..#
-##....##.#......#
-##....###...####.
-##########.#.####
-#......#....#...#
-
-#....##..##..
-#....#....#..
-#.##.#.##.#.#
-.#..#.#.##.#.
-.#..#.#..#.#.
-......####...
-.......##....
-##..########.
-#.##.#.##.#.#
-######.##.###
-#....##..##..
-
-##..####.
-#.#......
-....#.##.
-##..####.
-####.##.#
-..#......
-##.#....#
-###.####.
-..#.#..#.
-..#......
-.#.#.##.#
-..#..##..
-..#..##..
-
-...#.#...#..##..#
-##.#.#.###......#
-##.###.###.#..#.#
-..#...#.#..#..#..
-.#.#.######.##.##
-..##..##.###..###
-####.##.#########
-...###..#..####..
-......###...##...
-...##..###..##..#
-...###.#...####..
-...###....#....#.
-..#......##....##
-.....##.####..###
-..#...#.....##...
-...#..#...#.##.#.
-####.....#......#
-
-###..###.##.#.##.
-...##...###.#..##
-........#..###..#
-.#....#.#######..
-###..####.##...##
-###..###..#..#.##
-...........###..#
-#########....#..#
-..#..#...####..#.
-##.##.####..#....
-............##.##
-########..##.#.#.
-.........#..##...
-##....####.....#.
-...##...#..#.###.
-
-###..#####.##
-##....####.#.
-...##...####.
-..####.......
-#......###.##
-########.###.
-..####....###
-.........##..
-...##....##..
-
-####...##..##..##
-....#...##..##.##
-.##.#.##......#..
-####.####..#...##
-.##.#.#.###.#....
-.##.#.#.###.#....
-####.####..#...##
-.##.#.##......#..
-....#...##..##.##
-####...##..##..##
-.....##..#.#....#
-..#..#####.##.##.
-.##.##....##..#.#
-#..##.##...#####.
-######..#.#......
-
-.###.###.##
-..#.#.##..#
-.##.....###
-.##.#...###
-..#.#.##..#
-.###.###.##
-#....###.#.
-#....###.#.
-.###.###.##
-
-...#.......#.####
-#..####..########
-..######..######.
-...#..#.......#.#
-#.######.#####.##
-...#..#...#...#..
-....##....##...##
-##..##..###..##..
-...#..#....#.#.#.
-....##....###.#..
-###.##.####.##.#.
-##.####.###....##
-##.####.###....##
-###.##.####.##.#.
-....##....###.#..
-
-#.##.##
-.#.####
-.#.....
-.#.....
-.#.####
-#.#####
-####.#.
-#..###.
-##..#..
-#####..
-######.
-..##...
-..##...
-
-...##....##....##
-.#..#.##.#..#.#.#
-...##....##...###
-#..##.##.##..####
-##.#.####.#.##...
-##.########.#####
-...#.####.#......
-##....##....##.##
-#.##.####.##.#...
-#.#...##...#.##..
-....##..##.......
-..#.######.#.....
-.#...####...#....
-
-#.##.###..#..
-#.##.###..#..
-#..#####..##.
-##..#.##.#.#.
-..###.....###
-####.######.#
-#.#.##......#
-#.#.##......#
-####.######.#
-...##.....###
-##..#.##.#.#.
-#..#####..##.
-#.##.###..#..
-
-#......##.###.#
-########...####
-#......##.#.##.
-...##....#.....
-#.####.#.###...
-#..##..##..#.#.
-.#....#.##.#..#
-..#..#..#.....#
-#..##..###...#.
-#..##..###..##.
-..#..#..#.....#
-.#....#.##.#..#
-#..##..##..#.#.
-#.####.#.###...
-...##....#.....
-
-##.#.###.##....#.
-###...#...#...#..
-###...#...#...#..
-##.#.###.##....#.
-#..#.#..###.###..
-###.####.#...#...
-...#.#.##.##.####
-##..####.####.###
-###.###.##..###.#
-..#..#.#...#.#..#
-...###.#.....#...
-..#.######.#..#.#
-..###.#.....##.##
-##.#.#..#.##..###
-...#.#.#.#.###.#.
-.......##..####..
-##....###..##..#.
-
-##.##.....#.###
-.#.##.###..#...
-.#.##.###..#...
-##.##.....#.###
-#....#...###...
-..#..#.#..#....
-.#.##.##...#.##
-..######.#.#...
-..###...###.###
-##....#..######
-.#..##.###.####
-##..##..##.#...
-.##..#.###..#.#
-
-.#.#.###.
-#.#.####.
-.....###.
-......##.
-#.#.####.
-.#.#.###.
-#...#...#
-.....#.#.
-.#..#.##.
-##..#....
-##..#....
-
-#......
-..####.
-.##..##
-#.##.#.
-#.####.
-##.##.#
-##.##.#
-
-#...###.#..#.
-..###...####.
-###.#..######
-.##.#..######
-..###...####.
-#...###.#..#.
-#.##.....##..
-
-.#..####..####..#
-.#..####..####..#
-.#..####..#####.#
-.#######..#######
-#####.######.####
-.....##....##....
-#..###.####.###..
-..##..........##.
-..######..######.
-
-#..##..####
-.....##.#.#
-.....##.#.#
-#..###.####
-####.#.#...
-.##.##....#
-#..##....##
-####...#...
-#####..#.##
-########..#
-#..#.#.#.##
-######.#.##
-#..#.##.#..
-......##.##
-#..##.##..#
-#..#...#.##
-########...
-
-.##.#.#####.#..##
-..###.#.#...###.#
-.##...#.#....#.#.
-..##.#..##....##.
-#..##...#...#.##.
-#..##...#...#.##.
-..##.#..##....##.
-.##...#.#....#.#.
-..###.#.#...###.#
-.##.#.#####.#..##
-###......#.##....
-###......#.##....
-.##.#..####.#..##
-
-....###..
-..#######
-#..######
-##...##..
-.##.####.
-###......
-###......
-.##.####.
-##...##..
-#..######
-..#######
-
-#.##.###.##.###
-.#.##..........
-.####...#..#...
-.#.#.###....###
-..#..###....###
-#######.####.##
-.#..#...####...
-......##....##.
-#.###...#..#...
-.###.....##....
-...#.##.####.##
-
-#...##..##...##
-#.###.##.###.##
-#.###.##.###.##
-#...##..##...##
-.#####..#####.#
-#...#.##.#...##
-..#.######.#..#
-..##......##...
-##..........##.
-.....####.....#
-##..##..##..##.
-#....####....##
-####.####.##.#.
-.####....####..
-.##.######.##..
-.....####.....#
-#.####..####.##
-
-..#.######.##
-..#.###.##.##
-##.#.#...#..#
-..####.#.##..
-...##..###...
-.....#....###
-##.###...#..#
-
-#..##.####.
-#..##.####.
-........#..
-...###.###.
-.###..#...#
-##.#####.#.
-#.##.#.#...
-#.##.#.....
-##.#####.#.
-.###..#...#
-...###.###.
-
-#..#.#.#.#..#.#
-......####..###
-#..###...#..#..
-.##.......##...
-.##.##.#####.##
-####.##...##...
-.....#..##..##.
-....##..##..##.
-......####..###
-#####.#..####..
-.....#.###..###
-####.#.##....##
-#..#.....####..
-
-...#....#....##
-#..........#...
-...######...#..
-.#.##..##.#.###
-##.#....#.##.##
-..#.####.#...##
-.....##........
-#.#.####.#.##..
-..##....##...##
-#..##..##..##..
-..#..##..#..#..
-#.#......#.#.##
-#.#......#.##..
-#.##....##.#...
-#..##..##..##..
-#..##..##..#...
-.#.##..##......
-
-.#..#..
-#.#.###
-#.#.#.#
-.##.##.
-.#..##.
-....#.#
-#.##.##
-....###
-..#.###
-..#.###
-....###
-#.##.##
-....#.#
-.#..##.
-.##.##.
-
-....####....###
-.....##......##
-#..######..#.#.
-.###.##.###...#
-...##..##......
-###.####.###.##
-###.####.###.#.
-
-####.######.####.
-####.######.####.
-###.#..##..#.###.
-#.....#..#.....#.
-.#..#..##..#..#..
-.##########.###.#
-#....#.##.#....#.
-
-.#...##..
-##.......
-###.##.#.
-.##......
-..###..##
-#.#######
-..###..##
-..#.#..#.
-..##.##.#
-..##.##.#
-..##.##.#
-
-.#.....
-#.##.##
-##..###
-..##...
-.#..#..
-#.##.##
-##..###
-#....##
-.#..#..
-#....##
-#.##.##
-
-.#..##..#.#..
-##########.##
-###....###...
-...#..#...##.
-..#....#...#.
-##.####.#####
-..######.....
-####..#####..
-##.####.##.#.
-###.##.######
-..#.##.#..###
-
-.#.#....#
-.#.#....#
-.#..####.
-###.#..#.
-.#.#.##.#
-#....##..
-#.##.##.#
-##.#....#
-#.#.#..#.
-...#....#
-..###..##
-##...##..
-####.##.#
-..#..##..
-..#.####.
-##.##..##
-.###..#.#
-
-#.##..#.######.
-#.#.####.####.#
-#..##.###.##.##
-.##.###########
-.#..#..#######.
-...##..#......#
-##..###.######.
-#..#..#########
-#...####..##..#
-##.####.######.
-##.####.######.
-#...####..##..#
-#..#..#########
-
-#.##...#..#
-#...##.####
-.####.#.##.
-##.###..##.
-.....#.....
-#...#...##.
-.#.#.#..##.
-.#...#..##.
-#...#...##.
-
-#....#.##...##.##
-#.##.##.###.##...
-#######.###.##...
-#....#.##...##.##
-##..##.###....#..
-#.##.#...######..
-...............##
-#....##.#.#.##.##
-#....##.#...#...#
-.####..#.#.#.....
-.#..#............
-
-..#....#..###
-...#..#......
-...#..#.....#
-##.####.#####
-.###..###....
-...#..#......
-.##.##.##.#..
-...####....##
-##.####.###..
-.#.####.#....
-.#......#....
-...#..#......
-#.######.####
-
-#....##.#..##.###
-..##...#..##.##.#
-..##..###.##..###
-.......#.#......#
-..##..#.....##...
-.####..#..#....##
-.#..#..#.#..#####
-#.##.#.....###.##
-..##...#...#.#.##
-##..#####.#..#.#.
-..##..#..#.##.#.#
-..##..#####..####
-..##..#####..####
-..##..#..#.##.#.#
-##..#####.##.#.#.
-
-#.#..#.##
-###..##..
-##....###
-...##...#
-...##...#
-##....###
-###..##..
-#.#..#.#.
-#.#..#.#.
-###..##..
-##....###
-
-..####.##.#
-#.#.###..##
-#..#..####.
-......####.
-##.##.#..#.
-##....####.
-..##.......
-.######..##
-.#.#.##..##
-.#.#.##..##
-.######..##
-..##.......
-##....####.
-##.##.#..#.
-...#..####.
-
-..##....##....#
-#..#....##....#
-#..............
-####.##.##.##.#
-#.#############
-###.#..#..#..#.
-..##.##.##.##.#
-##..#........#.
-.##############
-.#.............
-.#...##....##..
-##..####..####.
-..#.####..####.
-..#..##....##..
-#...#..#..#..#.
-#..............
-###............
-
-...###..#..
-.######...#
-#.#.#.....#
-.#.#....#.#
-.#.#....#.#
-#.#.#.....#
-.######...#
-...###..#..
-.#.###.####
-...#...##.#
-.....#...##
-.####..###.
-.####..#.#.
-
-..##..##.
-####..###
-..#.##.#.
-...#..#..
-..######.
-..#.##.#.
-###....##
-..##...#.
-###....##
-
-##.##.####.##
-##.....###...
-.##.##.#.....
-##....#.#.#..
-#.....#.#.#..
-.##.##.#.....
-##.....###...
-##.##.####.##
-..#..##...#..
-.###.##..##..
-...##.#..##..
-.##..##....##
-##.#..####...
-
-#....##...##.
-..###.##.#..#
-.#.###.......
-.#.###.......
-..###.##.#..#
-#....##...##.
-#..####...##.
-..##..####..#
-..####.#.....
-##...#.#.#..#
-#.#..#.......
-...##.#..#..#
-..######.####
-##..#.#######
-###...#.##...
-..###..#.####
-##.#.####.##.
-
-.###.##.#
-.####.#..
-.#...####
-#..#.####
-....###.#
-..#.#.#.#
-####.###.
-...###.#.
-.####.#.#
-.#...###.
-.#...####
-.#...####
-.#...###.
-
-.#..#..#..#
-.##########
-##..####..#
-#..........
-.####..####
-###.#..#.##
-##..#..#..#
-#.##.##.##.
-..##....##.
-
-##.######.##..#.#
-##.######.##..#.#
-#.###..###.#...#.
-###.#..#.###.#...
-.#.#....#.#....#.
-...#....#...###.#
-.####..####..##.#
-..##.##.##..#####
-.#........#.##...
-#..######..#.#.#.
-...#.##.#....#.##
-#....##....##....
-.###....###.##..#
-.#.######.#....##
-####....####.....
-#...#..##..###.#.
-#...#..#...###...
-
-....#.###....##
-.##.#....#..#..
-.#.##....####..
-#..######....##
-....###........
-#####.#..####..
-.#..###..####..
-....###########
-.....####....##
-##.####..####..
-#.##.###.#..#.#
-.#..#####....##
-#.#.#.#..####..
-
-##...#.#.#.##
-.......#.###.
-###.#..###...
-##..#...#....
-..#.#...##...
-#####.#....##
-#####.#....##
-..#.#...##...
-##......#....
-###.#..###...
-.......#.###.
-##...#.#.#.##
-....##.#..###
-
-..##...
-.#.####
-..###..
-#.#..##
-...#...
-#...###
-#......
-#..####
-.####..
-#..####
-..##.##
-....#..
-..#.#..
-
-#.###......###.
-.#.##..##..##.#
-.#.##..##..##.#
-#.###......###.
-#..####..####..
-......####.....
-######.##..####
-...#...##...#..
-....###..###...
-
-#..##.#....##.###
-.#.#......#.##...
-#...#...#...#..##
-#..##..#.#..##...
-#########.##.##.#
-#########.##.##.#
-#..##..#.#..##...
-#...#...#...#..##
-.#.#......#.##...
-#..##.#..#.##.###
-#..#.##.#...#.###
-.#......#..###..#
-.##.##.##...##...
-.######..####..##
-####.#..#..#.#...
-####.#..#..#.#...
-.######..####..##
-
-...###.
-...###.
-.#...#.
-..#.###
-.#...#.
-....#.#
-.#.###.
-.#.###.
-....#.#
-.#...#.
-..#.###
-##...#.
-...###.
-
-##..###..##..##
-##..#####......
-#.##.#..#.####.
-######.##..##..
-#.##.##########
-#######..##..##
-#####..#.......
-.####..#...##..
-##..######.##.#
-
-.##.######.##..##
-.#####..#####..##
-##..##..##..####.
-#.#.#....###.##.#
-.#...#..#...#..#.
-##.###..###.####.
-.#..........#..#.
-...##.##.##......
-##...#..#...####.
-......##.........
-##..#.##.#..####.
-
-###..##
-####..#
-####..#
-..#..##
-##.#.##
-##...##
-...####
-#####..
-..#...#
-..#####
-###...#
-..#..#.
-##..#.#
-##.....
-###..##
-..###.#
-...##.#
-
-.#....###.#.#
-###.##.#.....
-###.##.#...#.
-.#....###.#.#
-#######..#...
-#######..#...
-.#....###.#.#
-###.##.#...#.
-###.##.#.....
-.#....###.#.#
-..#..#.....#.
-#.......#.#.#
-#.#####.#.##.
-....#...#..##
-..#.##.#.####
-####.######.#
-..###.#.#...#
-
-..#.#.#
-#####..
-...###.
-...#..#
-##..##.
-###..#.
-##.###.
-......#
-#.###.#
-####...
-..##..#
-##.....
-##.##.#
-##.####
-##.####
-
-####..###
-....##...
-..######.
-..##..##.
-##.####.#
-###.#..##
-##......#
-
-..#..####..
-..#.##..##.
-##.###..###
-##.###..###
-..#.##..##.
-..#..####..
-#...######.
-######..###
-...##....##
-#.#...##.#.
-#.##..##..#
-#...##..##.
-.##..#..#..
-
-#.#####
-.#.#..#
-.#.#..#
-#..####
-#....##
-#....##
-#..####
-.#.#..#
-.#.#..#
-#.#####
-##.##.#
-..##.##
-#..#.##
-#...#.#
-#.####.
-##.#...
-.#.####
-
-##.#..###....#..#
-#.###.##.#.##..##
-...##.#.#.##..#..
-#.#..####..##....
-#.#..####..##....
-...##.#.#.##.##..
-#.###.##.#.##..##
-##.#..###....#..#
-....#.#....#.##.#
-#.###...###.###.#
-##.##..#########.
-#.#....##.#..#.#.
-.##.#...###....#.
-.#.###...#..#..#.
-.#.###...#..#..#.
-.##.#...###....#.
-#.#....##.#..#.#.
-
-#.#.##.
-#...#..
-..#..#.
-##..#..
-##..#..
-..#..#.
-#...#..
-#.#.##.
-##..###
-.####..
-.#.#...
-###.##.
-##..#..
-##..#..
-###.#..
-.#.#...
-.####..
-
-###.#..#.##
-..##.#.....
-...#.###...
-...#.#...##
-....####...
-......#.#..
-...###.#.#.
-
-#.##.##.#
-..##..###
-.#.....#.
-##..##.##
-......##.
-#.##.#..#
-######.#.
-..##....#
-##..###.#
-#.##.####
-#.##.####
-##..###.#
-..##....#
-######.#.
-#.##.#..#
-......##.
-##..##.##
-
-####.##.######.#.
-...######....#.#.
-..###..###..#.##.
-..###..###..#.##.
-...######....#.#.
-####.##.######.#.
-#....##....#.....
-...#.##.#...##.#.
-..#..##..#..####.
-#.##....##.#.#.#.
-###############..
-.........#..#.#..
-############..#.#
-
-.##.#....#.
-..#.#....#.
-###..#..#..
-#...##..##.
-..###....##
-..#..####..
-####.#..#.#
-
-##.##......
-##..#.#..#.
-..#..#.##.#
-###.##.#..#
-....#.#..#.
-####.......
-..#...####.
-
-###.##.#####.
-####...######
-..#.##..####.
-##..#.#.#..#.
-###.##.######
-..#..##.#..#.
-########.##.#
-..##.#.######
-..#......##..
-..#....##..##
-##.####......
-
-...#..#.......##.
-.##....##...#####
-#..#..#..#.......
-..##..##..#......
-.###..###..#..##.
-.##....##.#...##.
-############.####
-#.#.##.#.#.##....
-#.##...#.#....##.
-.##.##.##....#..#
-.###..###.##.....
-#...##...#####..#
-###....###.##.##.
-#.##..##.#..##..#
-##......##.......
-##########..#####
-#.##..##.#..#.##.
-
-.#.#.####
-...#.#.##
-..###....
-#..##.#.#
-##..##...
-.###..#..
-##.#.####
-##.#.#..#
-#.#####..
-#...#.#.#
-#...#.#..
-#...#.#..
-#...#.#.#
-
-##.#..###.#..
-...####.#.###
-..#....##.#..
-###..##.##...
-##.####.#..##
-####.#..#..##
-###.#..##.###
-......##.#.##
-###....#..#..
-...##.#######
-##.#...#.#.##
-..#.####.#.##
-###...#..####
-..#..#.####.#
-##.##.#...###
-..####...##..
-...#...#.####
-
-.....##.#..#.####
-.##....#####....#
-#..##.###.###.##.
-######.##.....##.
-.##.#..##..###..#
-####..#.###.#####
-####.#..#..#.#..#
-####.#.#..###....
-########..##..##.
-#######.#.##.....
-.....##..#..#....
-
-#...#..######.#.#
-...#.###.##..#.#.
-###....#..#..#.#.
-.##.######.###.#.
-##..#..#..###....
-##..#..#..###....
-.##.##########.#.
-###....#..#..#.#.
-...#.###.##..#.#.
-#...#..######.#.#
-.##.#.#..#....##.
-.##.#.#..#....##.
-#...#..######.#.#
 ";
 }
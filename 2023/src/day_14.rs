@@ -70,28 +70,41 @@ beams?
 
  */
 
-#[cfg(test)]
-mod test {
-    fn part_1(input: &str) -> usize {
-        let input = input.trim();
-        let cols = input.find('\n').unwrap();
-        let rows = input.lines().count();
-        input
-            .lines()
+use common::{cycle, random::Rng, ParseError, Solution};
+use grid::Grid;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// The platform, with rounded rocks as 'O' and cube rocks as '#'.
+    type Parsed<'a> = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(input, "'O', '#' or '.'", |c| {
+            matches!(c, 'O' | '#' | '.').then_some(c as u8)
+        })
+    }
+
+    fn part_1(platform: &Grid<u8>) -> usize {
+        let (rows, cols) = (platform.rows(), platform.cols());
+        platform
+            .iter_rows()
             .enumerate()
             .fold(
                 (0usize, vec![0usize; cols]),
                 |(sum, mut prev), (ri, line)| {
                     let load: usize = line
-                        .chars()
+                        .iter()
                         .enumerate()
                         .map(|(ci, c)| match c {
-                            'O' => {
+                            b'O' => {
                                 let out = rows - prev[ci];
                                 prev[ci] += 1;
                                 out
                             }
-                            '#' => {
+                            b'#' => {
                                 prev[ci] = ri + 1;
                                 0usize
                             }
@@ -104,141 +117,144 @@ mod test {
             .0
     }
 
-    fn part_2(input: &str) -> usize {
-        let (rows, cols, mut input) = {
-            let input = input.trim();
-            let cols = input.find('\n').unwrap();
-            let rows = input.lines().count();
-            let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
-            bytes.extend(input.lines().map(|l| l.as_bytes().iter()).flatten());
-            (rows, cols, bytes)
-        };
+    fn part_2(platform: &Grid<u8>) -> usize {
+        let (rows, cols) = (platform.rows(), platform.cols());
         let mut stops: Vec<usize> = Vec::with_capacity(usize::max(rows, cols));
-        let mut history: Vec<u8> = Vec::with_capacity(input.len() * 100);
-        for iteration in 0..1000000000 {
-            // North
-            stops.clear();
-            stops.extend(0..cols);
-            for i in 0..input.len() {
-                match input[i] {
-                    b'O' => {
-                        let ci = i % cols;
-                        let other = input[stops[ci]];
-                        input[stops[ci]] = std::mem::replace(&mut input[i], other);
-                        stops[ci] += cols;
-                    }
-                    b'#' => {
-                        let ci = i % cols;
-                        stops[ci] = i + cols;
-                    }
-                    _ => {}
+        let state = cycle::nth(
+            &platform.cells().to_vec(),
+            |input: &mut Vec<u8>| spin(input, rows, cols, &mut stops),
+            1000000000,
+        );
+        state
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                if *c == b'O' {
+                    rows - (i / cols)
+                } else {
+                    0usize
                 }
+            })
+            .sum()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(2);
+        let platform: Vec<char> = (0..side * side)
+            .map(|_| match rng.range(0..10) {
+                0..=1 => 'O',
+                2 => '#',
+                _ => '.',
+            })
+            .collect();
+        Grid::from_vec(platform, side).to_string()
+    }
+}
+
+/// Tilts the platform north, west, south and then east, rolling the rounded
+/// rocks as far as they go. `stops` is scratch space for where the next
+/// rock in each row or column stops.
+fn spin(input: &mut [u8], rows: usize, cols: usize, stops: &mut Vec<usize>) {
+    // North
+    stops.clear();
+    stops.extend(0..cols);
+    for i in 0..input.len() {
+        match input[i] {
+            b'O' => {
+                let ci = i % cols;
+                let other = input[stops[ci]];
+                input[stops[ci]] = std::mem::replace(&mut input[i], other);
+                stops[ci] += cols;
+            }
+            b'#' => {
+                let ci = i % cols;
+                stops[ci] = i + cols;
+            }
+            _ => {}
+        }
+    }
+    // West
+    stops.clear();
+    stops.extend((0..rows).map(|r| r * cols));
+    for i in 0..input.len() {
+        match input[i] {
+            b'O' => {
+                let ri = i / cols;
+                let other = input[stops[ri]];
+                input[stops[ri]] = std::mem::replace(&mut input[i], other);
+                stops[ri] += 1;
             }
-            // West
-            stops.clear();
-            stops.extend((0..rows).map(|r| r * cols));
-            for i in 0..input.len() {
-                match input[i] {
-                    b'O' => {
-                        let ri = i / cols;
-                        let other = input[stops[ri]];
-                        input[stops[ri]] = std::mem::replace(&mut input[i], other);
-                        stops[ri] += 1;
-                    }
-                    b'#' => {
-                        let ri = i / cols;
-                        stops[ri] = i + 1;
-                    }
-                    _ => {}
+            b'#' => {
+                let ri = i / cols;
+                stops[ri] = i + 1;
+            }
+            _ => {}
+        }
+    }
+    // South
+    stops.clear();
+    stops.extend((0..cols).map(|c| c + cols * (rows - 1)));
+    for i in (0..input.len()).rev() {
+        match input[i] {
+            b'O' => {
+                let ci = i % cols;
+                let other = input[stops[ci]];
+                input[stops[ci]] = std::mem::replace(&mut input[i], other);
+                if stops[ci] > cols {
+                    stops[ci] -= cols;
                 }
             }
-            // South
-            stops.clear();
-            stops.extend((0..cols).map(|c| c + cols * (rows - 1)));
-            for i in (0..input.len()).rev() {
-                match input[i] {
-                    b'O' => {
-                        let ci = i % cols;
-                        let other = input[stops[ci]];
-                        input[stops[ci]] = std::mem::replace(&mut input[i], other);
-                        if stops[ci] > cols {
-                            stops[ci] -= cols;
-                        }
-                    }
-                    b'#' => {
-                        let ci = i % cols;
-                        if i > cols {
-                            stops[ci] = i - cols;
-                        }
-                    }
-                    _ => {}
+            b'#' => {
+                let ci = i % cols;
+                if i > cols {
+                    stops[ci] = i - cols;
                 }
             }
-            // East
-            stops.clear();
-            stops.extend((0..rows).map(|r| r * cols + cols - 1));
-            for i in (0..input.len()).rev() {
-                match input[i] {
-                    b'O' => {
-                        let ri = i / cols;
-                        let other = input[stops[ri]];
-                        input[stops[ri]] = std::mem::replace(&mut input[i], other);
-                        if stops[ri] > 1 {
-                            stops[ri] -= 1;
-                        }
-                    }
-                    b'#' => {
-                        let ri = i / cols;
-                        if i > 1 {
-                            stops[ri] = i - 1;
-                        }
-                    }
-                    _ => {}
+            _ => {}
+        }
+    }
+    // East
+    stops.clear();
+    stops.extend((0..rows).map(|r| r * cols + cols - 1));
+    for i in (0..input.len()).rev() {
+        match input[i] {
+            b'O' => {
+                let ri = i / cols;
+                let other = input[stops[ri]];
+                input[stops[ri]] = std::mem::replace(&mut input[i], other);
+                if stops[ri] > 1 {
+                    stops[ri] -= 1;
                 }
             }
-            match history
-                .chunks(input.len())
-                .enumerate()
-                .find(|(_i, prev)| prev == &input)
-            {
-                Some((start, _prev)) => {
-                    // We detected a cycle that means the final state
-                    // after 1 billion cycles is already in the
-                    // history and can be accessed through modula
-                    // arithmetic.
-                    let begin =
-                        input.len() * (start - 1 + (1000000000 - start) % (iteration - start));
-                    let end = begin + input.len();
-                    let state = &history[begin..end];
-                    return state
-                        .iter()
-                        .enumerate()
-                        .map(|(i, c)| {
-                            if *c == b'O' {
-                                rows - (i / cols)
-                            } else {
-                                0usize
-                            }
-                        })
-                        .sum();
+            b'#' => {
+                let ri = i / cols;
+                if i > 1 {
+                    stops[ri] = i - 1;
                 }
-                None => {}
             }
-            history.extend(input.iter());
+            _ => {}
         }
-        panic!("Expected to detect a cycle and return early.");
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 136);
-        assert_eq!(part_1(INPUT), 110779);
+        assert_eq!(Solver::solve_1(EXAMPLE), 136);
+        if let Some(input) = common::input::for_test(2023, 14) {
+            assert_eq!(Solver::solve_1(&input), 110779);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 64);
-        assert_eq!(part_2(INPUT), 86069);
+        assert_eq!(Solver::solve_2(EXAMPLE), 64);
+        if let Some(input) = common::input::for_test(2023, 14) {
+            assert_eq!(Solver::solve_2(&input), 86069);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -253,106 +269,4 @@ O.#..O.#.#
 #....###..
 #OO..#....
 ";
-
-    const INPUT: &str = "
-O........O..#...O...#OO..O.##..#.O....O#..#..O..#.O....#.....O.O.O..#OO#.....O#O..#.#.#O....O...#.O.
-...#....O.O...#..O....#O.....#..#O.O.....O.......#.O...O.O.#O.#..O#.#O..O##...#.OO.##...#O#.O..O###.
-O.......O...OO.....O...#..O#.##......O.O.O...O..##..#.O...O...O###O.#.OO.#....#O.........O.##...O#..
-.##.O.........O###...#.#......#.#...O.....OO#..#......#..OO.O.OO.O#OOO....O..............O.......O#O
-#......#OO#O...O#.O..#.#O..........O.............OOO.##..#....O..OO......OO.....OO.OO...O.......###.
-.O...#O#......OOO.....#OO.O.OO..O....#.#..#.O.......#O..O....OO....O..O.#.O.####.O...O..O....OOO#.OO
-...#.O......#...##O.......O.#.#..##......#O.O.OO.OO.....#OO#....OOO.....O...#..OOO..O.....O.O.#..O##
-##.....O##.O#.O###O#O..........#...O.O.O.#..OO....O#O.##.O......O.OO.O..#......#...#.#O..O#....#...O
-.OO........#..O.#O.O..O.....O...#O..OO.#O...O.#....O.O....#O.#O..O......O....#..##.O....##O..#O.O.O.
-.O.O.OO..........OOO##O....#.#.##OO#O.#...O...#.#...OO.#O.#..O.O#..O....OO.#.OO.#...####.O.##..O.O##
-.....OO#.#O......#.#O#.#...##...O.O.#OO#.....O#.O#.OO.O#O..O##.O#..O.OO#.O...O.#...........O.O.#....
-.........O.......OO...#..O.#...O.OO#.O...OO#...O.O#....O......O..O.O.......#.#...#.#.#...#....O.....
-..O...O....OO...#..OO.O...#O..O#O#.###..O##OO.....OO.......O...#..#O.....##...OOO##.#....O...#.....O
-OO..OO...##.O..#.O.OO..O..O..#....O..O#.#.....O...#...O.............O.#...#O..O..OO#.OO...##.#...OO.
-..#.O..#O.......OO..#.#.#.#O..O..O#.O...OO.O.....##....#.#O.O#..O.#....O#...OO.....OO.OO....O.#..O..
-.#..O...OOO.......O.....O.####OO.OO..O..#...#O....###.#OOO.O.....#...##.O..O.#OO....##....O.#.#O...#
-#O#.O.O#O.O....#.O#.#.....#O......O..O........#O.O.#O..O.#O........O.#.#O.O....O.#O##.......O.O...##
-...O#O.##.O##.....#.....O...O..OO..#.....O..OO.O...#.OOO.O...O#.O...O........#O#..O.O#O.O..O..OOO#.#
-O..O.....O....O..O...OO.O..O...OO##..#.##....#O......OOO......O#.O.#.....##OO...O..#..#.....#.......
-....#O#.......#..#..O.O.......#..O.....OO...............##..#....O...O...O.O.####...#.O##..O.#.#...O
-...O#..O#.OO.O#OOO.....O..........##O#.##.O.O....#..OO....#....O#....#...O#........O.......O.O.#OO..
-#......#.O.............#.OO.##.###...#..#.......OO.#..#...O..#.#.....#....O..#.....O....#..O.......O
-.O...O.#O.OO#.#...O.....O.O...O...O....##....#.O..#.#O...OOO.O.#.O.#.O#..O.#..O#O#.##...#..O.#O.O...
-O#.O...O#...O.O..O.O#.O##..#...#...O#.O.#.#......#.........#O.#.OO.O..#.#...#.OO.OO##.......O.O.###.
-O..O..O..O.#..#......##.#O#....O.OO...OO.........O...O...O..O#.O#OO.....#..##...#.#O.#...##.#.#.#...
-O#O....O..O..#..#.#.....#.......OO..O....O....O.OO...OOO#..O........O.....O..#....O#..O#....OO.#O.#O
-...O...O.O..O..##......#...#.O##..O..OO..#.#O#..O.#OO.#..#.O....O....#OOO.O......#O...#.#..#..O..O.O
-...O..O#.O.O.#O..O.#.O...OOO.#.....#....##O.....O..O......OOO.#.O..O.#..O#....OO#.....#O.O.....#.#..
-....#O..##O..O.O.O...#..OO.........#.###.O..#.O.O.......#.O.O#O..#....#.#..#.......#........#.......
-.O.O#...O.OO......O.OO..#........#...#O.....O#O...O.#.O.#O.#.#.O#..O#O#.#..###....O......O...#.O..#.
-.O.O.....#..O#..O.O..#..#...#..O....#..#O#...O.....#....OO.....#.#......#.#OOO..O.O..O....##........
-..#...#O#.O...#O.###OO.O.#O...#...O....##..#.O...#.#...O....#.O#..O..O......O#..O.#O..O......#......
-O.....O..##.....OO#.O.O.O..OO.OO..O..#......O.#....O..O##.OO..OO#.O#O#..OO.O....OOO..O..OO..OOO.O#..
-.#....O........OO#O..O...#..O...O.......O.#O...O.OO...O..O#....O#OO.##.O.#.....O.O..OO......#O...O#.
-..O.#OO.O..##...O.#...#..O...OO.O..O..O..O#..#.OO..OO.....O...#...#....#.O...OO....##..O........O...
-OOO...O#......#.#....O.O.#.#.......#.O.....#.O..OOO#O.#......##O.O..#O#.#...O.O...............OO..O#
-..#O#O.........OO....O..##.O.......O#..O....O....O..........O.......OOO.O....#..#O#..##..#OO...#..OO
-..#..#...O...#O...##O.OO........#..#...OO..O.O#..O....O..#.O.#..OOO...O.O.##......O#O...........#...
-...O..#O......#.....O.#.#...##...###.....#.O#.#......#.#O#O..O...##O.....OO.O.....O....O#O.#OO......
-.O.#...OO#.#O..O.....O...O.......#O.#O....O...O...O.O....O.O.....OOO#O...#.OO....#..O#....O....O...O
-...O.#.#.O#......OOO..............#.O....#.O..O........O...O..#OOOO.....O.#....O......##..O..O.#.O.#
-O#....##.#......O.......O..#..OOO..O.....#O...........O.O.....O...O.#O.O.....#..#..#.....OO#O.#...OO
-O..OO.O#....#O#.O.#.....OOO.....O.O.O.O...OOO.O.....#...##..#...O#...##...O....O.O.OO..#....O.O.....
-O....#....O.........#..#..O....#OO#....O..#.....#OO..#..O.#..O..#..#...#O.#O...........O...........#
-.O.#..O.#..O.#.....O..O#.#OO.#OOOOOO..O.O#..OOO....O.O.O#...O...O....O...O.##.....O..O.O.OO.OO#....O
-O#.O..OO..O...#.....O...O.O.O...OO.OO..O..O............#.O....O..O.........OO.#......#O...#O..O..O..
-.#O.....#.OO....O.#OO.#O....OO#OO.#.OO....O#..OO#..#O....##.#..O.O..#OO..O...O#O.O#......O...#.##O.#
-........#O.....O.OO....O.#O...#.O.O..........O#..O...##..#..O..OO..####O........#O#..O...O..O##....#
-O...##.O....OO....O#........O..O....O#.##O..O#.#.O....#O...#....O.#...O.OO...OO..O...........#.#....
-...O#O...O....#...O..#.#..#.......O.OO#OO.....O....O..#.#.....#..O#......O.O.OO#.##....O.....O......
-..#.#...#O#..O.#.O.#.#.#.O#.#OO..O..#.#.#O.O...O.......#...#O..#.#.O#O#..O......OO##..OO.....#O..#..
-.......O.OO....#...O.OOO##...#O..O.....#.#.#..##O.O.............#.....#..#O....#....O##....O......#O
-...#.O.#...##.O.....#.#O..O#.O.#......O.O#..#.##OO.......O........#.OO.OO.#.#OO..#O....O#..#..#...OO
-#O.....#......O..##.#O..#.O..#..##....#.#.O....#....O#.O.O.#....#O..##.O....O..O..OO.#.O.#O....O..#O
-...OO.O.......#.##.O#....O.O..#.O.O..O..O...#..O.OOOO.O#.O.O..O#O#..#....#.....O.....#.O....#..O.#.O
-#......#OOO....#......O##.....O..#....#......#.O...O#..O.O#...#O.........#..##..O.OO....O...#...#O..
-#O....O...O...O..#.....O#.....O.#O...O.O..##.O...O.#...#..O.OOOOO.O#..#.OO##.O.O.##...OO..O..O#OO.#.
-...#.........#..#O.#O..###.....OO.#O....#..O.....#....OO.O.####.O.O.....#O..#..#..O...O.#..#.#.O.O#O
-....O.#........OO..#...............##.....#.#.#O#.......#OO.....OOO...O..O..O.OO....##..OO#.O.OO....
-..O.O.O.O##...O##.#.O#...O.OOO#.O##..O..O.#O#....#...#.......O.O...OO.O.OO....O...#.........O.#...O.
-..O......O.OO.O.#.....O...#.O...O#.#...O.#..O#...O.O#.O#........#.O.O#..#..#........OO#.##.#.#O....O
-..#......####.....O.O.O......O#...O.O.....O.O..#......O.........#O.O...#..####..#....#.O.#..#..#..#.
-.......###OOO....O#OO.O##....#...O....#.#..O.....#......#OO#O.O..O....O.#.OO.....#.#................
-#....O.#OO....O..O..O.....O#...#...O#........O#.O.#.O#O#.#.O......O.#O.#.O..O.O..#.........O.....#..
-.O.O.##...O..OO...O.....O.O#..##...O..O.#..##O..O#....#.......O.O.#.......#....O#O..##....OO...##O.O
-..#.....#...###........O.O..#O.......#..#..OO#O##..#...O.#...#....O...O..#..##.......#....O#.O.#O..#
-#.#.OO.##.OO#....##..#..O.O...O...O....O#..O#..#O#O.#.....O#..#.........O#..O##.#..O....OO#..#O..O..
-O..........O..#..#..O...#.O#...#OO..##.O..OO..OO....##.O.O....O...O.O....O.....O.....#O...O...O....O
-O..OO.O..O.....#.OOO#.......O....O..O......#.O.##.OO...O...O.O.O#O.#..#.#O......#OOO..OO.O...##O..#.
-...#O.O.#O#..#...#.....O....#.......#...O.####..#....#..#..O##.O##...O..O.O.....#.#.#....O#.O......O
-..OO....#O..O............OOO.O....#....OOO.OO....#.....O##O.O.#O.O...#.#.O.....#..O........OO.#O..O.
-....OO.#.OO#O..#.OO##OO#.#..#..O..O...O...#O.O.O.....OO.#.O....O.O.O.#O#O#....O##........#..##O##...
-#.#..O.#.O......O...##....O.O.O#O#...O.O.O...O...#..OO...........#.#.....#..........#O#....O.OO#O.##
-#...#......#O...........#O.OOO#OO#..#..O....##......#...O#.OO...#....O..O#...#.#.#O...#..O.........#
-##O..........OO.#..#..O.......#O.#..O.#.#..O...O#O..O....O.OO...O..O....O.....#....O#O.#...O##.....#
-OO..O..#O.#OOO.#.......#.....#OO....O........O.#..#.O...#........OO..#..#.....#O...#....#....O....OO
-.OO....O......#O#..O.OO..OO.O..#......................O...#...#.O........#..##.#..O.#O#.#.O#O..#....
-..#...O.#....#.O.....O.O...#....O#......OO.......OO.##....OO......#....##OO....O.........O.OO.#..O..
-..O.O...O#...##....O##O.....#..#.......##...#.....#O#....O........O..#O.O.#........O....O.O..O#...O.
-.O.......#..#.O.OO...O.....OO.#..........O...O......O..O.##.#.....O.O.........OO#.......O......O..OO
-..O........O.#O..#..OO...O.....O.##O....OO#.OO#.O.O.#O...##......O..O.....O..OOO#.O##....O.O...##O..
-.O#....#O.##.#..O#.O..#.O.###.#....#O#O#.OOO#.#......O....#.O#......#.........###O#.O.OO....O..#O##.
-.O..O.........#.O#O..#.......OOO#OOOO......#..#..#.O##.O#...##.....O...#.#...#.#..O...O#.#.....#....
-O.....#O....O.#.#...OOO##..#.....OO...O#..#..O#.#..O..O...#...#.O#....#..O.....O..O.O#.#.O....O...O.
-....O.....O..O...OO......OO.#.#..O...O#...#.#O.................O..O.O.....##..#.##.#O..O.#.#..#O##OO
-...##.O..#....#O#.#..O..#....#O.O.#......O.O..O#OOOOOO....O.#..#..O..#..........O#....#...#O#..OO.O.
-#.#......O.#O#.....O.O...O.#.OOO..#.O.....#O.#.........O#.#.OO.O.....O..##..#O.OO..O...O....O#.##O.#
-.O.........#O#..O...##....#.#.#O..O..O.#..O..O#OOO..O.....##.........O#.#O.#...#O....#..O.....##.#O.
-#.O......O.O..O...O.#O.#..O.#..#O###..#..O..O....OO....O.#......O..#.OO.O#.#O....OO#O#...OOO.#.....O
-..O.O.#.#O#.......#..O.......#.O..OO...OO...O#..#....OOO....OOO..#.O#.O.O.....#.O........O.#........
-..O.#.......O..#.#.#.#O........#O....#.#O##.....O.O#.##O.#..OO........#...O......O...O....#...#.#..#
-.#...##..O.....#O#O..O.#.#.O...O.O#O....#........#....O...OO.#OO.....O#..O#O........#.....O.....O..#
-....O.O..O...OO.......O..##..##.O....O.............O.O.O....O....#......O..O........OO.O.#.O....O...
-O..#......O.#.O.O.#.OOO.......O.O.#.O...OO......##...O....O.O#O..O.O.....O..#..#...O.O###O...O#...##
-#.#..#..##.OO#...O..#O#..O..O..O.O...#.#..OO.OO.....O.O.O#O......O.O.O......##O..O.#....#.#.O......#
-.....#OO..OOO..O...O..O..O............O#.#.#O#..O#.#......#.O.....O..........#..O....O..O.....#.O#..
-#..#O.O...#.O.O.........O.O..#......OO...#...#..#.##O...#.O...........O.#.#OO.##..O..O...O.#OO...#OO
-..##.##..O....OO...OOO.........#.O.......#.O#..O#....OOO.#....O#.#....OO...#.....#.O...O.........#.O
-OO##..O#.OO##.......O...OOO.#..O........#O.O.#.O..O..O..#.....O.O..OO...O##....O.#..OO#O....O..##...
-..O..O..#.O..........O..O..OO.....#....O#...O#........##.O.#....OO.O.....O...O####...#O.#O.O....#O..";
 }
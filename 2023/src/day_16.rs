@@ -122,146 +122,116 @@ Find the initial beam configuration that energizes the largest number of tiles;
 that configuration?
  */
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
-
-    #[derive(Debug)]
-    enum Direction {
-        North,
-        East,
-        South,
-        West,
-    }
+use common::{random::Rng, ParseError, Solution};
+use grid::{
+    Direction::{self, *},
+    Grid,
+};
+
+pub enum Tile {
+    Space,
+    MirrorUp,
+    MirrorDown,
+    VSplit,
+    HSplit,
+}
 
-    enum Tile {
-        Space,
-        MirrorUp,
-        MirrorDown,
-        VSplit,
-        HSplit,
-    }
+use Tile::*;
 
-    use {Direction::*, Tile::*};
-
-    fn simulate_ray(
-        tiles: &Vec<Tile>,
-        rows: usize,
-        cols: usize,
-        pos: usize,
-        dir: Direction,
-    ) -> usize {
-        let mut visited = vec![[false; 4]; tiles.len()];
-        let mut rays: Vec<(usize, Direction)> = vec![(pos, dir)];
-        while let Some((pos, dir)) = rays.pop() {
-            let di = match dir {
-                North => 0,
-                East => 1,
-                South => 2,
-                West => 3,
-            };
-            if visited[pos][di] {
-                continue;
+fn simulate_ray(tiles: &Grid<Tile>, pos: usize, dir: Direction) -> usize {
+    let mut visited = vec![[false; 4]; tiles.len()];
+    let mut rays: Vec<(usize, Direction)> = vec![(pos, dir)];
+    while let Some((pos, dir)) = rays.pop() {
+        if visited[pos][dir.index()] {
+            continue;
+        }
+        visited[pos][dir.index()] = true;
+        let mut go = |dir: Direction| {
+            if let Some(next) = tiles.step(pos, dir.offset()) {
+                rays.push((next, dir));
             }
-            visited[pos][di] = true;
-            let nbs = [
-                pos >= cols,
-                (pos % cols) < (cols - 1),
-                pos / cols < rows - 1,
-                (pos % cols) > 0,
-            ];
-            match (&tiles[pos], dir) {
-                (Space, North) | (MirrorUp, East) | (MirrorDown, West) | (VSplit, North)
-                    if nbs[0] =>
-                {
-                    rays.push((pos - cols, North));
-                }
-                (Space, East) | (MirrorUp, North) | (MirrorDown, South) | (HSplit, East)
-                    if nbs[1] =>
-                {
-                    rays.push((pos + 1, East));
-                }
-                (Space, South) | (MirrorUp, West) | (MirrorDown, East) | (VSplit, South)
-                    if nbs[2] =>
-                {
-                    rays.push((pos + cols, South));
-                }
-                (Space, West) | (MirrorUp, South) | (MirrorDown, North) | (HSplit, West)
-                    if nbs[3] =>
-                {
-                    rays.push((pos - 1, West));
-                }
-                (VSplit, East) | (VSplit, West) => {
-                    if nbs[0] {
-                        rays.push((pos - cols, North));
-                    }
-                    if nbs[2] {
-                        rays.push((pos + cols, South));
-                    }
-                }
-                (HSplit, North) | (HSplit, South) => {
-                    if nbs[1] {
-                        rays.push((pos + 1, East));
-                    }
-                    if nbs[3] {
-                        rays.push((pos - 1, West));
-                    }
-                }
-                _ => {}
+        };
+        match (&tiles[pos], dir) {
+            (Space, _) | (VSplit, North | South) | (HSplit, East | West) => go(dir),
+            (MirrorUp, North | South) | (MirrorDown, East | West) => go(dir.turn_right()),
+            (MirrorUp, East | West) | (MirrorDown, North | South) => go(dir.turn_left()),
+            (VSplit, East | West) | (HSplit, North | South) => {
+                go(dir.turn_left());
+                go(dir.turn_right());
             }
         }
-        return visited.iter().filter(|&v| v.iter().any(|f| *f)).count();
     }
+    visited.iter().filter(|&v| v.iter().any(|f| *f)).count()
+}
 
-    fn parse_tiles(input: &str) -> (Vec<Tile>, usize, usize) {
-        let input = input.trim();
-        let cols = input.find('\n').unwrap();
-        let tiles = input
-            .lines()
-            .map(|l| {
-                l.chars().map(|c| match c {
-                    '.' => Space,
-                    '/' => MirrorUp,
-                    '\\' => MirrorDown,
-                    '|' => VSplit,
-                    '-' => HSplit,
-                    _ => panic!("Unrecognized tile: {}", c),
-                })
-            })
-            .flatten()
-            .collect_vec();
-        assert_eq!(tiles.len() % cols, 0);
-        let rows = tiles.len() / cols;
-        (tiles, rows, cols)
+pub struct Solver;
+
+impl Solution for Solver {
+    /// Tiles of the contraption.
+    type Parsed<'a> = Grid<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+        Grid::parse(input, "a mirror, a splitter or '.'", |c| match c {
+            '.' => Some(Space),
+            '/' => Some(MirrorUp),
+            '\\' => Some(MirrorDown),
+            '|' => Some(VSplit),
+            '-' => Some(HSplit),
+            _ => None,
+        })
     }
 
-    fn part_1(input: &str) -> usize {
-        let (tiles, rows, cols) = parse_tiles(input);
-        simulate_ray(&tiles, rows, cols, 0, East)
+    fn part_1(tiles: &Grid<Tile>) -> usize {
+        simulate_ray(tiles, 0, East)
     }
 
-    fn part_2(input: &str) -> usize {
-        let (tiles, rows, cols) = parse_tiles(input);
+    fn part_2(tiles: &Grid<Tile>) -> usize {
+        let (rows, cols) = (tiles.rows(), tiles.cols());
         (0..cols)
             .map(|pos| (pos, South))
             .chain((0..rows).map(|r| (r * cols, East)))
             .chain((0..rows).map(|r| (r * cols + cols - 1, West)))
             .chain((0..cols).map(|c| ((rows - 1) * cols + c, North)))
-            .map(|(pos, dir)| simulate_ray(&tiles, rows, cols, pos, dir))
+            .map(|(pos, dir)| simulate_ray(tiles, pos, dir))
             .max()
             .unwrap()
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        let tiles: Vec<char> = (0..side * side)
+            .map(|_| {
+                if rng.chance(0.1) {
+                    *rng.pick(&['/', '\\', '|', '-'])
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        Grid::from_vec(tiles, side).to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 46);
-        assert_eq!(part_1(INPUT), 8539);
+        assert_eq!(Solver::solve_1(EXAMPLE), 46);
+        if let Some(input) = common::input::for_test(2023, 16) {
+            assert_eq!(Solver::solve_1(&input), 8539);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 51);
-        assert_eq!(part_2(INPUT), 8674);
+        assert_eq!(Solver::solve_2(EXAMPLE), 51);
+        if let Some(input) = common::input::for_test(2023, 16) {
+            assert_eq!(Solver::solve_2(&input), 8674);
+        }
     }
 
     const EXAMPLE: &str = r#"
@@ -276,116 +246,4 @@ mod test {
 .|....-|.\
 ..//.|....
 "#;
-
-    const INPUT: &str = r#"
-\...|....||/............\.......-.......................\............./..........--......................./...
-.......-..........................................-..-......../....../........................\...............
-............/....\................-................-....................|..................\..../.............
-.............|.\.................\.................|..................|..-........-...................-\......
-..|...................|.....|......................./|...../......-................/.-..|.........\...........
-.|......-..........\....../....\............\........../..\....|......................................-.......
-....\....|/......../............\..............................-....../\|..-..\../.........\..\|..............
-.........-..........-.........../...................-...........|....|\.......................................
-.....|........................-......./...\../......\./.|................\......|.-...-........|.|/\..........
-././............|............|...........\.....\..../...|....|...-.....|..............|................-......
-................/../....\..........\......-....................-../............../........../-....\...........
-......|........-................/........-......./........|......-\.............../........../.....|..........
--\.\......|...........//...\-.../..\..-..\.............................../............../.\-.-.......\........
-.\..-...|./......./..............................\.................-....\../.................\..../...........
--....../......|....-...||...|.................../...-........-................|.......-/......\\...|..........
-...../.........\..............\.................\..|....../....\....../.-\.....................|..\...........
-\|..................\................-........./.......|...........\...../.................../................
-..................../......\..........\..-...............\........-......................................|-...
-................-................../.................................................-..................\-....
-..\...................|....../........................./....-..........|.....................-................
-..............-...................................\...............|./...-../.-|........||.....................
-...........................././...|..........\............|...-....-..../.\\.......\...-.........\......../...
-....\........................\..........-............\/...........|..-..\.....................................
-..../.........../-\..\.........................|.........-........................|...........................
-...|./..\...............................\....................../..............................................
-..-.................\....|......-...-.........\-|..........\.-......|.....\.\....|......................|.....
-......-.........\.....|..............-......................\.\...|\.....-..-..../............................
-.....-..././......\|............................\.-.....................-.....................................
-/-....................................-....................../.................................../.........\\.
-.........|./...................................-..........|..\...\........../\.....\.....|...\.....|../..|....
-............|.....................-.....................--.|-............................\|......../.|.....-..
--......\.........................\........|.\......../....||..||....................-........|.-.-............
-................././/|............../..\.........-|.....-...|....|.......................-.......|............
-|......|....../..............-............/........................-........\....\....|...../..............-..
-.............//..../.......-........\.............|...../............................/........\......|........
-...................-......-..........\..............\..............................\...................\......
-...............\./......|.-...................-.|.........\.............../................/..................
-.\..........-........|....................-..................||...........|...................................
-....................\......../.........|............-...........-.........\..............-.-......../...\.....
-........-.........|............\/.................-./.............-.......\...................-...............
-....................-...............-./.................|............./.......\.............\........./\......
-......\...............|........-/..................\.............../|.......\.|..............--..|.....|......
-......\...\.....././.........\||.............................|....\.\.............../....-..|/........-.......
-....../...../.................\...........|./.......|...\./....-...\........../.\......./..........-........\.
-..................\..../....|................................................................|.....|....-.....
-|..............................\......-................/|./-......./...........\........-.................|...
-.\..|\....-..............-..|...........\........|....|..|........./.................................../......
-...../...|..||.....\.--..........-....../............\..\.....|./..\....................\|......./............
-.......\...|......-.......|..../......\..\/.|./.\..........|....|..............-.......................|...../
-..............-...........\..\../...................\.........|.|....../..-/..............-...................
-.......-.......\..../................................................|...../....|................|...-........
-......................./........................|..\...|...-..-.........../....../.......-....|........../....
-.......|..................................-.....\-./.\.........................\..../....-...|................
-........../.../........./...........-.......|......./.-.........................\...\-.....................-..
-..........\.\.............|........\..|........./.................../...........\.................\.../.......
-....................-...|......|................../--.-.............-......................./.................
-......./.........|......./../.....................-....//..|..............-..|.............\............\.....
-...|...........-........................../.-..\............................\...|................\.//.......\.
-../.....\..\.......-.............../.....|../.......................\-.................../.......-|/..........
-......\.....................|.....\.............-.....\........../..........\.....-............|....\...-.....
-...../.....................-|...\..-......./.............../.|..../................\..-...........\........./.
-......|..\...........................\.........../.-............|....................-....|-.......-....\.....
-........................................./.......|......-../../.............../....................|..........
-........\.....|....../.......\.........../....../....-.........-.............||\...................-...|......
-...........|.................\..\.............|...............\.....|..............................|..........
-............|....../\....\..../.................\.-./......../\|../|..........................................
-........................................................-|....................\....|.............|..-.........
-/.|.............\\......\......./\.....................|.../.....................-...-.........../.-.....\....
-.........................\/....-...\......./......-...........................--..|........\-..../............
-.............-|...\........./..\........../..../..-.......\\.............................../.......|..../|....
-....-...../......|.................|........-.......|.....|.../........-.....\.../..\.................../.....
-.............../................|...........\............/-..|......../............-./.-./...............-....
-.....\............|..\...............\..............\\.....-......./|....|.....--../...-..............\.......
-................|............../................/......./............-..-.................-....||......./....\
-.\..............\................|.......|.............|.........\|........\....-..../.............\..\/......
-./.....\.......|......|.\..|.../.............-......\...............................-......\......\...........
-.....|...../........../.......|.......................-.-.....\..................\............/....--./..-....
-....................|..............-.\\......\..........\.....\.-.../.-.......-.......-.............\......-..
-........./...................|.../....\...|...............|./../...-.........\-.................-.............
-....................../.....\....-.\.............|............-......\.........\......\.......................
-................\..\...|....-./..............-...\\.........\..........|......-.|/./.|..........\.\......../..
-......\........-.............-/.............../...............................-.............|..........|/.....
-....|.......-|...................................../.........\................./..........................\...
-....\.......\\........-....\........\....\.............\............\........./........................\......
-../.............................................................-......\..............|...................../.
-............/../.........................\................................................../...-.............
-.\........................\/.-...................-.......-...............|.....................-..............
-./........................|.|/................\..............././..................................|.-........
-............/........-..-..........|..............-........\../.|...\............................../..........
-............\/......-.........\\............-.....................\..........\....................|..../..../.
-..........\.../.....\.....................-..\..\.|..|....../-..|......-...|............-.....................
-......................-........\.........-......................|..........-..................................
-.|................\......-...........|.../....../............................................/......../...|../
-.........-..........\...-...-................\............/..-....|\..............-.......\.|..-.../-.........
-..|....../......./../-.............../....-......|-................................./......-...-...........-..
-.............../............../....-....\......../.\../...................-............../\............|.\.|..
-..|....\................-.....|.......................|\......................\.\..-...-..-...........|.......
-.......................|......................\.../......|.................-..../..-..-.\....\................
-..........................................-..........|-......................./-............-.................
-..../...../....\.|........................................././.../...........................\................
-......./...................................-............|-./.-.........\.|.../.-//...................-........
-/......................|............|/.............../-..............-...............|.......|......|...|.....
-....|.....-....-.....................\../..-...................\........./.../................./......-.......
-........................\..............................-.\.-...|..................../............./.....-..../
-......-.-...-........-.......\........../......|.........|..................../.....\./.....--...............|
-.........-/...............-\...\..........\................|........./.../........\.|......|..................
-..|/.................../......................................................../.....\..|.....|......-....-..
-......................./......................\.........-./......................-............................
-.....-.|.\................-............................|.............-........................../....-........
-..../..|..|.\....|....\.................\.........|...-..-................/......|............/..\..........|."#;
 }
@@ -149,244 +149,198 @@ do you get if you multiply the total number of low pulses sent by the total numb
 
  */
 
-#[cfg(test)]
-mod test {
-    use std::collections::{HashMap, VecDeque};
+use common::{graph::Graph, parse, random::Rng, ParseError, Solution, Unsolved};
+use std::collections::VecDeque;
 
-    #[derive(Copy, Clone, Debug)]
-    enum Pulse {
-        Low,
-        High,
-    }
-    use Pulse::*;
-
-    #[derive(Debug)]
-    enum Module<'a> {
-        FlipFlop {
-            state: bool,
-            outputs: Vec<usize>,
-        },
-        Conjunction {
-            inputs: Vec<(usize, Pulse)>,
-            outputs: Vec<usize>,
-        },
-        Broadcaster {
-            outputs: Vec<usize>,
-        },
-        Button {
-            outputs: Vec<usize>,
-        },
-        #[allow(dead_code)]
-        Output(&'a str),
-    }
-    use Module::*;
-
-    impl<'a> Module<'a> {
-        fn outputs(&self) -> Option<&Vec<usize>> {
-            match self {
-                FlipFlop { state: _, outputs } => Some(outputs),
-                Conjunction { inputs: _, outputs } => Some(outputs),
-                Broadcaster { outputs } | Button { outputs } => Some(outputs),
-                Output(_) => None,
-            }
-        }
+#[derive(Copy, Clone, Debug)]
+pub enum Pulse {
+    Low,
+    High,
+}
+use Pulse::*;
+
+#[derive(Debug, Clone)]
+pub enum Module {
+    FlipFlop { state: bool },
+    Conjunction { inputs: Vec<(usize, Pulse)> },
+    Broadcaster,
+    Output,
+}
+use Module::*;
 
-        fn outputs_mut(&mut self) -> Option<&mut Vec<usize>> {
-            match self {
-                FlipFlop { state: _, outputs } => Some(outputs),
-                Conjunction { inputs: _, outputs } => Some(outputs),
-                Broadcaster { outputs } | Button { outputs } => Some(outputs),
-                Output(_) => None,
-            }
-        }
+/// The modules, and the graph of which modules each of them sends pulses to.
+pub struct Machine<'a> {
+    graph: Graph<&'a str>,
+    modules: Vec<Module>,
+    broadcaster: usize,
+}
 
-        fn num_outputs(&self) -> usize {
-            match self.outputs() {
-                Some(outputs) => outputs.len(),
-                None => 0,
+/// Pulse that a module sends on after receiving `pulse` from `sender`, if
+/// it sends one at all.
+fn receive(module: &mut Module, sender: usize, pulse: Pulse) -> Option<Pulse> {
+    match (module, pulse) {
+        (FlipFlop { .. }, High) => None,
+        (FlipFlop { state }, Low) => {
+            *state = !*state;
+            Some(if *state { High } else { Low })
+        }
+        (Conjunction { inputs }, pulse) => {
+            inputs.iter_mut().find(|(i, _p)| *i == sender).unwrap().1 = pulse;
+            if inputs.iter().all(|(_i, p)| matches!(p, High)) {
+                Some(Low)
+            } else {
+                Some(High)
             }
         }
+        (Broadcaster, pulse) => Some(pulse),
+        (Output, _) => None,
     }
+}
 
-    fn parse_input(input: &str) -> (usize, Vec<Module>) {
-        let input = input.trim();
-        let (mut modules, mut indexmap) = input.lines().fold(
-            (
-                vec![Button {
-                    outputs: Vec::new(),
-                }],
-                HashMap::<&str, usize>::new(),
-            ),
-            |(mut modules, mut indexmap), line| {
-                let (name, _) = line
-                    .trim_start_matches(&['%', '&'])
-                    .split_once(" -> ")
-                    .unwrap();
-                indexmap.entry(name).or_insert(modules.len());
-                modules.push(match name {
-                    _ if line.starts_with("%") => FlipFlop {
-                        state: false,
-                        outputs: Vec::new(),
-                    },
-                    _ if line.starts_with("&") => Conjunction {
-                        inputs: Vec::new(),
-                        outputs: Vec::new(),
-                    },
-                    "broadcaster" => Broadcaster {
-                        outputs: Vec::new(),
-                    },
-                    _ => panic!("Unrecognized module"),
-                });
-                (modules, indexmap)
-            },
-        );
-        let mut outputs: Vec<usize> = Vec::new();
-        for line in input.lines() {
-            let (name, dststr) = line
-                .trim_start_matches(&['%', '&'])
-                .split_once(" -> ")
-                .unwrap();
-            let mi = indexmap[name];
-            outputs.extend(
-                dststr
-                    .split(", ")
-                    .filter_map(|dst| match indexmap.get(dst).clone() {
-                        Some(mi) => Some(*mi),
-                        None => {
-                            modules.push(Output(dst));
-                            indexmap.insert(dst, modules.len() - 1);
-                            Some(modules.len() - 1)
-                        }
-                    }),
-            );
-            for &di in &outputs {
-                match &mut modules[di] {
-                    Conjunction { inputs, .. } => {
-                        match inputs.iter_mut().find(|(i, _p)| i == &mi) {
-                            Some((_, p)) => *p = Low,
-                            None => inputs.push((mi, Low)),
-                        }
-                    }
-                    _ => {}
+fn parse_input(input: &str) -> Result<Machine<'_>, ParseError> {
+    let input = input.trim();
+    let mut graph = Graph::new();
+    let mut kinds = Vec::new();
+    for line in input.lines() {
+        let (name, dststr) = parse::split_once(line.trim_start_matches(['%', '&']), " -> ")?;
+        let mi = graph.node(name);
+        kinds.resize(graph.len(), None);
+        kinds[mi] = Some(match name {
+            _ if line.starts_with('%') => FlipFlop { state: false },
+            _ if line.starts_with('&') => Conjunction { inputs: Vec::new() },
+            "broadcaster" => Broadcaster,
+            _ => return Err(ParseError::at(line, "'%', '&' or broadcaster")),
+        });
+        for dst in dststr.split(", ") {
+            let di = graph.node(dst);
+            graph.add_edge(mi, di);
+        }
+    }
+    // Modules that only receive pulses are outputs.
+    kinds.resize(graph.len(), None);
+    let modules = kinds
+        .into_iter()
+        .enumerate()
+        .map(|(mi, kind)| match kind {
+            Some(Conjunction { .. }) => {
+                let mut inputs = graph.incoming(mi).to_vec();
+                inputs.sort();
+                inputs.dedup();
+                Conjunction {
+                    inputs: inputs.into_iter().map(|i| (i, Low)).collect(),
                 }
             }
-            match modules[mi].outputs_mut() {
-                Some(dst) => dst.extend(outputs.drain(..)),
-                None => {}
-            }
-        }
-        let broadcaster_i = modules
-            .iter()
-            .position(|m| matches!(m, Broadcaster { .. }))
-            .unwrap();
-        match modules[0].outputs_mut() {
-            Some(dst) => dst.push(broadcaster_i),
-            None => {}
-        }
-        (broadcaster_i, modules)
+            Some(module) => module,
+            None => Output,
+        })
+        .collect::<Vec<_>>();
+    let broadcaster = modules
+        .iter()
+        .position(|m| matches!(m, Broadcaster))
+        .ok_or_else(|| ParseError::after(input, "a broadcaster module"))?;
+    Ok(Machine {
+        graph,
+        modules,
+        broadcaster,
+    })
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// All the modules, their wiring and which one is the broadcaster.
+    type Parsed<'a> = Machine<'a>;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Machine<'_>, ParseError> {
+        parse_input(input)
     }
 
-    fn part_1(input: &str) -> usize {
-        let (broadcaster_i, mut modules) = parse_input(input);
+    fn part_1(machine: &Machine) -> usize {
+        let (graph, broadcaster) = (&machine.graph, machine.broadcaster);
+        let mut modules = machine.modules.clone();
         let mut queue = VecDeque::<(usize, usize, Pulse)>::with_capacity(modules.len());
         let mut nlo = 0usize;
         let mut nhi = 0usize;
         for _ in 0..1000 {
             queue.clear();
             nlo += 1; // Button to broadcaster.
-            queue.push_back((broadcaster_i, 0, Low));
+            queue.push_back((broadcaster, broadcaster, Low));
             while let Some((receiver, sender, pulse)) = queue.pop_front() {
-                let pulse = match (&mut modules[receiver], pulse) {
-                    (FlipFlop { .. }, High) => continue,
-                    (FlipFlop { state, .. }, Low) => {
-                        if *state {
-                            *state = false;
-                            Low
-                        } else {
-                            *state = true;
-                            High
-                        }
-                    }
-                    (Conjunction { inputs, .. }, pulse) => {
-                        inputs.iter_mut().find(|(i, _p)| *i == sender).unwrap().1 = pulse;
-                        if inputs.iter().all(|(_i, p)| matches!(p, High)) {
-                            Low
-                        } else {
-                            High
-                        }
-                    }
-                    (Broadcaster { .. }, pulse) => pulse,
-                    (Output(_), _) | (Button { .. }, _) => continue,
+                let Some(pulse) = receive(&mut modules[receiver], sender, pulse) else {
+                    continue;
                 };
-                let noutputs = modules[receiver].num_outputs();
+                let dst = graph.edges(receiver);
                 match pulse {
-                    Low => nlo += noutputs,
-                    High => nhi += noutputs,
-                }
-                match modules[receiver].outputs() {
-                    Some(dst) => queue.extend(dst.iter().map(|dst| (*dst, receiver, pulse))),
-                    None => {}
+                    Low => nlo += dst.len(),
+                    High => nhi += dst.len(),
                 }
+                queue.extend(dst.iter().map(|dst| (*dst, receiver, pulse)))
             }
         }
-        return nlo * nhi;
+        nlo * nhi
     }
 
-    #[allow(dead_code)]
-    fn part_2(input: &str) -> usize {
-        let (broadcaster_i, mut modules) = parse_input(input);
-        println!("{}", modules.len());
-        println!("{modules:?}");
-        // todo!();
-        let mut queue = VecDeque::<(usize, usize, Pulse)>::with_capacity(modules.len());
-        let mut count = 1usize;
-        loop {
-            queue.clear();
-            queue.push_back((broadcaster_i, 0, Low));
-            while let Some((receiver, sender, pulse)) = queue.pop_front() {
-                let pulse = match (&mut modules[receiver], pulse) {
-                    (FlipFlop { .. }, High) => continue,
-                    (FlipFlop { state, .. }, Low) => {
-                        if *state {
-                            *state = false;
-                            Low
-                        } else {
-                            *state = true;
-                            High
-                        }
-                    }
-                    (Conjunction { inputs, .. }, pulse) => {
-                        inputs.iter_mut().find(|(i, _p)| *i == sender).unwrap().1 = pulse;
-                        if inputs.iter().all(|(_i, p)| matches!(p, High)) {
-                            Low
-                        } else {
-                            High
-                        }
-                    }
-                    (Broadcaster { .. }, pulse) => pulse,
-                    (Output(name), Low) if (*name == "rx") => {
-                        return count;
-                    }
-                    (Output(name), pulse) => {
-                        println!("{name}, {pulse:?}");
-                        continue;
-                    }
-                    (Button { .. }, _) => continue,
-                };
-                match modules[receiver].outputs() {
-                    Some(dst) => queue.extend(dst.iter().map(|dst| (*dst, receiver, pulse))),
-                    None => {}
+    fn part_2(_: &Machine) -> Unsolved {
+        Unsolved
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names = vec![String::from("rx")];
+        let mut name = |rng: &mut Rng| loop {
+            let name = rng.string("abcdefghijklmnopqrstuvwxyz", 2);
+            if !names.contains(&name) {
+                names.push(name.clone());
+                break name;
+            }
+        };
+        // Every chain of flip-flops counts the button presses in binary. Its
+        // conjunction sees when the count gets to an odd number, and then
+        // resets the chain to zero and signals the final conjunction through
+        // an inverter.
+        let last = name(rng);
+        let mut lines = vec![format!("&{last} -> rx")];
+        let mut starts = Vec::new();
+        for _ in 0..size.clamp(1, 4) {
+            let flips: Vec<String> = (0..12).map(|_| name(rng)).collect();
+            let (counter, inverter) = (name(rng), name(rng));
+            let number = rng.range(2049..4096usize) | 1;
+            let mut resets = vec![inverter.as_str()];
+            for (bit, flip) in flips.iter().enumerate() {
+                let mut outputs: Vec<&str> =
+                    flips.get(bit + 1).map(String::as_str).into_iter().collect();
+                if number >> bit & 1 == 1 {
+                    outputs.push(&counter);
+                }
+                if number >> bit & 1 == 0 || bit == 0 {
+                    resets.push(flip);
                 }
+                rng.shuffle(&mut outputs);
+                lines.push(format!("%{flip} -> {}", outputs.join(", ")));
             }
-            count += 1;
+            rng.shuffle(&mut resets);
+            lines.push(format!("&{counter} -> {}", resets.join(", ")));
+            lines.push(format!("&{inverter} -> {last}"));
+            starts.push(flips[0].clone());
         }
+        lines.push(format!("broadcaster -> {}", starts.join(", ")));
+        rng.shuffle(&mut lines);
+        lines.join("\n")
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE_1), 32000000);
-        assert_eq!(part_1(EXAMPLE_2), 11687500);
-        assert_eq!(part_1(INPUT), 836127690);
+        assert_eq!(Solver::solve_1(EXAMPLE_1), 32000000);
+        assert_eq!(Solver::solve_1(EXAMPLE_2), 11687500);
+        if let Some(input) = common::input::for_test(2023, 20) {
+            assert_eq!(Solver::solve_1(&input), 836127690);
+        }
     }
 
     #[test]
@@ -409,66 +363,5 @@ broadcaster -> a
 &inv -> b
 %b -> con
 &con -> output
-";
-
-    const INPUT: &str = "
-%ng -> vz
-%hv -> zz
-%cn -> fv, kp
-%sc -> sm
-&rt -> jf, hv, bs, kt, fn
-%bc -> fv
-%sb -> gk
-%vz -> gk, lg
-%sm -> mx
-%kp -> fv, pq
-&gk -> mx, sc, vq, bz, ng, zk, sm
-%bs -> rt, mk
-%pn -> rt
-%rq -> sl, xd
-%jr -> fv, bc
-%vm -> rt, pn
-%rk -> gk, sb
-%gs -> xt
-%dc -> sl
-%bz -> gk, sc
-%ql -> sl, fz
-%kt -> bt
-%gn -> fv, hk
-broadcaster -> bs, rq, cn, bz
-%rl -> rh, gk
-&hj -> rx
-%vj -> rt, rr
-%jx -> fv, bf
-&ks -> hj
-%rh -> gk, vq
-%hk -> jx
-%fn -> vj
-%jl -> mr, sl
-%vq -> ng
-%mr -> sl, dc
-%fk -> cc
-%jc -> fk, sl
-&jf -> hj
-%lg -> gk, rk
-%zz -> jg, rt
-%pq -> lx, fv
-%xt -> gn
-%bf -> fv, jr
-&qs -> hj
-%gv -> sl, jl
-%bt -> rt, fn
-%mm -> sl, ql
-%jg -> vm, rt
-%lx -> gs
-%rr -> hv, rt
-&fv -> xt, qs, gs, cn, lx, hk
-%mx -> rl
-&zk -> hj
-&sl -> fk, rq, fz, xd, ks
-%fz -> jc
-%mk -> rt, kt
-%xd -> mm
-%cc -> sl, gv
 ";
 }
@@ -80,78 +80,101 @@ However, the Elf actually needs to get 64 steps today, and the map he's handed y
 Starting from the garden plot marked S on your map, how many garden plots could the Elf reach in exactly 64 steps?
  */
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
-    use std::collections::HashSet;
+use common::{random::Rng, ParseError, Solution, Unsolved};
+use grid::Grid;
+use std::collections::HashSet;
+
+/// Whether every plot is walkable, and the position of the start plot.
+pub struct Garden {
+    grid: Grid<bool>,
+    start: usize,
+}
 
-    fn part_1(input: &str, max_steps: usize) -> usize {
-        let (grid, rows, cols, start) = {
-            let input = input.trim();
-            let cols = input.find('\n').unwrap();
-            let grid = input
-                .lines()
-                .flat_map(|line| line.chars().map(|tile| tile == '.' || tile == 'S'))
-                .collect_vec();
-            assert_eq!(grid.len() % cols, 0);
-            let rows = grid.len() / cols;
-            (
-                grid,
-                rows,
-                cols,
-                input
-                    .lines()
-                    .enumerate()
-                    .find_map(|(row, line)| {
-                        match line.chars().enumerate().find_map(|(col, c)| {
-                            if c == 'S' {
-                                Some(col)
-                            } else {
-                                None
-                            }
-                        }) {
-                            Some(col) => Some(row * cols + col),
-                            None => None,
-                        }
-                    })
-                    .unwrap(),
-            )
-        };
-        let mut visited = HashSet::<(usize, usize)>::new();
-        let mut stack = Vec::<(usize, usize)>::with_capacity(grid.len());
-        stack.push((start, 0));
-        while let Some((pos, steps)) = stack.pop() {
-            if steps > max_steps || !visited.insert((pos, steps)) {
-                continue;
-            }
-            stack.extend(
-                (0..4)
-                    .filter_map(|dir| match dir {
-                        0 if pos >= cols => Some(pos - cols),
-                        1 if pos % cols < cols - 1 => Some(pos + 1),
-                        2 if pos / cols < rows - 1 => Some(pos + cols),
-                        3 if pos % cols > 0 => Some(pos - 1),
-                        _ => None,
-                    })
-                    .filter_map(|npos| {
-                        if grid[npos] {
-                            Some((npos, steps + 1))
-                        } else {
-                            None
-                        }
-                    }),
-            );
+fn count_reachable(garden: &Garden, max_steps: usize) -> usize {
+    let Garden { grid, start } = garden;
+    let start = *start;
+    let mut visited = HashSet::<(usize, usize)>::new();
+    let mut stack = Vec::<(usize, usize)>::with_capacity(grid.len());
+    stack.push((start, 0));
+    while let Some((pos, steps)) = stack.pop() {
+        if steps > max_steps || !visited.insert((pos, steps)) {
+            continue;
         }
-        visited
-            .iter()
-            .filter(|(_pos, steps)| *steps == max_steps)
-            .count()
+        stack.extend(grid.neighbours_4(pos).filter_map(|npos| {
+            if grid[npos] {
+                Some((npos, steps + 1))
+            } else {
+                None
+            }
+        }));
+    }
+    visited
+        .iter()
+        .filter(|(_pos, steps)| *steps == max_steps)
+        .count()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Garden;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Garden, ParseError> {
+        let tiles = Grid::parse(input, "'#', '.' or S", |c| {
+            matches!(c, '#' | '.' | 'S').then_some(c)
+        })?;
+        Ok(Garden {
+            grid: tiles.map(|tile| *tile == '.' || *tile == 'S'),
+            start: tiles
+                .position(|tile| *tile == 'S')
+                .ok_or_else(|| ParseError::after(input, "the starting position S"))?,
+        })
+    }
+
+    fn part_1(garden: &Garden) -> usize {
+        count_reachable(garden, 64)
     }
 
+    fn part_2(_: &Garden) -> Unsolved {
+        Unsolved
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Like the real garden, the start is in the middle of an odd sized
+        // square, with its row, its column and the edges clear of rocks.
+        let side = size.max(2) | 1;
+        let middle = side / 2;
+        let tiles: Vec<char> = (0..side * side)
+            .map(|pos| {
+                let (row, col) = (pos / side, pos % side);
+                let clear = [row, col]
+                    .iter()
+                    .any(|&i| i == 0 || i == middle || i == side - 1);
+                if (row, col) == (middle, middle) {
+                    'S'
+                } else if !clear && rng.chance(0.1) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        Grid::from_vec(tiles, side).to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE, 6), 16);
-        assert_eq!(part_1(INPUT, 64), 3816);
+        assert_eq!(count_reachable(&Solver::parse(EXAMPLE).unwrap(), 6), 16);
+        if let Some(input) = common::input::for_test(2023, 21) {
+            assert_eq!(Solver::solve_1(&input), 3816);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -166,137 +189,4 @@ mod test {
 .##.#.####.
 .##..##.##.
 ...........";
-
-    const INPUT: &str = "
-...................................................................................................................................
-...#........#..#.#....#.....#..........#......#..........#...........................#.................#...#.#.....................
-................#....#.........#.#...#............#..#....#..................##..........#........#.....#....###..#....#...........
-.#...........##..............#...#.........#..............#.................#...............#...................................##.
-..#...........#...........##....##.............#.....#.........................#................#..........................#.......
-..............#......................#...........#..#...#......................#.............#.......#.............................
-...........#..#..............#.#.....#...........................................#.....###..............#.#..............#.......#.
-.......#....#............#......#....#.............................................#.....#.........................................
-....##.........##......##.........##.........#....#............#.............#...#..#....#...................#.........#.#.........
-..#...........#...#.#.........................................................#.....#.#...#.####.......##...................#...#..
-.......#....##...................#..#......#....#...................#..........#...............#.............#.......#..#..........
-.................#.#.#####....#....#....##.#...............#...#.....#.#..............#...................#...#................#...
-...........................#.....#...#.......................#....##.....................##....#..#..#.............................
-...#.#...............................................................#.#................#.#.......................#................
-....##.................................#.#.....#......................#....................#........#.................#.#..#.......
-.....#.........#.#...#..........#..#.#......#........................................#.................#..........#.##..........#..
-.........#......#....#..#...................#............................#.............................#...#.......#...............
-.#.#.........#...#..........#..#.........#..#...............##......##...#...........................#........#....................
-......#......................#.........#....................#.#............#.#............#.............#........#...#.#.........#.
-........#....#..................#......................#......#.#.......#..............................##...............#..........
-.#.......#...#.#..#..................................#............#..#...#..................#.#...#.#..............#...#.....#.....
-..#.....#...##..#......#.......#......#..............#....#..........#..........#.#.............#.....#.......#...#..###.##........
-...................#..#.....#.......................#.......#............#.....#..#.........#....#....#....#.................#.....
-........#.......###....##.......#.....#........#........#..##.....#.......##...#...#........#..............#.#.....................
-...............#......##..............................#............#..........#...###...................#.#..................#.....
-..#...#..#.#.#.#..........#.#...#..#.....................#..#.....#...........#...............#..............#..#..........#..#....
-........#........#..#.......##.##..........##................##............#........#...........................#..#...#...##...#..
-......#.#..#..#..........#.#...................#......#..............#.....#...........#..............##...........#...............
-.....#...#...#.......##...........................#................#....#............#...........#.....#......#........##..........
-..#.#........#..#.#...#.....#............#..................................#.............#................##....##................
-......#.......#......#..........................#.........#.#.............#..#.......#...................#..#......................
-......................#..................#.............#...#................#..........................#.##........#....#..........
-..#....#.##...............#.............#.............................#.#..###.#...........#............................#..........
-.....................................#.....#...#...#..#........#.....................#.#..#...#........#.....#.................#.#.
-.....................#.....................#........................#..........#...#.....#..............#......#.................#.
-.........#...#......#..#......................#.........#............#.........#........................#...#..###.#.....#..#......
-.............#..#.......#..........#.............#.#...#.#.#....#...........#......................................................
-.....##....#.......................#......................#...#.#..............................#..................##..#............
-.....#.........#..........................#.........#.............#...#....#............#....#...............#...#..##.........#...
-..#................................#......##..##..#.......#..#..................#.....................................#.#...#......
-.....................#..........#.#...........#..............#.............#.#......................#...........#.###..............
-......##.#.#.............................##........#.............................#.....#.....#.......................#.............
-................#..............................#....#...................#.##......#...................#...........#....##..........
-.#............#.....................#.....#...............................#.#.........#.............#...#........#.................
-.#.........##...................##..............#..........#.#................#.......................#........................#...
-..#............#.................#..............#..........##.....#..##..........#............#.......................#............
-....#.#.......#.........#.............#.......#......##....#............#................#........#................#..........#....
-..............................#....###......#..#..#....................##..........#.#.##.......#.........................#...##...
-........#............#...##.....................##..#......##......#..........#......#...............................#.#...#.......
-.....#.#.#............#....#....#.....#.......#.#....#.........#......#..................#.#..#..........................#.........
-.........#...........................#.....#..#.......#.....#...#........#......#...................#.........#....................
-....#....#............#........#..................#.......................#..#.##..............#................#..........#.......
-...###...............##..............#.......##..#.#.....#.....#.......#...#.......##......................##...#...........#.#....
-.......................#.....#.........#..........#......#...............#.....#.....#....#.........#..#.........#.................
-................................................#.#.#....#......#...#..............#........#.#....#..........#.............#......
-.#....#..................#.....#...#.......#......................#..#.........................#..#...........##....#..............
-.....................##................#.#....#......#......#......#....#...#....................#..........##.....................
-....#................#....#..........#.#.....................#.......#...#......#........#.#.#..........#...........#..........#.#.
-..#..................#.................#..#....#.#.#.....#.#...#.......#..#......................#...................#.............
-..#.............##.........#..#.......#......#.............#................##..................#...#......#............#..........
-............#.....##.............#..#...#....###...#.........................#..#.#........#..................#......#.............
-....................#.#........#.......#.........#....##......##....#...#.#.##.#..#...#...............#...#.......#....#...........
-..................................#...#.........#..............#........#.............#...........................#.......#........
-..............##.......#.............#.#.........#..#.#.....................#.......................##...##..#.........#...........
-..........#.....#.#..##....#..................#....#.......#............#.........#...#........#..................#.......#........
-.................................................................S.................................................................
-..............##.#.....................#...........##..##.......#............#....#.#.........#..#.....................##...#......
-...........................#.#...##..##............#......#............#...........#.#.#......#...#......................#.........
-....................#...........#...........#.........#.......................#.........#......##...........#...........#..........
-..................#......#.........................#.....###.......#................#..#.........#.........####..........#.........
-.................#......#....................#.#.#..........###.......................#...#.......#...#................#.#.........
-.......................#..#........#..##...#...........#.......#.......................#............#...#.....#.#..................
-...............#.....................#..#..#................#.#...........#...#......#......#..#.....#.........#.......#...........
-.#.................#........#.........#......#.....................#......................#..........................#..........#..
-................##......#..........#.....#..........#.........#....................#....#...........#..#.....#.....................
-.......................................#.......................#......#...........................#...#............................
-..............................#..............................#.....#...............#..............#...#....#................#....#.
-...#..#...........#...........##........##...........#.#.......................#..........#.#.......#...#..................##......
-.....#.............##........#.#.#.............#.......#............#.....#.........#.............#......................#...#.#...
-........#.....................#..........#......................#.............#.#.............#........#...........................
-..........................#..........#...............#.............#.....##...#.#......#.........#.......#.............#...........
-.#.....#..........................#.#..#..#..............#................#.............##..................................#......
-...........#...................##.........................#.....#......#...#....#...............#............#.............##.#....
-...#...........................#................#..#......#...#.............#..#.....#.............................................
-.......##..#..............#.#....................#.....#.............#....................#............#.#....................##...
-.##.#..........#........#.....................#.#.......#..#..#..........##...........................................#.......#....
-..............#..#........#......#.................#.....#.........#.......#..............#..........##.....................#......
-...........#...#.............#........#......#........#.........#......#.....#.....#....................#...........#..........#...
-.#...............................#...#.....#.##........#............#...#...#..#................#.......................##.......#.
-.........#.#...............................................#...#.....#.........................................#....#..............
-.....#..#...#...................#................#......#.#....................#......#...#.....#............#.......#..........#..
-..............#.....................#.....................................#.......#..#......#..................#.......#.....#.....
-....#....#...#...##................#..#.......#..#................#..#..............#...........#..........#.##......#....#...#.#..
-...#........#.........#..........#..#......#.....#..........................#........#................................#............
-.........#........#..................................#..........................#.........................#....#......#............
-.#..................................................#....##....#....#...#..#...#..........#.......................#................
-........#...#............................##.........................#.....#...##...#.........#............#.........#..............
-...#.#.......#.......................#.....#..............#...#.............#...#......................................#...#....#..
-....#.......#..#......................##...#....##....#............#.........................................#.....................
-.......#....................#.........#...............#.....#.#....#................#...#................#........#................
-..#...#....#......#......#....#........#..##....#....##..#..##.#..#.....................#..........#.......#................##.....
-......#.#............#....................#.....#...............#...#...............#...............###.#......#.........##........
-..................#........................##..#.......#....#......#.................#.............#......#....#....#......#...#...
-...#..#.......#....#...........#.....................#......................#..........#..........#........##......................
-.....##.....#.....#...#...#..........................##....................##..#.....................#......##.........#.....#.....
-....##.......##.#..#..#.........#...#.............#.....#..................................................#.#.........#....#......
-..........#..#.##..............#.................#..............#......#.#.##.......#.........................#....#.............#.
-...........#.......................#..............#..##.....#...#.#.........................................##........#....#.....#.
-..............#..#...................................#...................#.....#..............#...........#.........#..#...........
-..........#.#...................#...............#.....................#..........#.........................#.#.....................
-........##...#......#.#...##..#.....#................#............#........#....#..............#....#.....................#........
-.................#..#..#..#.................................#................#.....................#....................#..#.......
-...............#......#...#....#.#..#.#......................#........##..........................##...........................#...
-.............#....................#.#.........................#....#......#.............###........#...#........#....#....#........
-............................#....#.............................#.............#.........##..#....#.....##...........................
-..#............#.#......#.............................#.........#..#.......................#.......................................
-..#........#..#....#.......#...#..........#............#....#.#.......#.............#......................##.....#................
-.........##.#.............#...............................#.............................#.........................#.....#..........
-..............#...#......#..###...................................#.....................................#..#...#...............#...
-.....#.#...............#.........................#..............#.....#..........................#.#.............#..#......#.......
-...#...###..#........#........#.............##................#....#...................................#..#...##....##.............
-.....#.............#...............#..#...##.....#...................#............#..................#..##...............#.#.......
-.....#....#........#.#..#.........#.........#.......#...................................#.................#.........#......#.....#.
-......#...........................#....#..............#.........#.............................#.......................#.....#......
-......#....#...#.......#.#......#..##...........#...........................#...#.#..#........................................##...
-.....................................................#.....................#........#.......#......#........#.##.........#..#....#.
-...........#.#.....#......................#...#....................................#..................#.............#......#.......
-.#.....#.#.......................#......#..#................................#.......#...........##.........#....#..#......#..#.....
-..........................##..#.............#........#.............................###........................#.#..........#.#.....
-.....#..........#.#....................#...#......#........................#.#.....#..............##.................#.........#...
-...................................................................................................................................";
 }
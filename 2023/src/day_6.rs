@@ -131,20 +131,43 @@ a total of 71503 ways!
 How many ways can you beat the record in this one much longer race?
  */
 
-#[cfg(test)]
-mod test {
+use common::{parse, random::Rng, ParseError, Solution};
+
+/// The number words after `label` on the line.
+fn parse_words<'a>(line: &'a str, label: &str) -> Result<Vec<&'a str>, ParseError> {
+    parse::strip_prefix(line, label)?
+        .split_whitespace()
+        .map(|word| parse::number::<usize>(word).map(|_| word))
+        .collect()
+}
 
-    fn part_1(input: &str) -> usize {
-        fn parse_nums<'a>(line: &'a str) -> impl Iterator<Item = usize> + 'a {
-            let (_, numstr) = line.split_once(':').unwrap();
-            numstr
-                .trim()
-                .split_whitespace()
-                .map(|s| s.parse::<usize>().unwrap())
-        }
+pub struct Solver;
+
+impl Solution for Solver {
+    /// The number words on the time and distance lines.
+    type Parsed<'a> = (Vec<&'a str>, Vec<&'a str>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
         let mut lines = input.trim().lines();
-        parse_nums(lines.next().unwrap())
-            .zip(parse_nums(lines.next().unwrap()))
+        let times = parse_words(parse::next(&mut lines, input, "the times")?, "Time:")?;
+        let distances = parse_words(
+            parse::next(&mut lines, input, "the distances")?,
+            "Distance:",
+        )?;
+        if let Some(line) = lines.next() {
+            return Err(ParseError::at(line, "the end of the input"));
+        }
+        Ok((times, distances))
+    }
+
+    fn part_1((times, distances): &(Vec<&str>, Vec<&str>)) -> usize {
+        fn parse_nums<'a>(words: &'a [&str]) -> impl Iterator<Item = usize> + 'a {
+            words.iter().map(|s| s.parse::<usize>().unwrap())
+        }
+        parse_nums(times)
+            .zip(parse_nums(distances))
             .map(|(time, distance)| {
                 (0..time)
                     .map(|t| (time - t) * t)
@@ -154,20 +177,12 @@ mod test {
             .product::<usize>()
     }
 
-    fn part_2(input: &str) -> usize {
-        fn parse_digits(line: &str) -> usize {
-            let (_, numstr) = line.split_once(':').unwrap();
-            numstr
-                .trim()
-                .split_whitespace()
-                .collect::<Vec<_>>()
-                .join("")
-                .parse::<usize>()
-                .unwrap()
+    fn part_2((times, distances): &(Vec<&str>, Vec<&str>)) -> usize {
+        fn parse_digits(words: &[&str]) -> usize {
+            words.join("").parse::<usize>().unwrap()
         }
-        let mut lines = input.trim().lines();
-        let time = parse_digits(lines.next().unwrap()) as f64;
-        let distance = parse_digits(lines.next().unwrap()) as f64;
+        let time = parse_digits(times) as f64;
+        let distance = parse_digits(distances) as f64;
         let disc = (time * time) - (4. * distance);
         if disc < 0. {
             0
@@ -176,21 +191,50 @@ mod test {
         }
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let (mut times, mut distances) = (String::from("Time:    "), String::from("Distance:"));
+        // The numbers are joined up in part 2, so more races would overflow.
+        for _ in 0..size.clamp(1, 4) {
+            let time = rng.range(7..100usize);
+            // Short of the best distance, so the record can be beaten.
+            let distance = rng.range(0..time * time / 4);
+            times += &format!(" {time:>6}");
+            distances += &format!(" {distance:>6}");
+        }
+        format!("{times}\n{distances}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 288);
-        assert_eq!(part_1(INPUT), 1312850);
+        assert_eq!(Solver::solve_1(EXAMPLE), 288);
+        if let Some(input) = common::input::for_test(2023, 6) {
+            assert_eq!(Solver::solve_1(&input), 1312850);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 71503);
-        assert_eq!(part_2(INPUT), 36749103);
+        assert_eq!(Solver::solve_2(EXAMPLE), 71503);
+        if let Some(input) = common::input::for_test(2023, 6) {
+            assert_eq!(Solver::solve_2(&input), 36749103);
+        }
+    }
+
+    #[test]
+    fn t_parse() {
+        let input = format!("{EXAMPLE}\nTime: 3");
+        let err = Solver::parse(&input).unwrap_err().locate(&input);
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected the end of the input"
+        );
     }
 
     const EXAMPLE: &str = "Time:      7  15   30
 Distance:  9  40  200";
-
-    const INPUT: &str = "Time:        48     93     84     66
-Distance:   261   1192   1019   1063";
 }
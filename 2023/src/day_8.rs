@@ -106,66 +106,79 @@ does it take before you're only on nodes that end with Z?
 
  */
 
-#[cfg(test)]
-mod test {
-    use std::collections::HashMap;
+use common::{graph::Graph, math, parse, random::Rng, ParseError, Solution};
 
-    fn part_1(input: &str) -> usize {
-        let mut input = input.trim().lines().filter_map(|ln| {
-            let ln = ln.trim();
-            if ln.is_empty() {
-                None
-            } else {
-                Some(ln)
+/// The left / right instructions as 0 / 1, and the network with edges from
+/// every node to its left and right neighbours, in that order.
+pub struct Network<'a> {
+    steps: Vec<usize>,
+    graph: Graph<&'a str>,
+}
+
+/// The steps at which a ghost is on a node ending with 'Z'. Once the ghost
+/// is back at a node at the same point of the instructions, it goes round
+/// the same loop of `period` steps forever, from step `start` on.
+struct ZSteps {
+    /// Steps on the way to the loop, in order.
+    before: Vec<usize>,
+    start: usize,
+    period: usize,
+    /// Steps in the first round of the loop, in order.
+    looped: Vec<usize>,
+}
+
+impl ZSteps {
+    /// Follows the instructions from `node` until the walk loops.
+    fn new(network: &Network, mut node: usize) -> ZSteps {
+        let len = network.steps.len();
+        // The step at which each (node, instruction) pair was first seen.
+        let mut seen = vec![usize::MAX; len * network.graph.len()];
+        let mut steps = Vec::new();
+        let mut count = 0;
+        loop {
+            let state = node * len + count % len;
+            if seen[state] != usize::MAX {
+                let start = seen[state];
+                let looped = steps.split_off(steps.partition_point(|&s| s < start));
+                return ZSteps {
+                    before: steps,
+                    start,
+                    period: count - start,
+                    looped,
+                };
             }
-        });
-        let navsteps = input
-            .next()
-            .unwrap()
-            .chars()
-            .map(|c| match c {
-                'L' => 0usize,
-                'R' => 1usize,
-                _ => panic!("Invalid direction {}", c),
-            })
-            .collect::<Vec<_>>();
-        let nav = navsteps.iter().cycle();
-        let triplets = input
-            .map(|line| {
-                let (node, rhs) = line.split_once(" = ").unwrap();
-                let (left, right) = rhs
-                    .trim_matches(|c| c == '(' || c == ')')
-                    .split_once(", ")
-                    .unwrap();
-                (node, left, right)
-            })
-            .collect::<Vec<_>>();
-        let indexmap: HashMap<&str, usize> = HashMap::from_iter(
-            triplets
-                .iter()
-                .map(|(n, _, _)| *n)
-                .enumerate()
-                .map(|(i, n)| (n, i)),
-        );
-        let nodes = triplets
-            .iter()
-            .map(|(_, l, r)| [*indexmap.get(l).unwrap(), *indexmap.get(r).unwrap()])
-            .collect::<Vec<_>>();
-        let mut current = *indexmap.get("AAA").unwrap();
-        let end = *indexmap.get("ZZZ").unwrap();
-        let mut count = 0usize;
-        for n in nav {
-            if current == end {
-                break;
+            seen[state] = count;
+            if network.graph.name(node).ends_with('Z') {
+                steps.push(count);
             }
-            current = nodes[current][*n];
+            node = network.graph.edges(node)[network.steps[count % len]];
             count += 1;
         }
-        return count;
     }
 
-    fn part_2(input: &str) -> usize {
-        let mut input = input.trim().lines().filter_map(|ln| {
+    fn contains(&self, step: usize) -> bool {
+        if step < self.start {
+            self.before.binary_search(&step).is_ok()
+        } else {
+            let step = self.start + (step - self.start) % self.period;
+            self.looped.binary_search(&step).is_ok()
+        }
+    }
+}
+
+/// Past this many ways of picking one step in the loop of every ghost, the
+/// ghosts are walked instead.
+const MAX_COMBINATIONS: usize = 1 << 16;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = Network<'a>;
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+        let mut lines = input.trim().lines().filter_map(|ln| {
             let ln = ln.trim();
             if ln.is_empty() {
                 None
@@ -173,84 +186,188 @@ mod test {
                 Some(ln)
             }
         });
-        let navsteps = input
-            .next()
-            .unwrap()
-            .chars()
-            .map(|c| match c {
-                'L' => 0usize,
-                'R' => 1usize,
-                _ => panic!("Invalid direction {}", c),
-            })
-            .collect::<Vec<_>>();
-        let triplets = input
-            .map(|line| {
-                let (node, rhs) = line.split_once(" = ").unwrap();
-                let (left, right) = rhs
-                    .trim_matches(|c| c == '(' || c == ')')
-                    .split_once(", ")
-                    .unwrap();
-                (node, left, right)
+        let stepline = parse::next(&mut lines, input, "the instructions")?;
+        let steps = stepline
+            .char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(0usize),
+                'R' => Ok(1usize),
+                _ => Err(ParseError::at(&stepline[i..], "L or R")),
             })
-            .collect::<Vec<_>>();
-        let indexmap: HashMap<&str, usize> = HashMap::from_iter(
-            triplets
-                .iter()
-                .map(|(n, _, _)| *n)
-                .enumerate()
-                .map(|(i, n)| (n, i)),
-        );
-        let nodes = triplets
-            .iter()
-            .map(|(_, l, r)| [*indexmap.get(l).unwrap(), *indexmap.get(r).unwrap()])
-            .collect::<Vec<_>>();
-        triplets
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut graph = Graph::new();
+        for line in lines {
+            let (node, rhs) = parse::split_once(line, " = ")?;
+            let rhs = parse::strip_prefix(rhs, "(")?;
+            let (left, right) = parse::split_once(rhs, ", ")?;
+            let right = right
+                .strip_suffix(')')
+                .ok_or_else(|| ParseError::after(right, "')'"))?;
+            let node = graph.node(node);
+            for next in [left, right] {
+                let next = graph.node(next);
+                graph.add_edge(node, next);
+            }
+        }
+        // Nodes that are only ever pointed to have no edges of their own.
+        match (0..graph.len()).find(|&node| graph.edges(node).is_empty()) {
+            Some(node) => Err(ParseError::at(graph.name(node), "a node that is defined")),
+            None => Ok(Network { steps, graph }),
+        }
+    }
+
+    fn part_1(network: &Network) -> Option<usize> {
+        let mut current = network.graph.index(&"AAA")?;
+        let end = network.graph.index(&"ZZZ")?;
+        // Past that many steps the walk is going round without ever
+        // reaching the end.
+        let states = network.steps.len() * network.graph.len();
+        for (count, n) in network.steps.iter().cycle().take(states + 1).enumerate() {
+            if current == end {
+                return Some(count);
+            }
+            current = network.graph.edges(current)[*n];
+        }
+        None
+    }
+
+    fn part_2(network: &Network) -> Option<usize> {
+        let ghosts: Vec<ZSteps> = (0..network.graph.len())
+            .filter(|i| network.graph.name(*i).ends_with('A'))
+            .map(|start| ZSteps::new(network, start))
+            .collect();
+        let all_on_z = |step| ghosts.iter().all(|g| g.contains(step));
+        let Some(last) = ghosts.iter().max_by_key(|g| g.start) else {
+            return Some(0);
+        };
+        // Until the last ghost to get to its loop is there, the answer can
+        // only be one of its steps on the way.
+        if let Some(&step) = last.before.iter().find(|&&step| all_on_z(step)) {
+            return Some(step);
+        }
+        // From then on every ghost is on a 'Z' node at steps z + k * period,
+        // for each z in its loop. Every way of picking one z per ghost is a
+        // set of congruences with at most one solution.
+        let combinations = ghosts
             .iter()
-            .filter_map(|(n, ..)| {
-                if n.ends_with('A') {
-                    Some(*indexmap.get(n).unwrap())
-                } else {
-                    None
-                }
-            })
-            .map(|mut curr| {
-                let mut count = 0usize;
-                for n in navsteps.iter().cycle() {
-                    if triplets[curr].0.ends_with('Z') {
-                        break;
-                    }
-                    curr = nodes[curr][*n];
-                    count += 1;
-                }
-                count
-            })
-            .fold(1usize, |acc, n| {
-                let (mut min, mut max) = if acc < n { (acc, n) } else { (n, acc) };
-                let mut rem = max % min;
-                while rem != 0 {
-                    // Compute GCD using Euclid algo.
-                    max = rem;
-                    if max < min {
-                        (min, max) = (max, min);
-                    }
-                    rem = max % min;
-                }
-                // min is now the gcd.
-                acc * n / min
+            .try_fold(1usize, |n, g| n.checked_mul(g.looped.len()));
+        if combinations.is_some_and(|n| n <= MAX_COMBINATIONS) {
+            let mut solutions = vec![(0, 1)];
+            for ghost in &ghosts {
+                solutions = solutions
+                    .iter()
+                    .flat_map(|&solution| {
+                        ghost.looped.iter().filter_map(move |&z| {
+                            math::crt([solution, (z as i64, ghost.period as i64)])
+                        })
+                    })
+                    .collect();
+            }
+            return solutions
+                .into_iter()
+                .filter_map(|(x, period)| {
+                    let (x, period) = (x as usize, period as usize);
+                    let rounds = last.start.saturating_sub(x).div_ceil(period);
+                    x.checked_add(rounds.checked_mul(period)?)
+                })
+                .min();
+        }
+        // Walk the ghost with the fewest 'Z' nodes on its loop, until all of
+        // them have gone round their loops together.
+        let ghost = ghosts.iter().min_by_key(|g| g.looped.len())?;
+        let rounds = math::lcm_all(ghosts.iter().map(|g| g.period))? / ghost.period
+            + (last.start - ghost.start).div_ceil(ghost.period);
+        (0..rounds)
+            .flat_map(|round| ghost.looped.iter().map(move |&z| z + round * ghost.period))
+            .filter(|&step| step >= last.start)
+            .find(|&step| all_on_z(step))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let mut names = vec![String::from("AAA"), String::from("ZZZ")];
+        // A new name that ends with one of `ends`.
+        let mut name = |rng: &mut Rng, ends: &str| loop {
+            let name = rng.string(LETTERS, 2) + &rng.string(ends, 1);
+            if !names.contains(&name) {
+                names.push(name.clone());
+                break name;
+            }
+        };
+        let len = rng.range(2..=size.clamp(2, 100));
+        let steps: Vec<usize> = (0..len).map(|_| rng.range(0..2)).collect();
+        let mut primes = [3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut primes);
+        // Every ghost walks a path of a prime number of rounds of the
+        // instructions, ending at its 'Z' node, which leads back to the start
+        // of the path. The other side of every node leads anywhere, as it is
+        // never taken.
+        let mut nodes: Vec<(String, [Option<usize>; 2])> = Vec::new();
+        for (ghost, &prime) in primes[..size.clamp(1, 6)].iter().enumerate() {
+            let start = nodes.len();
+            let (first, last) = match ghost {
+                0 => (String::from("AAA"), String::from("ZZZ")),
+                _ => (name(rng, "A"), name(rng, "Z")),
+            };
+            let path = prime * len;
+            for k in 0..=path {
+                let node = match k {
+                    0 => first.clone(),
+                    _ if k == path => last.clone(),
+                    _ => name(rng, "BCDEFGHIJKLMNOPQRSTUVWXY"),
+                };
+                let mut next = [None; 2];
+                next[steps[k % len]] = Some(start + if k == path { 1 } else { k + 1 });
+                nodes.push((node, next));
+            }
+        }
+        let mut lines: Vec<String> = (0..nodes.len())
+            .map(|node| {
+                let [left, right] = nodes[node].1.map(|next| {
+                    let next = next.unwrap_or_else(|| rng.range(0..nodes.len()));
+                    nodes[next].0.clone()
+                });
+                format!("{} = ({left}, {right})", nodes[node].0)
             })
+            .collect();
+        rng.shuffle(&mut lines);
+        let steps: String = steps.iter().map(|&s| ['L', 'R'][s]).collect();
+        format!("{steps}\n\n{}", lines.join("\n"))
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 2);
-        assert_eq!(part_1(EXAMPLE_2), 6);
-        assert_eq!(part_1(INPUT), 19783);
+        assert_eq!(Solver::solve_1(EXAMPLE), Some(2));
+        assert_eq!(Solver::solve_1(EXAMPLE_2), Some(6));
+        assert_eq!(Solver::solve_1(EXAMPLE_3), None);
+        if let Some(input) = common::input::for_test(2023, 8) {
+            assert_eq!(Solver::solve_1(&input), Some(19783));
+        }
     }
 
     #[test]
     pub fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE_3), 6);
-        assert_eq!(part_2(INPUT), 9177460370549);
+        assert_eq!(Solver::solve_2(EXAMPLE_3), Some(6));
+        // A ghost that never reaches a 'Z' node, and one that reaches them
+        // at uneven steps, from the first step on.
+        assert_eq!(
+            Solver::solve_2("L\n\n11A = (11B, 11B)\n11B = (11A, 11A)"),
+            None
+        );
+        assert_eq!(
+            Solver::solve_2(
+                "L\n\n11A = (11Z, 11Z)\n11Z = (22Z, 22Z)\n22Z = (11B, 11B)\n11B = (11Z, 11Z)"
+            ),
+            Some(1)
+        );
+        if let Some(input) = common::input::for_test(2023, 8) {
+            assert_eq!(Solver::solve_2(&input), Some(9177460370549));
+        }
     }
 
     const EXAMPLE: &str = "RL
@@ -279,709 +396,4 @@ ZZZ = (ZZZ, ZZZ)";
 22C = (22Z, 22Z)
 22Z = (22B, 22B)
 XXX = (XXX, XXX)";
-
-    const INPUT: &str = "LRRLRLRRLRRRLRLRLRRLRRRLRRRLRRLRRRLRLRLRLRLRLRLRRRLRRLRRRLLLLRRRLRLLLRRRLLRLLRRRLRRRLRLRRLRRRLRRRLLRRRLRLRRRLLRRRLRLLRRRLRRLLRLRLRLRRRLRLLRLRLRRRLRLLRLRLRRRLLRRRLRRLRRRLRLRRLRLRRLRLRRLRRRLLRRRLLLRRRLLRRLRRLRRLRLLRRLRRRLRRLRLRLRRLRRLLLRRLRLRRRLRRRLRRRLLLRLRRRLLRRRLRLLRRRR
-
-NFK = (LMH, RSS)
-SLJ = (NBT, CDG)
-SKX = (SRC, KKX)
-LRB = (MNG, FSH)
-QMQ = (DMG, QRV)
-VRB = (PGG, PRG)
-QNB = (PFD, FFQ)
-BPK = (GFF, DFF)
-JTD = (NVV, VLD)
-PXD = (VNV, BDS)
-PXP = (VFH, DLR)
-XRH = (QMM, LDH)
-GLV = (SDM, MFS)
-TTM = (VGH, SJG)
-RHC = (DBR, QSQ)
-KNP = (FFB, SSB)
-VLL = (VNM, XQQ)
-SMJ = (PNH, MML)
-XJN = (SCD, TPG)
-GJL = (RJK, GGH)
-NFD = (CKB, HXV)
-XPH = (CDT, CVK)
-GNJ = (JFV, KLQ)
-CKC = (PCP, RXV)
-PTN = (MFX, GXV)
-HSD = (MGC, CGB)
-MFX = (MCK, PKT)
-QVQ = (LDG, JNX)
-PBB = (DXK, KJL)
-VNG = (JCH, GPT)
-LFM = (HCK, GGQ)
-SGL = (QPC, FJF)
-RFK = (VMS, CRB)
-TTJ = (RMQ, XHJ)
-FJP = (GCL, KXF)
-BRS = (LRK, NFD)
-HTL = (CBJ, BFM)
-QFP = (LRG, XQF)
-KKH = (QSQ, DBR)
-SLA = (HLN, TMV)
-JGS = (VMH, CHF)
-VNS = (HLN, TMV)
-FFM = (KKX, SRC)
-JHJ = (FMR, LVD)
-QSQ = (JVR, VVX)
-QKN = (LFQ, MDJ)
-JDC = (XFF, GFS)
-RPC = (TFS, TQL)
-HGD = (BHN, HKH)
-NDB = (CMJ, KDK)
-CQP = (TCK, RFK)
-SSB = (SNS, XPX)
-VXB = (QRS, SHF)
-RRT = (PJX, LTG)
-BBQ = (SBX, CFK)
-DJD = (KMQ, CHQ)
-LXR = (VXS, FLP)
-VHD = (QVQ, LQQ)
-BFM = (VQS, VMX)
-QGL = (CBD, VHD)
-MML = (SLT, MCH)
-VGV = (SNX, QBN)
-KND = (SQS, JFC)
-NFX = (XVF, GLX)
-HCK = (DFX, JGS)
-RSS = (MGG, CNL)
-KRH = (RKF, KJR)
-GMV = (BLX, FKF)
-CFB = (XPC, RRF)
-KLQ = (JGV, SNL)
-KCR = (NTC, GQL)
-SCB = (JSG, VBB)
-TSJ = (VSJ, HQD)
-SNL = (NNP, XBN)
-RXV = (PMB, RRT)
-MTH = (PCC, BPK)
-RRD = (GNL, MSJ)
-NVT = (CMD, FCF)
-KNF = (SQQ, JPS)
-SMK = (CKC, QTJ)
-RFB = (FDN, CJB)
-QXD = (NRN, GLV)
-LCR = (TRT, NMR)
-DPR = (GRM, HLQ)
-CMD = (FMV, NGL)
-LHP = (VVP, LCR)
-NDN = (BRH, PTF)
-JFC = (HFV, DLQ)
-VMX = (NVL, KVQ)
-HJF = (QFD, NDN)
-XHC = (JVC, JKS)
-LBN = (CCG, JRV)
-LPK = (XCM, VXL)
-HXJ = (TTM, PPT)
-GNL = (HNK, QQJ)
-VRK = (MRC, XPH)
-DJF = (CLD, NGD)
-KVP = (DJD, FXM)
-JHQ = (VNS, RPZ)
-FSK = (XCM, VXL)
-HCJ = (PJB, PBF)
-FJC = (TTJ, XHB)
-JVR = (VRH, RBM)
-NVL = (CQD, PJL)
-QNL = (GXD, DHK)
-NHK = (RFB, MXR)
-VQP = (BBG, PXD)
-SPV = (LMN, DTT)
-KJR = (PFJ, HPH)
-VGH = (PGC, NGH)
-SQQ = (DQV, BLM)
-DTQ = (SMB, CNC)
-RVB = (JRQ, JRQ)
-KKF = (SGL, NNN)
-MTP = (MHN, JGN)
-PNX = (PMS, XHV)
-CTR = (XBB, VRB)
-DFX = (CHF, VMH)
-QQM = (SRG, PSR)
-GLQ = (FHJ, TQD)
-RLJ = (LNR, NRS)
-JLJ = (DRR, MCV)
-PRJ = (XHF, SMK)
-XKQ = (HCJ, SHG)
-HJL = (BBG, PXD)
-MGC = (RLV, DPR)
-GLX = (RHC, KKH)
-AAA = (QRH, FRS)
-LSB = (XXB, XKQ)
-VNQ = (NVC, XTP)
-FJR = (HGD, SBH)
-MHX = (LRH, QGL)
-LMN = (STQ, STQ)
-FPT = (HQH, QQM)
-VGB = (MRC, XPH)
-VKB = (JXN, SKV)
-VHJ = (PRT, DTQ)
-QPC = (JLJ, XXR)
-CJJ = (KXB, VXB)
-SHG = (PBF, PJB)
-SKV = (RHQ, VCC)
-FMH = (LTT, HNX)
-SKQ = (SKX, FFM)
-LRG = (VHN, VHN)
-GCL = (RBQ, MKB)
-XVJ = (PQQ, FNC)
-HPV = (XHF, SMK)
-MCX = (GDX, JMV)
-NNN = (QPC, FJF)
-HNK = (CPS, NHK)
-VBB = (FDV, FMJ)
-TCD = (BFM, CBJ)
-TBV = (BSL, FKK)
-CPT = (LTT, HNX)
-CDL = (DCL, SMS)
-JGN = (SHK, KTG)
-CRB = (PXP, PPH)
-JPS = (DQV, BLM)
-MMS = (HJF, MGK)
-JFT = (FFQ, PFD)
-PGC = (TQP, BDN)
-RPZ = (TMV, HLN)
-FVD = (GTM, PDN)
-RTB = (FTM, QBK)
-DML = (XKK, LMP)
-QGH = (MHX, PXH)
-RHM = (TFS, TQL)
-GXB = (LXM, NPC)
-CMJ = (LXR, PHP)
-FFB = (SNS, XPX)
-MKC = (QMM, LDH)
-MCK = (TRP, VXJ)
-FNL = (QNB, JFT)
-GGQ = (JGS, DFX)
-NMR = (MQP, TNX)
-XKK = (XLK, VKB)
-QML = (LBN, FRH)
-JCH = (GQH, CLR)
-TMV = (BMV, TKL)
-FDN = (HCM, VVS)
-GPT = (CLR, GQH)
-DDC = (QGH, KVN)
-DKK = (LBN, FRH)
-RBM = (SLJ, NLJ)
-KLB = (LFV, SPT)
-GRK = (XNP, VLL)
-CLD = (CSS, GXT)
-GFS = (KRH, TRK)
-RQJ = (RQX, SQM)
-KTG = (NCX, NGJ)
-HNX = (JQB, SBJ)
-NTR = (VRB, XBB)
-HMF = (BGH, QKN)
-TPB = (LMN, DTT)
-GHX = (QBK, FTM)
-RMT = (RFK, TCK)
-MSR = (NMQ, LJC)
-PPT = (SJG, VGH)
-QMG = (VRG, SFZ)
-FKF = (LHJ, QHG)
-QRL = (LRD, RHJ)
-TFS = (GRK, JTQ)
-FMJ = (TSF, SXS)
-SBX = (GGT, VCL)
-KFG = (FCX, FTF)
-VXS = (RBG, KNP)
-VDG = (JDT, RPS)
-QHD = (RFN, ZZZ)
-SFM = (GLL, RQC)
-VXL = (DCK, FMG)
-CCD = (LMP, XKK)
-SJG = (PGC, NGH)
-FKK = (BPP, HSD)
-TXG = (FDC, JNJ)
-RKF = (PFJ, HPH)
-PMS = (HMT, XTG)
-MHN = (SHK, KTG)
-DXK = (FPT, JSJ)
-QCQ = (LRM, MCX)
-QPR = (MST, QHD)
-CKV = (VNS, VNS)
-DRJ = (VLD, NVV)
-FGT = (RHM, RPC)
-LHJ = (BQK, JHJ)
-STQ = (CKV, CKV)
-HLQ = (FXR, KKJ)
-GBQ = (BHT, HJH)
-RNH = (KNF, GGX)
-VPG = (PRR, FHK)
-CTS = (VVP, LCR)
-CPS = (MXR, RFB)
-DRR = (NFK, TKJ)
-MQG = (HJH, BHT)
-RTL = (GBQ, MQG)
-KSV = (HGD, SBH)
-TKJ = (LMH, RSS)
-FHF = (VCB, BKJ)
-CSL = (JNS, FTP)
-NMQ = (XPL, PBB)
-CNL = (VJJ, JBV)
-QQF = (GRF, DKC)
-PJB = (PPD, TBV)
-BDN = (HBD, RRD)
-VRG = (QDM, DVP)
-MGK = (NDN, QFD)
-CCG = (BQR, QBM)
-MQP = (CLF, HNQ)
-TPG = (VPG, BMF)
-VPP = (QBN, SNX)
-FXM = (KMQ, CHQ)
-CDG = (PPP, KFN)
-LVA = (QDM, DVP)
-QMM = (RFF, LNJ)
-LMP = (VKB, XLK)
-MHH = (HBN, FJC)
-JBC = (LTN, LTN)
-MGG = (VJJ, JBV)
-MPF = (LXM, NPC)
-JGV = (XBN, NNP)
-MNK = (VGL, DJB)
-FGX = (BVQ, DQK)
-XBJ = (RQC, GLL)
-KGB = (MCX, LRM)
-HXX = (MHH, TXD)
-XLK = (SKV, JXN)
-FCJ = (VPT, GQC)
-SHK = (NCX, NGJ)
-QRH = (BTG, FJP)
-LXM = (KGB, QCQ)
-DXV = (SLM, SCK)
-MRC = (CVK, CDT)
-QBN = (PKR, KQM)
-PPD = (FKK, BSL)
-PXH = (QGL, LRH)
-XXB = (SHG, HCJ)
-DCK = (DSL, DSL)
-PMG = (TXD, MHH)
-XKG = (TQD, FHJ)
-JRV = (QBM, BQR)
-VGL = (TGD, XTD)
-XCM = (DCK, FMG)
-PQN = (RPS, JDT)
-JBV = (NTR, CTR)
-TXD = (HBN, FJC)
-VJJ = (CTR, NTR)
-DCL = (SMJ, GXC)
-PCP = (RRT, PMB)
-LRD = (XHC, PBG)
-QTJ = (PCP, RXV)
-VMH = (PKK, RTL)
-DBP = (PRJ, HPV)
-CLR = (VDG, PQN)
-FLP = (KNP, RBG)
-RJT = (NDB, PTK)
-KFN = (CDL, FDM)
-DKC = (CJJ, HTB)
-XHV = (XTG, HMT)
-CFK = (GGT, VCL)
-HGX = (RDT, GKM)
-CKB = (CQP, RMT)
-KVQ = (CQD, PJL)
-PSR = (HGX, JLK)
-KTC = (JFV, KLQ)
-RMN = (SFM, XBJ)
-KMQ = (JDC, MRB)
-TXB = (FSR, MQD)
-BKS = (FLH, QFP)
-PFD = (MNK, BKL)
-BQR = (LGC, KKF)
-NPA = (FCJ, NPF)
-FRR = (MMS, TDR)
-BSL = (HSD, BPP)
-LMH = (MGG, CNL)
-TVH = (TBH, DKP)
-GXT = (JTD, DRJ)
-DFF = (TFC, KNN)
-RPS = (DBP, LXB)
-HKH = (VNQ, DPG)
-NRS = (VGR, DPS)
-FKT = (NDB, PTK)
-GGX = (SQQ, JPS)
-GDA = (LGN, DDC)
-RBG = (FFB, SSB)
-HBN = (TTJ, XHB)
-BLM = (TXG, JVD)
-VRH = (NLJ, SLJ)
-XPL = (DXK, KJL)
-VNM = (XXJ, QNL)
-MDC = (MTP, TGC)
-GQL = (KSN, VKN)
-TNX = (HNQ, CLF)
-NVV = (HVF, FGX)
-DXM = (PTN, QGM)
-XBB = (PRG, PGG)
-RLM = (XKQ, XXB)
-TGC = (JGN, MHN)
-SDM = (SLV, KVS)
-BPP = (MGC, CGB)
-QVB = (QFM, QMG)
-LJC = (PBB, XPL)
-QLG = (PPT, TTM)
-XTT = (BGH, QKN)
-HTB = (VXB, KXB)
-VCC = (MKC, XRH)
-LRM = (GDX, JMV)
-CJB = (HCM, VVS)
-TRK = (KJR, RKF)
-TFC = (CHT, LBT)
-NVC = (TCD, HTL)
-JRQ = (LGN, DDC)
-SQS = (DLQ, HFV)
-CHF = (PKK, RTL)
-PGG = (RQJ, PJM)
-PRR = (JGP, MSR)
-VHN = (QFM, QFM)
-RQC = (GNJ, KTC)
-MRB = (GFS, XFF)
-XBN = (XJN, DXC)
-SXS = (BBQ, XFV)
-VCB = (LPK, FSK)
-FTM = (SPV, TPB)
-PJL = (SPJ, VHJ)
-BKL = (DJB, VGL)
-GRR = (DJD, FXM)
-ZZZ = (FRS, QRH)
-KDK = (PHP, LXR)
-SNX = (KQM, PKR)
-VMS = (PXP, PPH)
-FSH = (RJT, FKT)
-JDT = (DBP, LXB)
-KVN = (PXH, MHX)
-DHK = (DLT, PNX)
-JSG = (FDV, FMJ)
-GQT = (NRN, GLV)
-NBT = (PPP, KFN)
-PMX = (KVT, RMN)
-SBH = (HKH, BHN)
-MNG = (FKT, RJT)
-FNC = (JSV, HKZ)
-KXF = (MKB, RBQ)
-DTT = (STQ, VFM)
-PKT = (VXJ, TRP)
-XXR = (DRR, MCV)
-VKN = (GRR, KVP)
-FJF = (XXR, JLJ)
-SMB = (RTB, GHX)
-RHJ = (PBG, XHC)
-CDR = (RJK, GGH)
-RBQ = (PMG, HXX)
-XCT = (NTC, GQL)
-FMR = (JLQ, QRL)
-SNS = (XKG, GLQ)
-FSR = (NCR, LRB)
-PKK = (GBQ, MQG)
-VXJ = (FDS, TSJ)
-QTQ = (BLX, FKF)
-KSN = (GRR, KVP)
-FCX = (RLJ, TQN)
-VNV = (VQV, MTH)
-HVF = (BVQ, DQK)
-CHQ = (JDC, MRB)
-MKB = (HXX, PMG)
-FRH = (JRV, CCG)
-CQC = (QML, DKK)
-SPJ = (DTQ, PRT)
-HXV = (CQP, RMT)
-CBJ = (VMX, VQS)
-FHG = (FSR, MQD)
-GFF = (KNN, TFC)
-XVF = (RHC, KKH)
-QRS = (RNL, FGT)
-XFV = (CFK, SBX)
-HPH = (SXL, CXD)
-FHK = (JGP, MSR)
-HQH = (SRG, PSR)
-LTT = (SBJ, JQB)
-GGT = (NFB, GSS)
-PRM = (VRR, MDC)
-HMT = (CPT, FMH)
-DSL = (MST, MST)
-TDM = (QML, DKK)
-LHX = (FRR, GSC)
-LHG = (DKC, GRF)
-PNH = (MCH, SLT)
-VFH = (HMF, XTT)
-VCL = (GSS, NFB)
-PPH = (VFH, DLR)
-FHJ = (DML, CCD)
-LFQ = (LSB, RLM)
-RFV = (LRK, NFD)
-JSV = (FCJ, NPF)
-DVP = (GDC, RNH)
-SXL = (VQP, HJL)
-NPC = (QCQ, KGB)
-JVC = (FGQ, GGJ)
-RQX = (TFQ, BCL)
-TFQ = (TPT, KLB)
-NPL = (DXN, CMZ)
-GKM = (MPQ, BTX)
-HSS = (KVT, RMN)
-JDF = (HXJ, QLG)
-XFF = (TRK, KRH)
-DBR = (JVR, VVX)
-PJM = (SQM, RQX)
-MRF = (JFC, SQS)
-BQK = (FMR, LVD)
-PHP = (VXS, FLP)
-LTN = (DXN, DXN)
-PPP = (CDL, FDM)
-TBH = (SCB, VCK)
-CGB = (RLV, DPR)
-TDR = (HJF, MGK)
-LFV = (PVB, FHF)
-NDL = (FLH, QFP)
-KHR = (RVB, RVB)
-GRM = (FXR, KKJ)
-RFF = (FNL, HMG)
-JLK = (GKM, RDT)
-TQD = (CCD, DML)
-FDV = (SXS, TSF)
-XTP = (TCD, HTL)
-PCC = (GFF, DFF)
-LDG = (QQF, LHG)
-HLN = (TKL, BMV)
-DPG = (XTP, NVC)
-MNH = (GLX, XVF)
-SCD = (BMF, VPG)
-GSS = (MNH, NFX)
-NXR = (MRF, KND)
-PVB = (VCB, BKJ)
-XHF = (QTJ, CKC)
-NRN = (MFS, SDM)
-RLV = (HLQ, GRM)
-DLF = (CMD, FCF)
-VQV = (BPK, PCC)
-BHT = (GBF, DRL)
-HQD = (NVT, DLF)
-CHT = (SLH, JGK)
-NFF = (VRR, MDC)
-KVT = (XBJ, SFM)
-DLQ = (LHX, XRD)
-NCR = (FSH, MNG)
-VDD = (PQQ, PQQ)
-JVD = (FDC, JNJ)
-VQS = (KVQ, NVL)
-NGJ = (XJT, CTJ)
-BGT = (RRF, XPC)
-QDD = (RVB, BCF)
-DPS = (CDR, GJL)
-JFV = (JGV, SNL)
-SLM = (QTQ, GMV)
-SBS = (TDM, CQC)
-BHN = (DPG, VNQ)
-FLH = (LRG, XQF)
-SBJ = (LTB, SKQ)
-FXR = (LFM, BGQ)
-NGH = (TQP, BDN)
-KQM = (SBS, RSQ)
-KPB = (SLM, SCK)
-HCM = (VFF, DJF)
-LXB = (HPV, PRJ)
-STZ = (DDC, LGN)
-HMG = (QNB, JFT)
-FMG = (DSL, QPR)
-XPX = (XKG, GLQ)
-CBD = (QVQ, LQQ)
-LRH = (VHD, CBD)
-SMS = (SMJ, GXC)
-QQJ = (NHK, CPS)
-XQQ = (QNL, XXJ)
-LTB = (FFM, SKX)
-JLQ = (RHJ, LRD)
-TQP = (HBD, RRD)
-RFN = (QRH, FRS)
-HJH = (GBF, DRL)
-RNL = (RHM, RPC)
-LVD = (JLQ, QRL)
-TQL = (JTQ, GRK)
-MPQ = (NFF, PRM)
-BKJ = (FSK, LPK)
-FTF = (TQN, RLJ)
-LDH = (LNJ, RFF)
-VFF = (CLD, NGD)
-TQN = (LNR, NRS)
-GXV = (MCK, PKT)
-DXN = (KPB, DXV)
-TCK = (CRB, VMS)
-HNQ = (VNG, DHL)
-JNS = (KHR, KHR)
-BBG = (BDS, VNV)
-JXN = (RHQ, VCC)
-RCA = (KPB, DXV)
-BGQ = (HCK, GGQ)
-BCF = (JRQ, STZ)
-GDC = (GGX, KNF)
-DXC = (TPG, SCD)
-DLR = (XTT, HMF)
-PTK = (CMJ, KDK)
-SKP = (TXB, FHG)
-PFJ = (CXD, SXL)
-KVS = (CFB, BGT)
-PRT = (SMB, CNC)
-MCV = (TKJ, NFK)
-FGQ = (GXB, MPF)
-SLH = (FJR, KSV)
-MQD = (LRB, NCR)
-VRR = (TGC, MTP)
-TPT = (SPT, LFV)
-DTK = (HXJ, QLG)
-CVK = (QSM, TVH)
-RRF = (KKN, KFG)
-FCF = (FMV, NGL)
-DJB = (TGD, XTD)
-FTP = (KHR, QDD)
-CSS = (DRJ, JTD)
-QGM = (MFX, GXV)
-CXD = (HJL, VQP)
-TRP = (FDS, TSJ)
-PQQ = (JSV, JSV)
-RHQ = (MKC, XRH)
-FFQ = (BKL, MNK)
-JGP = (NMQ, LJC)
-LGC = (SGL, NNN)
-LQQ = (LDG, JNX)
-SBV = (JNS, FTP)
-XHB = (RMQ, XHJ)
-CQD = (VHJ, SPJ)
-RDT = (MPQ, BTX)
-LHL = (LTN, NPL)
-LRK = (CKB, HXV)
-MCH = (HSS, PMX)
-QFD = (BRH, PTF)
-PKR = (RSQ, SBS)
-JNX = (LHG, QQF)
-KLX = (PTN, QGM)
-MDJ = (LSB, RLM)
-TGD = (NXR, DGD)
-MST = (RFN, RFN)
-BCL = (KLB, TPT)
-VPT = (VRK, VGB)
-JMV = (DTK, JDF)
-PJX = (NDL, BKS)
-GGJ = (GXB, MPF)
-NNP = (XJN, DXC)
-PMB = (PJX, LTG)
-QFM = (VRG, VRG)
-BTX = (PRM, NFF)
-DKP = (SCB, VCK)
-BMF = (FHK, PRR)
-JTQ = (VLL, XNP)
-SRG = (JLK, HGX)
-RMQ = (RFV, BRS)
-JGK = (FJR, KSV)
-KNN = (CHT, LBT)
-JQB = (SKQ, LTB)
-DHL = (GPT, JCH)
-NTC = (KSN, VKN)
-QRV = (LHP, CTS)
-VFM = (CKV, JHQ)
-LBT = (SLH, JGK)
-GXC = (PNH, MML)
-HFV = (LHX, XRD)
-TRT = (TNX, MQP)
-FDM = (DCL, SMS)
-KXB = (SHF, QRS)
-VSJ = (NVT, DLF)
-KJL = (JSJ, FPT)
-LTG = (BKS, NDL)
-SQM = (TFQ, BCL)
-PTF = (KCP, QMQ)
-LGN = (KVN, QGH)
-PBG = (JKS, JVC)
-DQK = (XFG, XCP)
-XFG = (MXD, FVD)
-PRG = (RQJ, PJM)
-FRS = (BTG, FJP)
-XNP = (VNM, XQQ)
-XRD = (GSC, FRR)
-RSQ = (TDM, CQC)
-MFS = (KVS, SLV)
-SPT = (PVB, FHF)
-DRL = (VPP, VGV)
-VVP = (NMR, TRT)
-XTG = (CPT, FMH)
-SCK = (GMV, QTQ)
-VCK = (VBB, JSG)
-DQV = (TXG, JVD)
-XPC = (KKN, KFG)
-KXJ = (FHG, TXB)
-MSJ = (QQJ, HNK)
-VGR = (GJL, CDR)
-QDM = (RNH, GDC)
-NGD = (GXT, CSS)
-FDS = (HQD, VSJ)
-NFB = (NFX, MNH)
-CNC = (GHX, RTB)
-BDS = (VQV, MTH)
-DGD = (KND, MRF)
-CTJ = (VDD, XVJ)
-JSJ = (QQM, HQH)
-GXD = (PNX, DLT)
-TSF = (XFV, BBQ)
-GLL = (GNJ, KTC)
-CMZ = (DXV, KPB)
-SRC = (QXD, GQT)
-FMV = (JBC, JBC)
-NPF = (GQC, VPT)
-HBD = (GNL, MSJ)
-CDT = (TVH, QSM)
-JNJ = (SKP, KXJ)
-NGL = (JBC, LHL)
-SFZ = (DVP, QDM)
-GTM = (DXM, KLX)
-GSC = (MMS, TDR)
-XJT = (VDD, VDD)
-FDC = (SKP, KXJ)
-BVQ = (XCP, XFG)
-GGH = (CSL, SBV)
-BMV = (XCT, KCR)
-QSM = (DKP, TBH)
-TKL = (KCR, XCT)
-CLF = (DHL, VNG)
-PBF = (PPD, TBV)
-GBF = (VGV, VPP)
-NCX = (XJT, CTJ)
-DMG = (LHP, CTS)
-MXD = (PDN, GTM)
-QBM = (KKF, LGC)
-SLV = (BGT, CFB)
-BRH = (QMQ, KCP)
-XHJ = (BRS, RFV)
-MXR = (FDN, CJB)
-RJK = (CSL, SBV)
-GDX = (DTK, JDF)
-VVX = (VRH, RBM)
-BGH = (MDJ, LFQ)
-HKZ = (NPF, FCJ)
-NLJ = (CDG, NBT)
-BTG = (KXF, GCL)
-QBK = (SPV, TPB)
-SHF = (FGT, RNL)
-XQF = (VHN, QVB)
-JKS = (GGJ, FGQ)
-GRF = (HTB, CJJ)
-LNR = (VGR, DPS)
-XTD = (DGD, NXR)
-GQC = (VGB, VRK)
-KCP = (DMG, QRV)
-XCP = (MXD, FVD)
-XXJ = (DHK, GXD)
-VVS = (DJF, VFF)
-QHG = (BQK, JHJ)
-SLT = (PMX, HSS)
-PDN = (KLX, DXM)
-DLT = (XHV, PMS)
-KKN = (FTF, FCX)
-BLX = (QHG, LHJ)
-GQH = (VDG, PQN)
-VLD = (FGX, HVF)
-KKX = (GQT, QXD)
-KKJ = (BGQ, LFM)
-LNJ = (FNL, HMG)";
 }
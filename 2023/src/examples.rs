@@ -0,0 +1,613 @@
+//! Tests of the examples in the puzzle statements, generated by
+//! `cargo run -p aoc -- examples`. Do not edit, run that again instead.
+
+use common::{Answer, Solution};
+
+#[test]
+fn day_1_part_1() {
+    let input = r"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+    assert_eq!(
+        Answer::from(crate::day_1::Solver::solve_1(input)).to_string(),
+        "142"
+    );
+}
+
+#[test]
+fn day_1_part_2() {
+    let input = r"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+    assert_eq!(
+        Answer::from(crate::day_1::Solver::solve_2(input)).to_string(),
+        "281"
+    );
+}
+
+#[test]
+fn day_2_part_1() {
+    let input = r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+    assert_eq!(
+        Answer::from(crate::day_2::Solver::solve_1(input)).to_string(),
+        "8"
+    );
+}
+
+#[test]
+fn day_2_part_2() {
+    let input = r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+    assert_eq!(
+        Answer::from(crate::day_2::Solver::solve_2(input)).to_string(),
+        "2286"
+    );
+}
+
+#[test]
+fn day_3_part_1() {
+    let input = r"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    assert_eq!(
+        Answer::from(crate::day_3::Solver::solve_1(input)).to_string(),
+        "4361"
+    );
+}
+
+#[test]
+fn day_3_part_2() {
+    let input = r"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    assert_eq!(
+        Answer::from(crate::day_3::Solver::solve_2(input)).to_string(),
+        "467835"
+    );
+}
+
+#[test]
+fn day_4_part_1() {
+    let input = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    assert_eq!(
+        Answer::from(crate::day_4::Solver::solve_1(input)).to_string(),
+        "13"
+    );
+}
+
+#[test]
+fn day_4_part_2() {
+    let input = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    assert_eq!(
+        Answer::from(crate::day_4::Solver::solve_2(input)).to_string(),
+        "30"
+    );
+}
+
+#[test]
+fn day_5_part_1() {
+    let input = r"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+    assert_eq!(
+        Answer::from(crate::day_5::Solver::solve_1(input)).to_string(),
+        "35"
+    );
+}
+
+#[test]
+fn day_5_part_2() {
+    let input = r"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+    assert_eq!(
+        Answer::from(crate::day_5::Solver::solve_2(input)).to_string(),
+        "46"
+    );
+}
+
+#[test]
+fn day_6_part_1() {
+    let input = r"Time:      7  15   30
+Distance:  9  40  200";
+    assert_eq!(
+        Answer::from(crate::day_6::Solver::solve_1(input)).to_string(),
+        "288"
+    );
+}
+
+#[test]
+fn day_6_part_2() {
+    let input = r"Time:      7  15   30
+Distance:  9  40  200";
+    assert_eq!(
+        Answer::from(crate::day_6::Solver::solve_2(input)).to_string(),
+        "71503"
+    );
+}
+
+#[test]
+fn day_7_part_1() {
+    let input = r"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+    assert_eq!(
+        Answer::from(crate::day_7::Solver::solve_1(input)).to_string(),
+        "6440"
+    );
+}
+
+#[test]
+fn day_7_part_2() {
+    let input = r"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+    assert_eq!(
+        Answer::from(crate::day_7::Solver::solve_2(input)).to_string(),
+        "5905"
+    );
+}
+
+#[test]
+fn day_8_part_1() {
+    let input = r"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+    assert_eq!(
+        Answer::from(crate::day_8::Solver::solve_1(input)).to_string(),
+        "2"
+    );
+}
+
+#[test]
+fn day_8_part_2() {
+    let input = r"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+    assert_eq!(
+        Answer::from(crate::day_8::Solver::solve_2(input)).to_string(),
+        "6"
+    );
+}
+
+#[test]
+fn day_9_part_1() {
+    let input = r"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+    assert_eq!(
+        Answer::from(crate::day_9::Solver::solve_1(input)).to_string(),
+        "114"
+    );
+}
+
+#[test]
+fn day_9_part_2() {
+    let input = r"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+    assert_eq!(
+        Answer::from(crate::day_9::Solver::solve_2(input)).to_string(),
+        "2"
+    );
+}
+
+#[test]
+fn day_10_part_1() {
+    let input = r".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+    assert_eq!(
+        Answer::from(crate::day_10::Solver::solve_1(input)).to_string(),
+        "4"
+    );
+}
+
+#[test]
+fn day_10_part_2() {
+    let input = r"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+    assert_eq!(
+        Answer::from(crate::day_10::Solver::solve_2(input)).to_string(),
+        "4"
+    );
+}
+
+#[test]
+fn day_11_part_1() {
+    let input = r"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+    assert_eq!(
+        Answer::from(crate::day_11::Solver::solve_1(input)).to_string(),
+        "374"
+    );
+}
+
+#[test]
+fn day_13_part_1() {
+    let input = r"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+    assert_eq!(
+        Answer::from(crate::day_13::Solver::solve_1(input)).to_string(),
+        "405"
+    );
+}
+
+#[test]
+fn day_13_part_2() {
+    let input = r"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+    assert_eq!(
+        Answer::from(crate::day_13::Solver::solve_2(input)).to_string(),
+        "400"
+    );
+}
+
+#[test]
+fn day_14_part_1() {
+    let input = r"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    assert_eq!(
+        Answer::from(crate::day_14::Solver::solve_1(input)).to_string(),
+        "136"
+    );
+}
+
+#[test]
+fn day_15_part_1() {
+    let input = r"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    assert_eq!(
+        Answer::from(crate::day_15::Solver::solve_1(input)).to_string(),
+        "1320"
+    );
+}
+
+#[test]
+fn day_15_part_2() {
+    let input = r"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    assert_eq!(
+        Answer::from(crate::day_15::Solver::solve_2(input)).to_string(),
+        "145"
+    );
+}
+
+#[test]
+fn day_16_part_1() {
+    let input = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+    assert_eq!(
+        Answer::from(crate::day_16::Solver::solve_1(input)).to_string(),
+        "46"
+    );
+}
+
+#[test]
+fn day_16_part_2() {
+    let input = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+    assert_eq!(
+        Answer::from(crate::day_16::Solver::solve_2(input)).to_string(),
+        "51"
+    );
+}
+
+#[test]
+fn day_17_part_1() {
+    let input = r"2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+    assert_eq!(
+        Answer::from(crate::day_17::Solver::solve_1(input)).to_string(),
+        "102"
+    );
+}
+
+#[test]
+fn day_17_part_2() {
+    let input = r"111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+    assert_eq!(
+        Answer::from(crate::day_17::Solver::solve_2(input)).to_string(),
+        "71"
+    );
+}
+
+#[test]
+fn day_18_part_1() {
+    let input = r"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+    assert_eq!(
+        Answer::from(crate::day_18::Solver::solve_1(input)).to_string(),
+        "62"
+    );
+}
+
+#[test]
+fn day_18_part_2() {
+    let input = r"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+    assert_eq!(
+        Answer::from(crate::day_18::Solver::solve_2(input)).to_string(),
+        "952408144115"
+    );
+}
+
+#[test]
+fn day_19_part_1() {
+    let input = r"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+    assert_eq!(
+        Answer::from(crate::day_19::Solver::solve_1(input)).to_string(),
+        "19114"
+    );
+}
+
+#[test]
+fn day_19_part_2() {
+    let input = r"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+    assert_eq!(
+        Answer::from(crate::day_19::Solver::solve_2(input)).to_string(),
+        "167409079868000"
+    );
+}
+
+#[test]
+fn day_20_part_1() {
+    let input = r"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+    assert_eq!(
+        Answer::from(crate::day_20::Solver::solve_1(input)).to_string(),
+        "11687500"
+    );
+}
@@ -1,21 +1,55 @@
-mod day_1;
-mod day_10;
-mod day_2;
-mod day_3;
-mod day_4;
-mod day_5;
-mod day_6;
-mod day_7;
-mod day_8;
-mod day_9;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-mod day_21;
+use common::{Day, Registry};
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+
+#[cfg(test)]
+mod examples;
+
+/// Every day of 2023, for tooling that runs days by number.
+pub const REGISTRY: Registry = Registry {
+    year: 2023,
+    days: &[
+        Day::new::<day_1::Solver>(1),
+        Day::new::<day_2::Solver>(2),
+        Day::new::<day_3::Solver>(3),
+        Day::new::<day_4::Solver>(4),
+        Day::new::<day_5::Solver>(5),
+        Day::new::<day_6::Solver>(6),
+        Day::new::<day_7::Solver>(7),
+        Day::new::<day_8::Solver>(8),
+        Day::new::<day_9::Solver>(9),
+        Day::new::<day_10::Solver>(10),
+        Day::new::<day_11::Solver>(11),
+        Day::new::<day_12::Solver>(12),
+        Day::new::<day_13::Solver>(13),
+        Day::new::<day_14::Solver>(14),
+        Day::new::<day_15::Solver>(15),
+        Day::new::<day_16::Solver>(16),
+        Day::new::<day_17::Solver>(17),
+        Day::new::<day_18::Solver>(18),
+        Day::new::<day_19::Solver>(19),
+        Day::new::<day_20::Solver>(20),
+        Day::new::<day_21::Solver>(21),
+    ],
+};
@@ -1,62 +1,64 @@
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
+use common::{ParseError, Solution, random::Rng};
+use grid::{Direction, Grid};
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Square {
+    Empty,
+    Obstacle,
+    Guard(Direction),
+}
+
+/// Position one step from `pos` towards `dir`, which must be inside the map.
+fn step(grid: &Grid<Square>, pos: usize, dir: Direction) -> usize {
+    grid.step(pos, dir.offset())
+        .expect("Stepped outside the map")
+}
 
-    #[derive(Copy, Clone, Eq, PartialEq)]
-    enum Square {
-        Empty,
-        Obstacle,
-        Up,
-        Down,
-        Left,
-        Right,
+fn propagate_backwards(grid: &Grid<Square>, maybe_obstacle: &mut [bool], mut pos: usize) {
+    use Square::*;
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let Guard(dir) = grid[pos] else {
+        panic!("Unexpected")
+    };
+    loop {
+        let (x, y) = (pos % cols, pos / cols);
+        if x == 0 || y == 0 || x + 1 == cols || y + 1 == rows || matches!(grid[pos], Obstacle) {
+            // Reached the boundary.
+            break;
+        }
+        let obpos = step(grid, pos, dir.turn_left());
+        maybe_obstacle[obpos] = matches!(grid[obpos], Empty);
+        pos = step(grid, pos, dir.reverse());
     }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    /// The map, with the guard marked by the direction it is facing.
+    type Parsed<'a> = Grid<Square>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    fn parse(input: &str) -> (Vec<Square>, usize, usize) {
+    fn parse(input: &str) -> Result<Grid<Square>, ParseError> {
         use Square::*;
-        let (grid, rows, cols) = {
-            input.trim().lines().fold(
-                (Vec::with_capacity(input.len()), 0usize, None),
-                |(mut grid, rows, cols), line| {
-                    let line = line.trim();
-                    grid.extend(line.chars().map(|c| match c {
-                        '#' => Obstacle,
-                        '^' => Up,
-                        '>' => Right,
-                        'v' => Down,
-                        '<' => Left,
-                        _ => Empty,
-                    }));
-                    (
-                        grid,
-                        rows + 1,
-                        match cols {
-                            Some(prev) => {
-                                let curr = line.len();
-                                assert_eq!(prev, curr);
-                                Some(curr)
-                            }
-                            None => Some(line.len()),
-                        },
-                    )
-                },
-            )
-        };
-        let cols = cols.expect("Cannot count columns in the map grid");
-        (grid, rows, cols)
+        let grid = Grid::parse(input, "'#', '.' or a guard", |c| match c {
+            '#' => Some(Obstacle),
+            '.' => Some(Empty),
+            _ => Direction::from_arrow(c).map(Guard),
+        })?;
+        match grid.position(|s| matches!(s, Guard(_))) {
+            Some(_) => Ok(grid),
+            None => Err(ParseError::after(input, "a guard")),
+        }
     }
 
-    fn part_1(input: &str) -> usize {
+    fn part_1(grid: &Grid<Square>) -> usize {
         use Square::*;
-        let (mut grid, rows, cols) = parse(input);
-        assert_eq!(rows * cols, grid.len());
+        let (mut grid, rows, cols) = (grid.clone(), grid.rows(), grid.cols());
         let mut visited = vec![false; grid.len()];
         let mut pos = grid
-            .iter()
-            .position(|s| match s {
-                Empty | Obstacle => false,
-                Up | Down | Left | Right => true,
-            })
+            .position(|s| matches!(s, Guard(_)))
             .expect("Cannot find the initial position");
         loop {
             visited[pos] = true;
@@ -65,125 +67,103 @@ mod test {
                 // Reached the boundary.
                 break visited.iter().filter(|&&v| v).count();
             }
-            let dir = std::mem::replace(&mut grid[pos], Empty);
-            let next = match dir {
-                Empty | Obstacle => panic!("Unexpected"),
-                Up => pos - cols,
-                Down => pos + cols,
-                Left => pos - 1,
-                Right => pos + 1,
+            let Guard(dir) = std::mem::replace(&mut grid[pos], Empty) else {
+                panic!("Unexpected")
             };
+            let next = step(&grid, pos, dir);
             match grid[next] {
                 Empty => {
-                    grid[next] = dir;
+                    grid[next] = Guard(dir);
                     pos = next;
                 }
-                Obstacle => {
-                    grid[pos] = match dir {
-                        Empty | Obstacle => panic!("Unexpected"),
-                        Up => Right,
-                        Down => Left,
-                        Left => Up,
-                        Right => Down,
-                    }
-                }
-                Up | Down | Left | Right => panic!("Unexpected"),
-            }
-        }
-    }
-
-    fn propagate_backwards(
-        grid: &[Square],
-        maybe_obstacle: &mut [bool],
-        rows: usize,
-        cols: usize,
-        mut pos: usize,
-    ) {
-        use Square::*;
-        let dir = grid[pos];
-        loop {
-            let (x, y) = (pos % cols, pos / cols);
-            if x == 0 || y == 0 || x + 1 == cols || y + 1 == rows || matches!(grid[pos], Obstacle) {
-                // Reached the boundary.
-                break;
+                Obstacle => grid[pos] = Guard(dir.turn_right()),
+                Guard(_) => panic!("Unexpected"),
             }
-            let (obpos, next) = match dir {
-                Empty | Obstacle => panic!("Unexpected"),
-                Up => (pos - 1, pos + cols),
-                Down => (pos + 1, pos - cols),
-                Left => (pos + cols, pos + 1),
-                Right => (pos - cols, pos - 1),
-            };
-            maybe_obstacle[obpos] = match grid[obpos] {
-                Empty => true,
-                _ => false,
-            };
-            pos = next;
         }
     }
 
-    fn part_2(input: &str) -> usize {
+    fn part_2(grid: &Grid<Square>) -> usize {
         use Square::*;
-        let (mut grid, rows, cols) = parse(input);
-        assert_eq!(rows * cols, grid.len());
+        let (mut grid, rows, cols) = (grid.clone(), grid.rows(), grid.cols());
         let mut maybe_obstacles = vec![false; grid.len()];
         let mut obstacles = vec![false; grid.len()];
         let mut pos = grid
-            .iter()
-            .position(|s| match s {
-                Empty | Obstacle => false,
-                Up | Down | Left | Right => true,
-            })
+            .position(|s| matches!(s, Guard(_)))
             .expect("Cannot find the initial position");
-        propagate_backwards(&grid, &mut maybe_obstacles, rows, cols, pos);
+        propagate_backwards(&grid, &mut maybe_obstacles, pos);
         loop {
             let (x, y) = (pos % cols, pos / cols);
             if x == 0 || y == 0 || x + 1 == cols || y + 1 == rows {
                 // Reached the boundary.
                 break;
             }
-            let dir = std::mem::replace(&mut grid[pos], Empty);
-            let next = match dir {
-                Empty | Obstacle => panic!("Unexpected"),
-                Up => pos - cols,
-                Down => pos + cols,
-                Left => pos - 1,
-                Right => pos + 1,
+            let Guard(dir) = std::mem::replace(&mut grid[pos], Empty) else {
+                panic!("Unexpected")
             };
+            let next = step(&grid, pos, dir);
             match grid[next] {
                 Empty => {
-                    grid[next] = dir;
+                    grid[next] = Guard(dir);
                     pos = next;
                     if maybe_obstacles[next] {
                         obstacles[next] = true;
                     }
                 }
                 Obstacle => {
-                    grid[pos] = match dir {
-                        Empty | Obstacle => panic!("Unexpected"),
-                        Up => Right,
-                        Down => Left,
-                        Left => Up,
-                        Right => Down,
-                    };
-                    propagate_backwards(&grid, &mut maybe_obstacles, rows, cols, pos);
+                    grid[pos] = Guard(dir.turn_right());
+                    propagate_backwards(&grid, &mut maybe_obstacles, pos);
                 }
-                Up | Down | Left | Right => panic!("Unexpected"),
+                Guard(_) => panic!("Unexpected"),
             }
         }
         obstacles.iter().filter(|&&ob| ob).count()
     }
 
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        loop {
+            let mut map: Grid<char> = Grid::new(side, side, '.');
+            for cell in map.cells_mut() {
+                if rng.chance(0.1) {
+                    *cell = '#';
+                }
+            }
+            let start = rng.range(0..map.len());
+            map[start] = '^';
+            // The guard must walk off the map rather than go around in a
+            // loop forever.
+            let mut seen = vec![[false; 4]; map.len()];
+            let (mut pos, mut dir) = (start, Direction::North);
+            while !seen[pos][dir.index()] {
+                seen[pos][dir.index()] = true;
+                match map.step(pos, dir.offset()) {
+                    None => return map.to_string(),
+                    Some(next) if map[next] == '#' => dir = dir.turn_right(),
+                    Some(next) => pos = next,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
     #[test]
     fn t_part_1() {
-        assert_eq!(part_1(EXAMPLE), 41);
-        assert_eq!(part_1(INPUT), 4758);
+        assert_eq!(Solver::solve_1(EXAMPLE), 41);
+        if let Some(input) = common::input::for_test(2024, 6) {
+            assert_eq!(Solver::solve_1(&input), 4758);
+        }
     }
 
     #[test]
     fn t_part_2() {
-        assert_eq!(part_2(EXAMPLE), 6);
-        assert_eq!(part_2(INPUT), 298);
+        assert_eq!(Solver::solve_2(EXAMPLE), 6);
+        if let Some(input) = common::input::for_test(2024, 6) {
+            assert_eq!(Solver::solve_2(&input), 298);
+        }
     }
 
     const EXAMPLE: &str = "
@@ -197,137 +177,4 @@ mod test {
 ........#.
 #.........
 ......#...";
-
-    const INPUT: &str = "
-#.....#.........#.........................#...#....................#......#.......#...........#........#..........................
-..................#......#..................................#..................#....#...............#.#....................#.....#
-..............#...........#......#.#.......................................#..................#......#............................
-##...............................#..........#...................#.......#................................................#........
-#.....................#.......##................#...#..........#............................................#...#....#............
-........#........#.............#...............#...##...................#...........#...........#.......#...........##.#...#.#....
-................................................#....................................................#.#..........................
-.........#....#.....................................................................##......#.....#........#...............#......
-.............................#.....#.........#......................#...#................#.#..........#...........................
-........................................#.........................................................................#...............
-.........................................#.......#.............#.#.........................#....#.....#...........#...............
-...#............##...................................#...........#........................................#..................#....
-....#.................#.......#............#.....#...........#................................#.#.#...........................#...
-...#................................................................#.......................#.#........................#..#...#...
-............................#......#.......#...............................................................................#....#.
-.......................................................#.............................#...#.......#.............#............#.....
-.....................................................................#......................................#.................##..
-......#.................#......................................................#................#.................................
-...............................................................................#...............#............................#.....
-........................................................#......................##..................#........#.....................
-.#.................................#....#........#..#........#.................................#...............#..........#.#.....
-.................#................#...................#.#.....#................#..................#.........#.....................
-...............#........................................................................#............#..........#.................
-....................#..................#...........#................................#.....................#...#......#............
-.............#..#..................................#..............................#...............................#...#......#...#
-..................#.............................................................#...............................#......#..........
-................................................................#....#..............#.............................#...............
-.............#............#............................#...#........#...#.........................##..............................
-....................................#....#.............#...#....#..........................#..................#.#............#....
-.......................#........................................................................................#.................
-........#..................................#..#.........#.......................................#......#..............#...........
-..........#............#............#..................#.......#..........#...................................#...........#.......
-....#..............................#.....#...........##...#.....................#...........................#..........#..........
-................................#..#.................................................................#...#...................#....
-..#....................#..........................................................................#...............................
-..................#........................#.......#..#......................................................................#....
-.#.....#......................#.....................................#.........................................#...................
-..#....#...........................................#..........................................................................#...
-..#.....#........................................................................#........#.#.............................#.......
-................#......................................#...............#....................................#..........#..........
-....................................#........................##.............................#..........................#........#.
-.#..............#..............................#......................................................#...........................
-...#.#......................#.................#..................................#...........................#....................
-..#....#..........................................#...#.......#..............................................#....................
-.......#.................#............................#..................................................#..#....................#
-.....##.#...........#...........................................#.....................................................#...........
-................#.#........................................................................#..........#..#....#.##................
-..........#........#............................................#.#......................................#.............#..........
-............................................................#..........................................#..........................
-.......#........#.................#....................................................................#..........................
-........................................................#..........................#..............#...............................
-.............#.#....................................#.................##............#.#.............................#..........#..
-.##.............................................^........#...................#.....#...................................#..........
-...............#..#..#................................................................#...........................#...............
-.#.......##...........#.........#..................#......#..........#...#....#...................#...............................
-....#................................................................#...................#........................................
-.........#............#..#......................................................#..#..............................................
-...#.............................................................#......#....#....................................#...#....#......
-.....#.........#.................#....................#..................#..#.#.............#......#.....................#........
-...........#.......#.##.#......#..#.............................#...........#..............................................#......
-................................................................................................#.#......#......................#.
-##..................................##....#.................#...............##....................................................
-.....#.............................#.............................................#................#.....................#.........
-............#..............................#.....................#...............#..#....#....#...................................
-.........#.........................#...#................................................#........................................#
-.#..........................................#.......#.....................................................#..............#...#....
-#.........#...........#.............#..........................#........................................#.........................
-.......................................................................#.............................................#............
-....#..............#..#......................................................#..........................................#....#....
-........#...................................#...#................#.........................................................#...#..
-.........................................................#..#..#..#..........#...........#...........#............#...............
-..............................................##......#.................#..............................................#..........
-.............................#..........#......#.....#....#.............#..............................................#...#..#...
-......................................##......#.....................................#.......#....................#................
-...................................................................................................#..............................
-.................................#..................................................................................#.............
-........#...................................................................#...................##............#...................
-...............................#..................................................................................................
-...............................#...........................................................#...............##..............#......
-........................................................................#..........#..................................#...........
-................#.................................................................................................................
-...#.................#..........#...........#...................................#......................#..........................
-.......................#..........................................................#.............#.............#..........#........
-..........#..........#.#.................................#........#.............#..#..........................#........#.........#
-.................#.........#.#........................#...#.............................................................#......#..
-......#.....................................................................#.....#...............#...............#....#..........
-......#...##....#..#.......................................##......#......#....#..............#............#....................#.
-.#..........#........#...#........##..............#....#...........................#.....#................#...................#...
-.........................#........................................................................................................
-..........................#.......................#.........#................................................#.#...........#......
-......#...#.....#..........#....................#..................................#...#.................................#........
-.......................................................#............................#.....................#.#.................#...
-..............#...............#......................#......#...................#.....................#.........#................#
-....#.........#...............................................................#.......#.......#...........#.................#.....
-.......................#.........................#....................................#...........................................
-...............#.................#..........#........................#...##.......................................................
-....##..................#..#..................................................................................#.................#.
-..................#.............#......................#...............#................#.#.......................................
-........................#....#................................................#...#..........#....#.......#.......................
-.........................................................#..............#.............#.#.......#..................#......#...#...
-...........#...........................#.........#..................................#.....................................#.......
-.#..........................................#................................................................#...........#........
-..................................##....................#..........#..............#..........#............#.....#.................
-##..#..#....#.........................................................#...............#...................................#.......
-....#.#...........#............................#....#........#......#.............................................................
-#....#.........................#.............#.............................................................#.....#..#.............
-............................#......#................#...............................................#.....#............#..........
-........................#.............#..................#........................................................................
-..#.............#........................................#.................................................#......................
-...................#...................#..........#..#.............................................#...........#...#............#.
-....#...#..........................##...#...............#....#.....................................#............#.................
-..............................#..............................................................###..................................
-.................#......................................#.......................##.#............#.................................
-............#.........................................#......##.....................#............#................................
-....................#..#.............................#...#......................................#....................#.#........#.
-....#...........#...........................................#....................................................#................
-............#.....#..................................##.........#........#.....................................#..................
-......#..............#..................................#...#.......#.............................................................
-..........#.................#.......#.........................................................................#...........#.......
-...........#..............#................#.....##.#............#........#.......................................................
-................#.......................#.........................................................................#...........#...
-..............................#............................................#.......#..............................................
-..............#..............................#......#..................#....#.....#.................................#........#....
-....................##..........#...#..#................#............#.......................#.................................#..
-........................##.......#..................#.......#.....................#................#.....................#.....#..
-..............#.............#...#......#.........#.................##..#...........#...........#.........................##.......
-#........#...........#........#..#............#...........#....#....#......##..............................................#......
-........................................#.....................#....................................#........#......#......#.......
-.............#........#.............#...........#..#..#.......................................................#......#............
-#...................#.#........................................#..................................#...#...........................
-";
 }
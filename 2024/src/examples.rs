@@ -0,0 +1,186 @@
+//! Tests of the examples in the puzzle statements, generated by
+//! `cargo run -p aoc -- examples`. Do not edit, run that again instead.
+
+use common::{Answer, Solution};
+
+#[test]
+fn day_1_part_1() {
+    let input = r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+    assert_eq!(
+        Answer::from(crate::day_1::Solver::solve_1(input)).to_string(),
+        "11"
+    );
+}
+
+#[test]
+fn day_1_part_2() {
+    let input = r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+    assert_eq!(
+        Answer::from(crate::day_1::Solver::solve_2(input)).to_string(),
+        "31"
+    );
+}
+
+#[test]
+fn day_2_part_1() {
+    let input = r"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+    assert_eq!(
+        Answer::from(crate::day_2::Solver::solve_1(input)).to_string(),
+        "2"
+    );
+}
+
+#[test]
+fn day_2_part_2() {
+    let input = r"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+    assert_eq!(
+        Answer::from(crate::day_2::Solver::solve_2(input)).to_string(),
+        "4"
+    );
+}
+
+#[test]
+fn day_3_part_1() {
+    let input = r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    assert_eq!(
+        Answer::from(crate::day_3::Solver::solve_1(input)).to_string(),
+        "161"
+    );
+}
+
+#[test]
+fn day_3_part_2() {
+    let input = r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    assert_eq!(
+        Answer::from(crate::day_3::Solver::solve_2(input)).to_string(),
+        "48"
+    );
+}
+
+#[test]
+fn day_4_part_1() {
+    let input = r"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+    assert_eq!(
+        Answer::from(crate::day_4::Solver::solve_1(input)).to_string(),
+        "18"
+    );
+}
+
+#[test]
+fn day_4_part_2() {
+    let input = r"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+    assert_eq!(
+        Answer::from(crate::day_4::Solver::solve_2(input)).to_string(),
+        "9"
+    );
+}
+
+#[test]
+fn day_5_part_1() {
+    let input = r"47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+    assert_eq!(
+        Answer::from(crate::day_5::Solver::solve_1(input)).to_string(),
+        "143"
+    );
+}
+
+#[test]
+fn day_5_part_2() {
+    let input = r"47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+    assert_eq!(
+        Answer::from(crate::day_5::Solver::solve_2(input)).to_string(),
+        "123"
+    );
+}
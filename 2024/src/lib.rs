@@ -1,6 +1,24 @@
-mod day_1;
-mod day_2;
-mod day_3;
-mod day_4;
-mod day_5;
-mod day_6;
+use common::{Day, Registry};
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+
+#[cfg(test)]
+mod examples;
+
+/// Every day of 2024, for tooling that runs days by number.
+pub const REGISTRY: Registry = Registry {
+    year: 2024,
+    days: &[
+        Day::new::<day_1::Solver>(1),
+        Day::new::<day_2::Solver>(2),
+        Day::new::<day_3::Solver>(3),
+        Day::new::<day_4::Solver>(4),
+        Day::new::<day_5::Solver>(5),
+        Day::new::<day_6::Solver>(6),
+    ],
+};
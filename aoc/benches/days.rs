@@ -0,0 +1,113 @@
+//! Benchmarks parsing and each part separately, for every day that has an
+//! input. After the run, the median times are written as a table to
+//! `summary.md` in the criterion output directory, in a fixed order so that
+//! the tables from two commits can be diffed.
+
+use aoc::{format_time, YEARS};
+use common::{input, Timer};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Benchmarks every stage of a day as one function of the day's group.
+struct Stages<'a> {
+    group: BenchmarkGroup<'a, WallTime>,
+    names: Vec<String>,
+}
+
+impl Timer for Stages<'_> {
+    fn time(&mut self, stage: &str, f: &mut dyn FnMut()) {
+        self.group.bench_function(stage, |b| b.iter(&mut *f));
+        self.names.push(stage.to_string());
+    }
+}
+
+fn output_dir() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .expect("The aoc crate must be inside the workspace")
+                .join("target")
+        })
+        .join("criterion")
+}
+
+/// Median time in nanoseconds that criterion recorded for a benchmark, if
+/// it was measured after `since`.
+fn median(dir: &Path, group: &str, stage: &str, since: SystemTime) -> Option<f64> {
+    let path = dir
+        .join(group)
+        .join(stage)
+        .join("new")
+        .join("estimates.json");
+    if fs::metadata(&path).ok()?.modified().ok()? < since {
+        return None;
+    }
+    let estimates: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    estimates["median"]["point_estimate"].as_f64()
+}
+
+fn main() {
+    let dir = output_dir();
+    let start = SystemTime::now();
+    let mut criterion = Criterion::default()
+        .output_directory(&dir)
+        .configure_from_args();
+    let mut measured = Vec::new();
+    for registry in YEARS {
+        for day in registry.days {
+            let (year, day) = (registry.year, day);
+            if !(1..=2).any(|part| day.is_solved(part)) {
+                continue;
+            }
+            let Ok(text) = input::load(year, day.day) else {
+                eprintln!("Skipping {year} day {}, no input", day.day);
+                continue;
+            };
+            let group = format!("{year}_day_{}", day.day);
+            let mut stages = Stages {
+                group: criterion.benchmark_group(&group),
+                names: Vec::new(),
+            };
+            if let Err(e) = day.bench(&text, &mut stages) {
+                eprintln!("Skipping {year} day {}, malformed input: {e}", day.day);
+            }
+            stages.group.finish();
+            measured.extend(
+                stages
+                    .names
+                    .into_iter()
+                    .map(|stage| (year, day.day, group.clone(), stage)),
+            );
+        }
+    }
+    criterion.final_summary();
+    let rows: Vec<_> = measured
+        .iter()
+        .filter_map(|(year, day, group, stage)| {
+            let time = median(&dir, group, stage, start)?;
+            Some(format!(
+                "| {year} | {day:>3} | {stage:<6} | {:>10} |",
+                format_time(time)
+            ))
+        })
+        .collect();
+    if rows.is_empty() {
+        return;
+    }
+    let table = format!(
+        "| Year | Day | Stage  |     Median |\n|------|-----|--------|------------|\n{}\n",
+        rows.join("\n")
+    );
+    let path = dir.join("summary.md");
+    match fs::write(&path, &table) {
+        Ok(()) => println!("\n{table}\nWritten to {}", path.display()),
+        Err(e) => eprintln!("Cannot write {}: {e}", path.display()),
+    }
+}
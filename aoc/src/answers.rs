@@ -0,0 +1,105 @@
+//! Known answers for the inputs of a year, kept in a TOML file with a table
+//! per day and a key per part:
+//!
+//! ```toml
+//! [day_1]
+//! part_1 = 54968
+//! part_2 = "QNNTGTPFN"
+//! ```
+//!
+//! Numbers and strings are both accepted. Strings are read the same way as
+//! the answers of the solvers, so `"42"` is the number 42.
+
+use common::{input, Answer};
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32), Answer>,
+}
+
+/// Number in a key such as `day_12`.
+fn key_number(key: &str, prefix: &str) -> Option<u32> {
+    key.strip_prefix(prefix)?.parse().ok()
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let table: toml::Table = text.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        let mut answers = BTreeMap::new();
+        for (key, parts) in &table {
+            let day = key_number(key, "day_")
+                .filter(|d| (1..=25).contains(d))
+                .ok_or_else(|| format!("Expected a table such as [day_1], found [{key}]"))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("Expected [{key}] to be a table"))?;
+            for (key, value) in parts {
+                let part = key_number(key, "part_")
+                    .filter(|p| (1..=2).contains(p))
+                    .ok_or_else(|| {
+                        format!("Expected part_1 or part_2 in day {day}, found {key}")
+                    })?;
+                let answer = match value {
+                    toml::Value::Integer(n) => Answer::from(*n),
+                    toml::Value::String(s) => Answer::parse(s),
+                    _ => {
+                        return Err(format!(
+                            "Expected a number or a string for day {day} part {part}"
+                        ))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Answers { answers })
+    }
+
+    /// Reads the answers file at `path`. A missing file has no answers.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("Cannot read {}: {e}", path.display())),
+        }
+    }
+
+    /// Reads the answers for the given year from the inputs directory.
+    pub fn for_year(year: u32) -> Result<Answers, String> {
+        Answers::load(&input::answers_path(year))
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    /// Days that have at least one answer, in order.
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut last = None;
+        self.answers
+            .keys()
+            .filter_map(move |&(day, _)| (last.replace(day) != Some(day)).then_some(day))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_answers() {
+        let answers = Answers::parse(
+            "[day_1]\npart_1 = 54968\npart_2 = \"QNNTGTPFN\"\n\n[day_10]\npart_2 = \"\"\"\n#..#\n####\"\"\"\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, 1), Some(&Answer::Int(54968)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::from("QNNTGTPFN")));
+        assert_eq!(answers.get(10, 1), None);
+        assert_eq!(answers.get(10, 2), Some(&Answer::from("#..#\n####")));
+        assert_eq!(answers.days().collect::<Vec<_>>(), [1, 10]);
+        assert!(Answers::parse("[day_26]\npart_1 = 1").is_err());
+        assert!(Answers::parse("[day_1]\npart_3 = 1").is_err());
+        assert!(Answers::parse("[day_1]\npart_1 = 1.5").is_err());
+        assert!(Answers::parse("[day_1\n").is_err());
+    }
+}
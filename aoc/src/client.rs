@@ -0,0 +1,420 @@
+//! Client for the Advent of Code website. The site asks automated tools to
+//! identify themselves with a User-Agent, to cache what they download, and
+//! to keep their requests infrequent, which this client does.
+
+use common::input;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const USER_AGENT: &str = concat!(
+    "github.com/ranjeethmahankali/adventofcode aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// Environment variable that overrides the server, for example to point
+/// the client at the mock server.
+pub const URL_VAR: &str = "AOC_URL";
+
+/// Environment variable holding the session cookie of the account.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Minimum time between two requests to the server.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// When the puzzle of the given day unlocks, which is midnight US Eastern
+/// time, or 05:00 UTC.
+pub fn unlock_time(year: u32, day: u32) -> SystemTime {
+    // Days since the epoch of the given date in December, using Howard
+    // Hinnant's days from civil algorithm.
+    let (y, m, d) = (year as i64, 12i64, day as i64);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m - 3) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+    UNIX_EPOCH + Duration::from_secs((days * 86400 + 5 * 3600) as u64)
+}
+
+/// Spaces out requests by keeping the time of the last one in a file, so
+/// that separate runs of the tool are throttled too.
+pub struct Throttle {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(path: PathBuf, interval: Duration) -> Throttle {
+        Throttle { path, interval }
+    }
+
+    /// Sleeps until the interval has passed since the last request, then
+    /// records the current time as the last request.
+    pub fn wait(&self) -> Result<(), String> {
+        let last = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|text| text.trim().parse::<u64>().ok())
+            .map(|nanos| UNIX_EPOCH + Duration::from_nanos(nanos));
+        if let Some(last) = last {
+            let elapsed = SystemTime::now()
+                .duration_since(last)
+                .unwrap_or(Duration::ZERO);
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| e.to_string())?;
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Cannot create {}: {e}", dir.display()))?;
+        }
+        fs::write(&self.path, now.as_nanos().to_string())
+            .map_err(|e| format!("Cannot write {}: {e}", self.path.display()))
+    }
+}
+
+/// Whether an input was read from the disk or downloaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// The server's reply to an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently. Holds how long is left to
+    /// wait, if the server said.
+    Wait(Option<Duration>),
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+}
+
+/// Parses a wait such as `1m 5s`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut secs = 0;
+    for token in text.split_whitespace() {
+        let (digits, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = digits.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the server replies to an answer
+    /// with. The page is HTML meant for people, so this only looks for the
+    /// sentences the server is known to use.
+    pub fn parse(page: &str) -> Option<Verdict> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Right)
+        } else if page.contains("That's not the right answer") {
+            Some(if page.contains("answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| parse_wait(wait));
+            Some(Verdict::Wait(wait))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    url: String,
+    session: Option<String>,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub fn new(url: &str, session: Option<String>, throttle: Throttle) -> Client {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            url: url.trim_end_matches('/').to_string(),
+            session,
+            throttle,
+        }
+    }
+
+    /// Client for the server in `$AOC_URL`, or the real one, with the
+    /// session in `$AOC_SESSION`. The time of the last request is kept in
+    /// the inputs directory.
+    pub fn from_env() -> Client {
+        Client::new(
+            &env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_string()),
+            env::var(SESSION_VAR).ok().filter(|s| !s.trim().is_empty()),
+            Throttle::new(input::dir().join(".last_request"), MIN_INTERVAL),
+        )
+    }
+
+    /// Sends a request to the server, as a form post if `form` is given.
+    fn send(&self, path: &str, form: Option<&[(&str, &str)]>) -> Result<String, String> {
+        self.throttle.wait()?;
+        let url = format!("{}{path}", self.url);
+        let method = if form.is_some() { "POST" } else { "GET" };
+        let mut request = self.agent.request(method, &url);
+        if let Some(session) = &self.session {
+            request = request.set("Cookie", &format!("session={}", session.trim()));
+        }
+        let result = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match result {
+            Ok(response) => response.into_string().map_err(|e| e.to_string()),
+            Err(ureq::Error::Status(429, response)) => Err(format!(
+                "Rate limited by the server, retry after {} seconds",
+                response.header("Retry-After").unwrap_or("some")
+            )),
+            Err(ureq::Error::Status(code, response)) => Err(format!(
+                "{method} {path} failed with status {code}: {}",
+                response.into_string().unwrap_or_default().trim()
+            )),
+            Err(e) => Err(format!("{method} {path} failed: {e}")),
+        }
+    }
+
+    /// Saves the input of the given day to `path`, unless it is already
+    /// there. `force` downloads it again regardless.
+    pub fn fetch_input(
+        &self,
+        year: u32,
+        day: u32,
+        path: &Path,
+        force: bool,
+    ) -> Result<Fetched, String> {
+        if !force && path.exists() {
+            return Ok(Fetched::Cached);
+        }
+        if SystemTime::now() < unlock_time(year, day) {
+            return Err(format!("{year} day {day} is not unlocked yet"));
+        }
+        if self.session.is_none() {
+            return Err(format!(
+                "Inputs differ by account, set {SESSION_VAR} to your session cookie"
+            ));
+        }
+        let text = self.send(&format!("/{year}/day/{day}/input"), None)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Cannot create {}: {e}", dir.display()))?;
+        }
+        fs::write(path, text).map_err(|e| format!("Cannot write {}: {e}", path.display()))?;
+        Ok(Fetched::Downloaded)
+    }
+
+    /// Posts an answer to a part of the given day, and reads the verdict
+    /// out of the page the server replies with.
+    pub fn submit_answer(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+    ) -> Result<Verdict, String> {
+        if self.session.is_none() {
+            return Err(format!(
+                "Answers are per account, set {SESSION_VAR} to your session cookie"
+            ));
+        }
+        let level = part.to_string();
+        let page = self.send(
+            &format!("/{year}/day/{day}/answer"),
+            Some(&[("level", &level), ("answer", answer)]),
+        )?;
+        Verdict::parse(&page).ok_or_else(|| "Cannot make sense of the server's reply".to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::MockServer;
+    use std::time::Instant;
+
+    /// Empty directory for one test to write in.
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn fixtures() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+    }
+
+    fn client(server: &MockServer, dir: &Path, session: Option<&str>) -> Client {
+        Client::new(
+            &server.url(),
+            session.map(|s| s.to_string()),
+            Throttle::new(dir.join(".last_request"), Duration::ZERO),
+        )
+    }
+
+    #[test]
+    fn t_fetch() {
+        let dir = scratch("fetch");
+        let server = MockServer::start(fixtures(), "127.0.0.1:0").unwrap();
+        let client = client(&server, &dir, Some("abc123"));
+        let path = dir.join("2023").join("day_1.txt");
+        assert_eq!(
+            client.fetch_input(2023, 1, &path, false),
+            Ok(Fetched::Downloaded)
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            fs::read_to_string(fixtures().join("2023").join("day_1.txt")).unwrap()
+        );
+        // Cached inputs are not downloaded again, unless forced.
+        assert_eq!(
+            client.fetch_input(2023, 1, &path, false),
+            Ok(Fetched::Cached)
+        );
+        assert_eq!(server.received().len(), 1);
+        assert_eq!(
+            client.fetch_input(2023, 1, &path, true),
+            Ok(Fetched::Downloaded)
+        );
+        let received = server.received();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[0].method, "GET");
+        assert_eq!(received[0].url, "/2023/day/1/input");
+        assert_eq!(received[0].user_agent.as_deref(), Some(USER_AGENT));
+        assert_eq!(received[0].session.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn t_fetch_errors() {
+        let dir = scratch("fetch_errors");
+        let server = MockServer::start(fixtures(), "127.0.0.1:0").unwrap();
+        let path = dir.join("day_1.txt");
+        // No request is made without a session.
+        let err = client(&server, &dir, None)
+            .fetch_input(2023, 1, &path, false)
+            .unwrap_err();
+        assert!(err.contains(SESSION_VAR), "{err}");
+        assert!(server.received().is_empty());
+        let err = client(&server, &dir, Some("abc123"))
+            .fetch_input(2023, 25, &path, false)
+            .unwrap_err();
+        assert!(err.contains("404"), "{err}");
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn t_submit() {
+        let dir = scratch("submit");
+        let server = MockServer::start(fixtures(), "127.0.0.1:0").unwrap();
+        let logged_in = client(&server, &dir, Some("abc123"));
+        assert_eq!(
+            logged_in.submit_answer(2023, 1, 1, "142"),
+            Ok(Verdict::Right)
+        );
+        assert_eq!(
+            logged_in.submit_answer(2023, 1, 1, "142"),
+            Ok(Verdict::WrongLevel)
+        );
+        assert_eq!(
+            logged_in.submit_answer(2023, 1, 2, "100"),
+            Ok(Verdict::TooLow)
+        );
+        match logged_in.submit_answer(2023, 1, 2, "200") {
+            Ok(Verdict::Wait(Some(wait))) => assert!(wait <= crate::mock::COOLDOWN),
+            other => panic!("Expected to wait, got {other:?}"),
+        }
+        let received = server.received();
+        assert_eq!(received.len(), 4);
+        assert_eq!(received[0].method, "POST");
+        assert_eq!(received[0].url, "/2023/day/1/answer");
+        assert_eq!(received[0].body, "level=1&answer=142");
+        // No request is made without a session.
+        assert!(client(&server, &dir, None)
+            .submit_answer(2023, 1, 2, "142")
+            .is_err());
+        assert_eq!(server.received().len(), 4);
+    }
+
+    #[test]
+    fn t_verdict() {
+        let page = |text: &str| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+        assert_eq!(
+            Verdict::parse(&page("That's the right answer!  You are one gold star closer to finding the chief historian. <a href=\"/2024/day/1#part2\">[Continue to Part Two]</a>")),
+            Some(Verdict::Right)
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.")),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a>")),
+            Some(Verdict::Wait(Some(Duration::from_secs(65))))
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Some(Verdict::WrongLevel)
+        );
+        assert_eq!(Verdict::parse("<html></html>"), None);
+    }
+
+    #[test]
+    fn t_throttle() {
+        let dir = scratch("throttle");
+        let throttle = Throttle::new(dir.join(".last_request"), Duration::from_millis(300));
+        let start = Instant::now();
+        throttle.wait().unwrap();
+        assert!(start.elapsed() < Duration::from_millis(300));
+        // Separate throttles sharing the file wait on each other. The
+        // tolerance covers the wall clock and the monotonic clock drifting.
+        Throttle::new(dir.join(".last_request"), Duration::from_millis(300))
+            .wait()
+            .unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(290));
+    }
+
+    #[test]
+    fn t_unlock_time() {
+        assert_eq!(
+            unlock_time(2023, 1),
+            UNIX_EPOCH + Duration::from_secs(1701406800)
+        );
+        assert_eq!(
+            unlock_time(2024, 25),
+            UNIX_EPOCH + Duration::from_secs(1735102800)
+        );
+    }
+}
@@ -0,0 +1,478 @@
+//! Finds the examples in the puzzle statements at the top of each day, and
+//! the answers the statements give for them.
+//!
+//! The statements are plain text, or markdown when imported from a puzzle
+//! page, so this goes by how they are usually written. An example is the
+//! block of text after a paragraph such as "For example:", up to the next
+//! paragraph of prose, or an item of a list such as "`abc`: 5 steps". The
+//! answer is the last number or capitalised word in a paragraph of prose,
+//! leaving out anything in parentheses and the words of the final question
+//! of the part, and preferring emphasised words. It is looked for in the
+//! prose right after the example, then in the paragraph introducing it,
+//! then before the final question. This gives a few candidates for every
+//! part, and checking the solvers against them picks the right one, or
+//! none at all.
+
+/// An example input of a part, with the answer the statement gives.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: u32,
+    pub input: String,
+    pub answer: String,
+}
+
+/// The puzzle statement in the comment at the top of a day's source.
+pub fn statement(source: &str) -> Option<&str> {
+    let start = source.find("/*")? + 2;
+    let end = start + source[start..].find("*/")?;
+    Some(&source[start..end])
+}
+
+/// A paragraph of a statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Paragraph<'a> {
+    lines: Vec<&'a str>,
+    /// Whether it was fenced as code, which is never prose.
+    code: bool,
+}
+
+/// Lines of the text, split into paragraphs at blank lines. A fenced code
+/// block is a paragraph of its own, blank lines included, without the
+/// fences.
+fn paragraphs(text: &str) -> Vec<Paragraph<'_>> {
+    let mut paragraphs = Vec::new();
+    let mut current = Vec::new();
+    let mut fenced = false;
+    for line in text.lines().map(str::trim_end) {
+        if line.starts_with("```") {
+            if fenced || !current.is_empty() {
+                paragraphs.push(Paragraph {
+                    lines: std::mem::take(&mut current),
+                    code: fenced,
+                });
+            }
+            fenced = !fenced;
+        } else if fenced || !line.is_empty() {
+            current.push(line);
+        } else if !current.is_empty() {
+            paragraphs.push(Paragraph {
+                lines: std::mem::take(&mut current),
+                code: false,
+            });
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(Paragraph {
+            lines: current,
+            code: fenced,
+        });
+    }
+    paragraphs
+}
+
+/// Whether the paragraph reads like sentences, rather than like an example
+/// input or a diagram.
+fn is_prose(paragraph: &Paragraph) -> bool {
+    let Paragraph { lines, code } = paragraph;
+    let (Some(first), Some(last)) = (lines.first(), lines.last()) else {
+        return false;
+    };
+    if *code {
+        return false;
+    }
+    let words: Vec<&str> = lines.iter().flat_map(|l| l.split_whitespace()).collect();
+    // Sentences are mostly made of words, unlike lines such as
+    // `AAA = (BBB, CCC)`.
+    let wordy = words
+        .iter()
+        .filter(|w| {
+            w.trim_start_matches(['*', '"', '(', '`'])
+                .starts_with(|c: char| c.is_ascii_alphabetic())
+                && w.contains(|c: char| c.is_ascii_lowercase())
+        })
+        .count();
+    if wordy * 2 < words.len() {
+        return false;
+    }
+    let capitalised = first.starts_with(|c: char| c.is_ascii_uppercase() || c == '(');
+    // A sentence that goes on from the previous paragraph, such as
+    // "...now instead means this:".
+    let continued = first
+        .strip_prefix("...")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_lowercase()));
+    if (first.starts_with("* ") || first.starts_with("- ")) && words.len() >= 4 {
+        // A bulleted list.
+        return true;
+    }
+    (capitalised || continued)
+        && (last.ends_with(':')
+            || (words.len() >= 4 && last.ends_with(['.', '?', '!', ',', ')', '"'])))
+}
+
+/// Whether the paragraph introduces an example. `strict` only accepts the
+/// ones that say so.
+fn is_intro(paragraph: &Paragraph, strict: bool) -> bool {
+    is_prose(paragraph)
+        && paragraph.lines.last().is_some_and(|l| l.ends_with(':'))
+        && (!strict || paragraph.lines.join(" ").to_lowercase().contains("example"))
+}
+
+/// A block of text introduced by a paragraph ending in a colon.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Block {
+    /// Index of the paragraph introducing the block.
+    intro: usize,
+    /// Index of the paragraph after the block.
+    end: usize,
+    text: String,
+}
+
+/// Every block of text introduced by a paragraph ending in a colon. Blocks
+/// introduced as examples are preferred, if there are any.
+fn blocks(paragraphs: &[Paragraph]) -> Vec<Block> {
+    let find = |strict: bool| {
+        let mut blocks = Vec::new();
+        let mut i = 1;
+        while i < paragraphs.len() {
+            if !is_intro(&paragraphs[i - 1], strict) || is_prose(&paragraphs[i]) {
+                i += 1;
+                continue;
+            }
+            // Inputs can contain blank lines, so the block runs up to the
+            // next prose.
+            let end = (i..paragraphs.len())
+                .find(|j| is_prose(&paragraphs[*j]))
+                .unwrap_or(paragraphs.len());
+            let text: Vec<_> = paragraphs[i..end]
+                .iter()
+                .map(|p| p.lines.join("\n"))
+                .collect();
+            blocks.push(Block {
+                intro: i - 1,
+                end,
+                text: text.join("\n\n"),
+            });
+            i = end;
+        }
+        blocks
+    };
+    let strict = find(true);
+    if strict.is_empty() {
+        find(false)
+    } else {
+        strict
+    }
+}
+
+/// Examples given as the items of a list, such as
+/// `* mjqjpqmgbljsphdztnvjfqwrcgsmlb: first marker after character 7`, one
+/// word of input followed by a colon and the answer.
+fn listed(paragraphs: &[Paragraph], question: &[&str]) -> Vec<(String, String)> {
+    paragraphs
+        .iter()
+        .filter(|p| !p.code)
+        .flat_map(|p| &p.lines)
+        .filter_map(|line| {
+            let item = line
+                .strip_prefix("* ")
+                .or_else(|| line.strip_prefix("- "))?;
+            let (input, text) = item.split_once(':')?;
+            let input = input.trim().trim_matches('`');
+            if input.is_empty() || input.contains(char::is_whitespace) {
+                return None;
+            }
+            Some((input.to_string(), answer_in(text, question)?))
+        })
+        .collect()
+}
+
+/// Whether a word of prose looks like an answer.
+fn is_answer(word: &str) -> bool {
+    let digits = word.strip_prefix('-').unwrap_or(word);
+    (!digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+        || (word.len() >= 2
+            && word.starts_with(|c: char| c.is_ascii_uppercase())
+            && word
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()))
+}
+
+/// The last word of the text that looks like an answer, other than the
+/// words of the question.
+fn last_answer<'a>(text: &'a str, question: &[&str]) -> Option<&'a str> {
+    text.rsplit(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .find(|w| is_answer(w) && !question.contains(w))
+}
+
+/// Spans of the text emphasised in markdown, such as `*42*`.
+fn emphasised(text: &str) -> Vec<&str> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+    // An opening star is followed by a word, and a closing one follows a
+    // word, so bullets and escaped stars are left alone.
+    while let Some(open) = rest.match_indices('*').map(|(i, _)| i).find(|&i| {
+        rest[i + 1..].starts_with(|c: char| !c.is_whitespace()) && !rest[..i].ends_with('\\')
+    }) {
+        rest = &rest[open + 1..];
+        let Some(close) = rest
+            .match_indices('*')
+            .map(|(i, _)| i)
+            .find(|&i| i > 0 && !rest[..i].ends_with(|c: char| c.is_whitespace() || c == '\\'))
+        else {
+            break;
+        };
+        spans.push(&rest[..close]);
+        rest = &rest[close + 1..];
+    }
+    spans
+}
+
+/// The answer given in a paragraph of prose, if any.
+fn answer_in(text: &str, question: &[&str]) -> Option<String> {
+    let mut text = text.to_string();
+    while let Some(open) = text.find('(') {
+        let close = text[open..].find(')').map_or(text.len(), |c| open + c + 1);
+        text.replace_range(open..close, " ");
+    }
+    emphasised(&text)
+        .into_iter()
+        .rev()
+        .find_map(|span| last_answer(span, question))
+        .or_else(|| last_answer(&text, question))
+        .map(|w| w.to_string())
+}
+
+/// Candidate examples of one part, most likely first. `reused` are the
+/// examples of the first part, which the second part often reuses.
+fn part_examples(part: u32, text: &str, reused: &[Block]) -> (Vec<Example>, Vec<Block>) {
+    // Pages of solved puzzles give the answers to the real input too.
+    let paragraphs: Vec<_> = paragraphs(text)
+        .into_iter()
+        .filter(|p| {
+            !p.lines.first().is_some_and(|l| {
+                l.starts_with("Your puzzle answer was")
+                    || l.starts_with("Both parts of this puzzle are complete")
+            })
+        })
+        .collect();
+    let Some((question, before)) = paragraphs.split_last() else {
+        return (Vec::new(), Vec::new());
+    };
+    // The question names what is counted, which is never the answer.
+    let question: Vec<&str> = question
+        .lines
+        .iter()
+        .flat_map(|l| l.split(|c: char| !c.is_ascii_alphanumeric()))
+        .collect();
+    let answer = |p: &Paragraph| {
+        is_prose(p)
+            .then(|| answer_in(&p.lines.join(" "), &question))
+            .flatten()
+    };
+    let last = before.iter().rev().find_map(answer);
+    let blocks = blocks(before);
+    let mut examples = Vec::new();
+    let mut add = |input: &str, answer: Option<String>| {
+        let example = answer.map(|answer| Example {
+            part,
+            input: input.to_string(),
+            answer,
+        });
+        if let Some(example) = example.filter(|e| !examples.contains(e)) {
+            examples.push(example);
+        }
+    };
+    for (i, block) in blocks.iter().enumerate() {
+        let next = blocks.get(i + 1).map_or(before.len(), |b| b.intro);
+        add(&block.text, before[block.end..next].iter().find_map(answer));
+        add(&block.text, answer(&before[block.intro]));
+        add(&block.text, last.clone());
+    }
+    for (input, answer) in listed(before, &question) {
+        add(&input, Some(answer));
+    }
+    for block in reused {
+        add(&block.text, last.clone());
+    }
+    (examples, blocks)
+}
+
+/// Candidate examples of both parts of a statement, most likely first.
+/// Parts often show diagrams of intermediate steps besides the input, and
+/// the second part often reuses the example of the first, so every block
+/// of a part is a candidate, followed by those of the first part.
+pub fn extract(statement: &str) -> Vec<Example> {
+    let (first, second) = match statement.split_once("--- Part Two ---") {
+        Some((first, second)) => (first, Some(second)),
+        None => (statement, None),
+    };
+    let (mut examples, blocks) = part_examples(1, first, &[]);
+    if let Some(second) = second {
+        examples.extend(part_examples(2, second, &blocks).0);
+    }
+    examples
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = "/*
+--- Day 1: Example ---
+
+The document consists of lines of text, with a value on each line
+(your puzzle input).
+
+For example:
+
+1abc2
+pqr3stu8vwx
+
+In this example, the values of these two lines are 12 and 38. Adding
+these together produces 50 (12 + 38).
+
+What is the sum of all of the values?
+
+--- Part Two ---
+
+Some of the digits are spelled out. For example:
+
+two1nine
+
+- The value of two1nine is 29 now.
+
+The answer would be ABC.
+
+What is the sum of all of the values now?
+*/
+
+pub struct Solver;
+";
+
+    #[test]
+    fn t_extract() {
+        let examples = extract(statement(SOURCE).unwrap());
+        assert_eq!(
+            examples[..2],
+            [
+                Example {
+                    part: 1,
+                    input: "1abc2\npqr3stu8vwx".to_string(),
+                    answer: "50".to_string(),
+                },
+                Example {
+                    part: 2,
+                    input: "two1nine".to_string(),
+                    answer: "29".to_string(),
+                },
+            ]
+        );
+        // Then the answer before the question, for the example of the part
+        // and for the one the second part can reuse from the first.
+        assert_eq!(examples.len(), 4);
+        assert_eq!(examples[2].input, "two1nine");
+        assert_eq!(examples[2].answer, "ABC");
+        assert_eq!(examples[3].part, 2);
+        assert_eq!(examples[3].input, "1abc2\npqr3stu8vwx");
+        assert_eq!(statement("pub struct Solver;"), None);
+    }
+
+    /// A statement imported from a puzzle page, in markdown.
+    const MARKDOWN: &str = "--- Day 1: Example ---
+
+For example:
+
+```
+Seeds: 79 14 55 13.
+
+Soil: 1 2 3 4.
+```
+
+The lowest of *4* numbers is *`13`*, after 2 \\*steps\\* and 1 more.
+
+*What is the lowest number?*";
+
+    fn paragraph<'a>(lines: &[&'a str]) -> Paragraph<'a> {
+        Paragraph {
+            lines: lines.to_vec(),
+            code: false,
+        }
+    }
+
+    #[test]
+    fn t_extract_markdown() {
+        assert_eq!(
+            extract(MARKDOWN),
+            [Example {
+                part: 1,
+                input: "Seeds: 79 14 55 13.\n\nSoil: 1 2 3 4.".to_string(),
+                answer: "13".to_string(),
+            }]
+        );
+        assert_eq!(emphasised("* a *b* \\*c\\* *`d`*"), ["b", "`d`"]);
+    }
+
+    #[test]
+    fn t_paragraphs() {
+        let text = "a\nb  \n\n\n   \nc\n```\nd\n\ne\n```\n";
+        assert_eq!(
+            paragraphs(text),
+            [
+                paragraph(&["a", "b"]),
+                paragraph(&["c"]),
+                Paragraph {
+                    lines: vec!["d", "", "e"],
+                    code: true,
+                },
+            ]
+        );
+        assert!(is_prose(&paragraph(&["For example:"])));
+        assert!(is_prose(&paragraph(&["- a bulleted list item."])));
+        assert!(!is_prose(&paragraph(&["#.##..##.", "..#.##.#."])));
+        assert!(!is_prose(&paragraph(&["seeds: 79 14 55 13"])));
+        assert!(!is_prose(&paragraph(&[
+            "AAA = (BBB, CCC)",
+            "ZZZ = (ZZZ, ZZZ)"
+        ])));
+        assert!(is_prose(&paragraph(&["...now instead means this:"])));
+        assert!(!is_prose(&paragraph(&["...#......", ".......#.."])));
+    }
+
+    /// Examples listed with their answers, and a question that names a
+    /// word that looks like an answer.
+    const LISTED: &str = "--- Day 6: Example ---
+
+For example, suppose you receive the following buffer:
+
+mjqjpqmgbljsphdztnvjfqwrcgsmlb
+
+Here XMAS shows up after 7 characters, and the last XMAS ends the line.
+
+Here are a few more examples:
+
+* bvwbjplbgvbhsrlpgdmjqwftvncz: first marker after character 5
+* `nppdvjthqldpwncqszvftbrmjlhg`: first marker after character *6*
+
+How many characters are processed before XMAS is found?
+
+Your puzzle answer was 1640.";
+
+    #[test]
+    fn t_extract_listed() {
+        let example = |input: &str, answer: &str| Example {
+            part: 1,
+            input: input.to_string(),
+            answer: answer.to_string(),
+        };
+        assert_eq!(
+            extract(LISTED),
+            [
+                example("mjqjpqmgbljsphdztnvjfqwrcgsmlb", "7"),
+                // The last answer before the question.
+                example("mjqjpqmgbljsphdztnvjfqwrcgsmlb", "6"),
+                example("bvwbjplbgvbhsrlpgdmjqwftvncz", "5"),
+                example("nppdvjthqldpwncqszvftbrmjlhg", "6"),
+            ]
+        );
+    }
+}
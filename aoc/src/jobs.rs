@@ -0,0 +1,169 @@
+//! Runs jobs on a pool of threads, each with a time limit.
+//!
+//! Threads cannot be stopped from the outside, so a job that runs out of
+//! time is given up on, and a new thread takes the place of the one still
+//! running it, which exits once the job is over. Results come back in the
+//! order of the jobs, however they were scheduled.
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A job to run on the pool.
+pub type Job<T> = Box<dyn FnOnce() -> T + Send>;
+
+/// How a job ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Done(T),
+    Panicked,
+    TimedOut,
+}
+
+/// What the threads tell the pool about a job.
+enum Event<T> {
+    Started(usize, Instant),
+    Finished(usize, Option<T>),
+}
+
+/// Runs the jobs on `threads` threads, giving up on any that take longer
+/// than `timeout`, and returns how each of them ended, in order.
+pub fn run<T: Send + 'static>(
+    jobs: Vec<Job<T>>,
+    threads: usize,
+    timeout: Duration,
+) -> Vec<Outcome<T>> {
+    let count = jobs.len();
+    let jobs: Arc<Vec<Mutex<Option<Job<T>>>>> =
+        Arc::new(jobs.into_iter().map(|j| Mutex::new(Some(j))).collect());
+    let next = Arc::new(AtomicUsize::new(0));
+    // Set by whichever comes first of the job finishing and the pool giving
+    // up on it, so that only one of them goes on with the next jobs.
+    let settled: Arc<Vec<AtomicBool>> =
+        Arc::new((0..count).map(|_| AtomicBool::new(false)).collect());
+    let (sender, receiver) = mpsc::channel();
+    let spawn = || {
+        let (jobs, next, settled, sender) =
+            (jobs.clone(), next.clone(), settled.clone(), sender.clone());
+        thread::spawn(move || loop {
+            let i = next.fetch_add(1, Ordering::Relaxed);
+            let Some(job) = jobs.get(i).and_then(|j| j.lock().unwrap().take()) else {
+                break;
+            };
+            if sender.send(Event::Started(i, Instant::now())).is_err() {
+                break;
+            }
+            let result = panic::catch_unwind(AssertUnwindSafe(job)).ok();
+            if settled[i].swap(true, Ordering::SeqCst) {
+                // Timed out, and another thread took over.
+                break;
+            }
+            if sender.send(Event::Finished(i, result)).is_err() {
+                break;
+            }
+        });
+    };
+    for _ in 0..threads.clamp(1, count.max(1)) {
+        spawn();
+    }
+    let mut outcomes: Vec<Option<Outcome<T>>> = (0..count).map(|_| None).collect();
+    let mut running: Vec<(usize, Instant)> = Vec::new();
+    let mut left = count;
+    while left > 0 {
+        let deadline = running.iter().map(|(_, start)| *start + timeout).min();
+        let event = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver
+                .recv()
+                .map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+        };
+        match event {
+            Ok(Event::Started(i, start)) => running.push((i, start)),
+            Ok(Event::Finished(i, result)) => {
+                running.retain(|(j, _)| *j != i);
+                if outcomes[i].is_none() {
+                    outcomes[i] = Some(result.map_or(Outcome::Panicked, Outcome::Done));
+                    left -= 1;
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                for (i, _) in running.iter().filter(|(_, start)| *start + timeout <= now) {
+                    // Otherwise it has just finished, and says so next.
+                    if !settled[*i].swap(true, Ordering::SeqCst) {
+                        outcomes[*i] = Some(Outcome::TimedOut);
+                        left -= 1;
+                        spawn();
+                    }
+                }
+                running.retain(|(_, start)| *start + timeout > now);
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    outcomes
+        .into_iter()
+        .map(|o| o.unwrap_or(Outcome::TimedOut))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_run() {
+        let mut jobs: Vec<Job<usize>> = Vec::new();
+        for i in 0..20 {
+            // Later jobs finish first, and still come back in order.
+            jobs.push(Box::new(move || {
+                thread::sleep(Duration::from_millis(20 - i as u64));
+                i
+            }));
+        }
+        jobs.push(Box::new(|| panic!("failed")));
+        jobs.push(Box::new(|| {
+            thread::sleep(Duration::from_secs(5));
+            0
+        }));
+        jobs.push(Box::new(|| 100));
+        let start = Instant::now();
+        let outcomes = run(jobs, 4, Duration::from_millis(300));
+        assert!(start.elapsed() < Duration::from_secs(2));
+        let expected: Vec<_> = (0..20)
+            .map(Outcome::Done)
+            .chain([Outcome::Panicked, Outcome::TimedOut, Outcome::Done(100)])
+            .collect();
+        assert_eq!(outcomes, expected);
+        assert_eq!(run(Vec::<Job<()>>::new(), 4, Duration::from_secs(1)), []);
+    }
+
+    #[test]
+    fn t_run_timed_out() {
+        // The thread that gave up on the first job must not go on with the
+        // others once it is over, alongside the one that replaced it.
+        let active = Arc::new(AtomicUsize::new(0));
+        let most = Arc::new(AtomicUsize::new(0));
+        let mut jobs: Vec<Job<()>> = vec![Box::new(|| thread::sleep(Duration::from_millis(200)))];
+        for _ in 0..10 {
+            let (active, most) = (active.clone(), most.clone());
+            jobs.push(Box::new(move || {
+                most.fetch_max(active.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(50));
+                active.fetch_sub(1, Ordering::SeqCst);
+            }));
+        }
+        let outcomes = run(jobs, 1, Duration::from_millis(100));
+        assert_eq!(outcomes[0], Outcome::TimedOut);
+        assert!(outcomes[1..].iter().all(|o| *o == Outcome::Done(())));
+        assert_eq!(most.load(Ordering::SeqCst), 1);
+    }
+}
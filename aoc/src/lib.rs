@@ -0,0 +1,105 @@
+//! Pieces of the runner shared by the binary, the benchmarks and the tests.
+
+pub mod answers;
+pub mod client;
+pub mod examples;
+pub mod jobs;
+pub mod mock;
+pub mod page;
+pub mod scaffold;
+pub mod status;
+pub mod submissions;
+
+use common::Registry;
+use std::path::{Path, PathBuf};
+
+/// Solutions of every year, in order.
+pub const YEARS: [&Registry; 3] = [
+    &adventofcode_2022::REGISTRY,
+    &adventofcode_2023::REGISTRY,
+    &adventofcode_2024::REGISTRY,
+];
+
+/// Root of the workspace, where each year's crate lives.
+pub fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The aoc crate must be inside the workspace")
+}
+
+/// Source file of the given day.
+pub fn source_path(year: u32, day: u32) -> PathBuf {
+    workspace()
+        .join(year.to_string())
+        .join("src")
+        .join(format!("day_{day}.rs"))
+}
+
+/// Renders a duration given in nanoseconds with a unit that suits it.
+pub fn format_time(nanos: f64) -> String {
+    match nanos {
+        n if n < 1e3 => format!("{n:.1} ns"),
+        n if n < 1e6 => format!("{:.1} µs", n / 1e3),
+        n if n < 1e9 => format!("{:.1} ms", n / 1e6),
+        n => format!("{:.2} s", n / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{env, fmt::Write, fs};
+
+    /// Answers to the inputs generated by `t_generate`, one line per part.
+    /// Set `UPDATE_SNAPSHOTS` when running the test to rewrite it, after
+    /// checking that the changed answers are right.
+    const SNAPSHOT: &str = "snapshots/generated.txt";
+
+    #[test]
+    fn t_generate() {
+        // Every generated input must parse, and both parts must solve it
+        // with the same answers as before.
+        let mut answers = String::new();
+        for registry in YEARS {
+            for day in registry.days {
+                for (seed, size) in [(0, 1), (1, 3), (2, 10), (3, 30)] {
+                    let input = day.generate(seed, size);
+                    assert_eq!(input, day.generate(seed, size));
+                    for part in 1..=2 {
+                        match day.solve(&input, part) {
+                            Some(Ok(answer)) => writeln!(
+                                answers,
+                                "{} day {} seed {seed} size {size} part {part}: {:?}",
+                                registry.year,
+                                day.day,
+                                answer.to_string()
+                            )
+                            .unwrap(),
+                            Some(Err(e)) => panic!(
+                                "{} day {} seed {seed} size {size}: {e}\n{input}",
+                                registry.year, day.day
+                            ),
+                            None => {}
+                        }
+                    }
+                }
+            }
+        }
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SNAPSHOT);
+        if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, answers).unwrap();
+            return;
+        }
+        let expected = fs::read_to_string(&path).unwrap_or_default();
+        let changed: Vec<&str> = answers
+            .lines()
+            .filter(|line| !expected.lines().any(|old| old == *line))
+            .collect();
+        assert!(
+            changed.is_empty() && answers.lines().count() == expected.lines().count(),
+            "Answers to generated inputs differ from {SNAPSHOT}:\n{}",
+            changed.join("\n")
+        );
+    }
+}
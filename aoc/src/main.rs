@@ -0,0 +1,794 @@
+use aoc::{
+    answers::Answers,
+    client::{Client, Fetched, Verdict},
+    examples::{self, Example},
+    format_time,
+    jobs::{self, Outcome},
+    mock::MockServer,
+    page, scaffold,
+    status::{Star, Status},
+    submissions::{Submission, Submissions},
+    YEARS,
+};
+use clap::{Args, Parser, Subcommand};
+use common::{input, Answer, Day, ParseError, Registry};
+use std::{
+    fs, panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::{mpsc, Arc},
+    thread,
+    time::Duration,
+};
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every implemented day with `--all`.
+    Run(RunArgs),
+    /// Check every implemented day against the answers file of its year,
+    /// `answers.toml` in the inputs directory.
+    Verify(VerifyArgs),
+    /// Show a calendar of the stars of each year: parts that are solved
+    /// and verified by an answer, solved but unverified, or unimplemented.
+    Status(StatusArgs),
+    /// Add a day to the crate of its year, from a template. The statement
+    /// is filled in from the puzzle page if it was saved.
+    New(NewArgs),
+    /// Import the statement of a day from its saved puzzle page, into the
+    /// comment at the top of its source and into `docs/<year>/day_<day>.md`.
+    Statement(StatementArgs),
+    /// Generate tests from the examples in the puzzle statements, into
+    /// `src/examples.rs` of each year's crate.
+    Examples(ExamplesArgs),
+    /// Print a random input for a day, in the format of its puzzle. The same
+    /// seed and size always give the same input.
+    Generate(GenerateArgs),
+    /// Download the input of a day into the inputs directory, unless it is
+    /// already there. Needs the session cookie in `AOC_SESSION`.
+    Fetch(FetchArgs),
+    /// Solve a part and submit the answer. Answers the server rejected
+    /// before are refused without asking it again.
+    Submit(SubmitArgs),
+    /// Serve fixture inputs and puzzle pages locally, standing in for the
+    /// Advent of Code server. Point the client at it with `AOC_URL`.
+    Mock(MockArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Puzzle year. Restricts `--all` to this year when given.
+    year: Option<u32>,
+    /// Puzzle day.
+    day: Option<u32>,
+    /// Run only this part instead of both.
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+    /// Puzzle input file. Defaults to `<year>/day_<day>.txt` in the inputs
+    /// directory, which can be set with `AOC_INPUTS`.
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Run every implemented day, and print a table of the answers and
+    /// the times they took.
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+    /// Number of parts to run at once with `--all`. Defaults to the number
+    /// of CPUs.
+    #[arg(long, requires = "all")]
+    jobs: Option<usize>,
+    /// Seconds a part gets with `--all` before it is given up on.
+    #[arg(long, requires = "all", default_value_t = 60)]
+    timeout: u64,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Verify only this year.
+    year: Option<u32>,
+}
+
+#[derive(Args)]
+struct StatusArgs {
+    /// Show only this year.
+    year: Option<u32>,
+    /// Print the same as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    year: u32,
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    /// Saved puzzle page. Defaults to `<year>/day_<day>.html` in the inputs
+    /// directory, if it is there.
+    #[arg(long)]
+    page: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct StatementArgs {
+    year: u32,
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    /// Saved puzzle page. Defaults to `<year>/day_<day>.html` in the inputs
+    /// directory.
+    #[arg(long)]
+    page: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ExamplesArgs {
+    /// Generate the tests of only this year.
+    year: Option<u32>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    year: u32,
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// How big the input is, as the number of lines or the side of the map,
+    /// depending on the puzzle.
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    year: u32,
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    /// Download the input even if it is already there.
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    year: u32,
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: u32,
+    /// Submit this answer instead of solving the part.
+    #[arg(long)]
+    answer: Option<String>,
+    /// Puzzle input file, as with `run`.
+    #[arg(long, conflicts_with = "answer")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct MockArgs {
+    #[arg(long, default_value_t = 8080)]
+    port: u16,
+    /// Directory with `<year>/day_<day>.txt` inputs and `.html` pages.
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"))]
+    fixtures: PathBuf,
+}
+
+fn registry(year: u32) -> Result<&'static Registry, String> {
+    YEARS
+        .iter()
+        .find(|r| r.year == year)
+        .copied()
+        .ok_or_else(|| format!("No solutions for year {year}"))
+}
+
+/// Prints the answer after the label, with the letters of a picture if
+/// they can be read.
+fn print_answer(label: &str, answer: &Answer) {
+    match (answer, answer.read()) {
+        (Answer::Art(art), Ok(letters)) => println!("{label}: {letters}\n{art}"),
+        (Answer::Art(art), Err(_)) => println!("{label}:\n{art}"),
+        (answer, _) => println!("{label}: {answer}"),
+    }
+}
+
+/// Renders a parse error like a compiler diagnostic, quoting the offending
+/// line of the input with a marker under the column.
+fn diagnostic(path: &Path, input: &str, err: &ParseError) -> String {
+    if err.line == 0 {
+        return format!("{}: {err}", path.display());
+    }
+    let text = input.lines().nth(err.line - 1).unwrap_or_default();
+    let num = err.line.to_string();
+    let pad = " ".repeat(num.len());
+    format!(
+        "expected {}\n{pad}--> {}:{}:{}\n{pad} |\n{num} | {text}\n{pad} | {}^",
+        err.expected,
+        path.display(),
+        err.line,
+        err.column,
+        " ".repeat(err.column - 1)
+    )
+}
+
+fn run_one(args: &RunArgs, year: u32, day: u32) -> Result<(), String> {
+    let solution = registry(year)?
+        .get(day)
+        .ok_or_else(|| format!("{year} day {day} is not implemented"))?;
+    let path = args.input.clone().unwrap_or_else(|| input::path(year, day));
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("Cannot read input {}: {e}", path.display()))?;
+    match args.part {
+        Some(part) => {
+            let answer = solution
+                .solve(&input, part)
+                .ok_or_else(|| format!("{year} day {day} part {part} is not implemented"))?
+                .map_err(|e| diagnostic(&path, &input, &e))?;
+            println!("{answer}");
+        }
+        None => {
+            if !(1..=2).any(|part| solution.is_solved(part)) {
+                return Err(format!("{year} day {day} is not implemented"));
+            }
+            for part in 1..=2 {
+                if let Some(answer) = solution.solve(&input, part) {
+                    let answer = answer.map_err(|e| diagnostic(&path, &input, &e))?;
+                    print_answer(&format!("Part {part}"), &answer);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Renders rows as a markdown table, with the columns padded to line up.
+/// Columns listed in `right` are aligned to the right.
+fn table(header: &[&str], rows: &[Vec<String>], right: &[usize]) -> String {
+    let mut widths: Vec<_> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |cells: Vec<String>| {
+        let cells: Vec<_> = cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, width))| match right.contains(&i) {
+                true => format!(" {cell:>width$} "),
+                false => format!(" {cell:<width$} "),
+            })
+            .collect();
+        format!("|{}|", cells.join("|"))
+    };
+    let mut lines = vec![line(header.iter().map(|h| h.to_string()).collect())];
+    lines.push(format!(
+        "|{}|",
+        widths
+            .iter()
+            .map(|w| "-".repeat(w + 2))
+            .collect::<Vec<_>>()
+            .join("|")
+    ));
+    lines.extend(rows.iter().map(|row| line(row.clone())));
+    lines.join("\n")
+}
+
+fn run_all(args: &RunArgs) -> Result<(), String> {
+    if let Some(year) = args.year {
+        registry(year)?;
+    }
+    let parts = match args.part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    };
+    // Every solved part, with its input if there is one. Inputs are shared
+    // by the parts of a day.
+    let mut runs = Vec::new();
+    let mut jobs: Vec<jobs::Job<_>> = Vec::new();
+    for registry in YEARS
+        .iter()
+        .filter(|r| args.year.is_none_or(|a| a == r.year))
+    {
+        let year = registry.year;
+        for solution in registry.days {
+            let day = solution.day;
+            let path = input::path(year, day);
+            let mut input = None;
+            for part in parts.clone().filter(|part| solution.is_solved(*part)) {
+                let input = input
+                    .get_or_insert_with(|| fs::read_to_string(&path).ok().map(Arc::new))
+                    .clone();
+                if let Some(input) = input.clone() {
+                    let solution = *solution;
+                    jobs.push(Box::new(move || solution.solve_timed(&input, part)));
+                }
+                runs.push((year, day, part, input));
+            }
+        }
+    }
+    let threads = args.jobs.unwrap_or_else(|| {
+        thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+    let mut outcomes = jobs::run(jobs, threads, Duration::from_secs(args.timeout)).into_iter();
+    let (mut failed, mut rows, mut errors) = (0usize, Vec::new(), Vec::new());
+    let mut malformed = None;
+    for (year, day, part, input) in runs {
+        let mut row = vec![year.to_string(), day.to_string(), part.to_string()];
+        let Some(input) = input else {
+            row.extend(["missing input".to_string(), String::new(), String::new()]);
+            rows.push(row);
+            continue;
+        };
+        let (answer, times) = match outcomes.next() {
+            Some(Outcome::Done(Some(Ok(timed)))) => {
+                let answer = match timed.answer.read() {
+                    Ok(answer) => answer.to_string(),
+                    Err(_) => "(picture)".to_string(),
+                };
+                (answer, Some((timed.parse, timed.solve)))
+            }
+            Some(Outcome::Done(Some(Err(e)))) => {
+                // Both parts fail on the same input, so it is shown once.
+                if malformed.replace((year, day)) != Some((year, day)) {
+                    let path = input::path(year, day);
+                    errors.push(format!("error: {}", diagnostic(&path, &input, &e)));
+                }
+                ("malformed input".to_string(), None)
+            }
+            Some(Outcome::Done(None)) | None => ("unimplemented".to_string(), None),
+            Some(Outcome::Panicked) => ("panicked".to_string(), None),
+            Some(Outcome::TimedOut) => ("timed out".to_string(), None),
+        };
+        if times.is_none() {
+            failed += 1;
+        }
+        row.push(answer);
+        match times {
+            Some((parse, solve)) => {
+                row.extend([parse, solve].map(|t| format_time(t.as_nanos() as f64)))
+            }
+            None => row.extend([String::new(), String::new()]),
+        }
+        rows.push(row);
+    }
+    println!(
+        "{}",
+        table(
+            &["Year", "Day", "Part", "Answer", "Parse", "Solve"],
+            &rows,
+            &[1, 2, 4, 5]
+        )
+    );
+    for error in errors {
+        eprintln!("{error}");
+    }
+    if failed > 0 {
+        return Err(format!("{failed} parts failed"));
+    }
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    if let Some(year) = args.year {
+        registry(year)?;
+    }
+    // Passed, failed, missing answers, missing inputs and unimplemented.
+    let mut counts = [0usize; 5];
+    let mut malformed = 0usize;
+    for registry in YEARS
+        .iter()
+        .filter(|r| args.year.is_none_or(|a| a == r.year))
+    {
+        let year = registry.year;
+        let answers = Answers::for_year(year)?;
+        let mut days: Vec<_> = registry
+            .days
+            .iter()
+            .map(|d| d.day)
+            .chain(answers.days())
+            .collect();
+        days.sort();
+        days.dedup();
+        for day in days {
+            let solution = registry.get(day);
+            let path = input::path(year, day);
+            let input = fs::read_to_string(&path).ok();
+            for part in 1..=2 {
+                let label = format!("{year} day {day} part {part}");
+                let Some(expected) = answers.get(day, part) else {
+                    // Parts that are neither implemented nor answered, such
+                    // as the second part of day 25, are not worth a line.
+                    if solution.is_some_and(|s| s.is_solved(part)) {
+                        println!("{label}: missing answer");
+                        counts[2] += 1;
+                    }
+                    continue;
+                };
+                let Some(input) = &input else {
+                    println!("{label}: missing input {}", path.display());
+                    counts[3] += 1;
+                    continue;
+                };
+                match solution.and_then(|s| s.solve(input, part)) {
+                    Some(Ok(answer)) if answer == *expected => {
+                        println!("{label}: pass");
+                        counts[0] += 1;
+                    }
+                    Some(Ok(answer)) => {
+                        println!("{label}: fail");
+                        eprintln!("expected:\n{expected}\ngot:\n{answer}");
+                        counts[1] += 1;
+                    }
+                    Some(Err(e)) => {
+                        println!("{label}: malformed input");
+                        eprintln!("error: {}", diagnostic(&path, input, &e));
+                        malformed += 1;
+                    }
+                    None => {
+                        println!("{label}: unimplemented");
+                        counts[4] += 1;
+                    }
+                }
+            }
+        }
+    }
+    let [passed, failed, missing, no_input, unimplemented] = counts;
+    println!(
+        "\n{passed} passed, {failed} failed, {missing} missing answers, \
+         {no_input} missing inputs, {unimplemented} unimplemented"
+    );
+    match (failed, malformed) {
+        (0, 0) => Ok(()),
+        (failed, 0) => Err(format!("{failed} answers are wrong")),
+        (failed, malformed) => Err(format!(
+            "{failed} answers are wrong and {malformed} inputs could not be parsed"
+        )),
+    }
+}
+
+fn status(args: &StatusArgs) -> Result<(), String> {
+    if let Some(year) = args.year {
+        registry(year)?;
+    }
+    let mut years = Vec::new();
+    for registry in YEARS
+        .iter()
+        .filter(|r| args.year.is_none_or(|a| a == r.year))
+    {
+        years.push(Status::new(registry, &Answers::for_year(registry.year)?));
+    }
+    if args.json {
+        let years: Vec<_> = years.iter().map(Status::to_json).collect();
+        let json = serde_json::to_string_pretty(&years).map_err(|e| e.to_string())?;
+        println!("{json}");
+        return Ok(());
+    }
+    for status in &years {
+        println!("{}", status.calendar());
+    }
+    let legend: Vec<_> = Star::ALL
+        .iter()
+        .map(|s| format!("{} {}", s.symbol(), s.name()))
+        .collect();
+    println!("{}", legend.join(", "));
+    Ok(())
+}
+
+fn new_day(args: &NewArgs) -> Result<(), String> {
+    let (year, day) = (args.year, args.day);
+    let lib_path = aoc::workspace()
+        .join(year.to_string())
+        .join("src")
+        .join("lib.rs");
+    let lib = fs::read_to_string(&lib_path).map_err(|e| {
+        format!(
+            "No crate for {year}, cannot read {}: {e}",
+            lib_path.display()
+        )
+    })?;
+    let path = aoc::source_path(year, day);
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+    let page_path = args.page.clone().or_else(|| {
+        let path = page_path(year, day);
+        path.exists().then_some(path)
+    });
+    let statement = page_path
+        .map(|path| {
+            fs::read_to_string(&path)
+                .map(|html| page::statement(&html, ""))
+                .map_err(|e| format!("Cannot read {}: {e}", path.display()))
+        })
+        .transpose()?
+        .filter(|s| !s.is_empty());
+    let example = statement
+        .as_deref()
+        .and_then(|s| examples::extract(s).into_iter().find(|e| e.part == 1));
+    let lib = scaffold::register(&lib, day)?;
+    let source = scaffold::day_source(
+        year,
+        day,
+        statement.as_deref(),
+        example.as_ref().map(|e| e.input.as_str()),
+    );
+    fs::write(&path, source).map_err(|e| format!("Cannot write {}: {e}", path.display()))?;
+    fs::write(&lib_path, lib).map_err(|e| format!("Cannot write {}: {e}", lib_path.display()))?;
+    println!("Created {}", path.display());
+    if statement.is_none() {
+        println!("No puzzle page was found, so the statement is left out");
+    }
+    Ok(())
+}
+
+/// Where the puzzle page of a day is saved in the inputs directory.
+fn page_path(year: u32, day: u32) -> PathBuf {
+    input::dir()
+        .join(year.to_string())
+        .join(format!("day_{day}.html"))
+}
+
+fn import_statement(args: &StatementArgs) -> Result<(), String> {
+    let (year, day) = (args.year, args.day);
+    let path = args.page.clone().unwrap_or_else(|| page_path(year, day));
+    let html =
+        fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+    if page::statement(&html, "").is_empty() {
+        return Err(format!("No puzzle statement in {}", path.display()));
+    }
+    let source_path = aoc::source_path(year, day);
+    let source = fs::read_to_string(&source_path)
+        .map_err(|e| format!("Cannot read {}: {e}", source_path.display()))?;
+    let source = scaffold::with_statement(&source, &page::statement(&html, ""));
+    fs::write(&source_path, source)
+        .map_err(|e| format!("Cannot write {}: {e}", source_path.display()))?;
+    let docs = aoc::workspace().join("docs").join(year.to_string());
+    let docs_path = docs.join(format!("day_{day}.md"));
+    fs::create_dir_all(&docs)
+        .and_then(|_| fs::write(&docs_path, page::statement(&html, "## ") + "\n"))
+        .map_err(|e| format!("Cannot write {}: {e}", docs_path.display()))?;
+    println!(
+        "Updated {} and {}",
+        source_path.display(),
+        docs_path.display()
+    );
+    Ok(())
+}
+
+/// How long a solver gets for an example before it is skipped.
+const EXAMPLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Solves an example on its own thread, so that a solver that panics or
+/// runs too long on an example it was not written for is only skipped.
+fn solve_example(solution: Day, example: &Example) -> Result<Answer, String> {
+    let (sender, receiver) = mpsc::channel();
+    let (input, part) = (example.input.clone(), example.part);
+    thread::spawn(move || {
+        let _ = sender.send(solution.solve(&input, part));
+    });
+    match receiver.recv_timeout(EXAMPLE_TIMEOUT) {
+        Ok(Some(Ok(answer))) => Ok(answer),
+        Ok(Some(Err(e))) => Err(format!("the example is malformed, {e}")),
+        Ok(None) => Err("the part is not implemented".to_string()),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err("the solver panicked".to_string()),
+        Err(mpsc::RecvTimeoutError::Timeout) => Err("the solver timed out".to_string()),
+    }
+}
+
+/// Renders the example as a test of the day's solver.
+fn example_test(day: u32, example: &Example) -> String {
+    // Enough hashes around the raw string for any quotes in the input.
+    let mut hashes = String::new();
+    while example.input.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+    let part = example.part;
+    let solve = format!("Answer::from(crate::day_{day}::Solver::solve_{part}(input)).to_string()");
+    let answer = format!("{:?}", example.answer);
+    // Laid out the way rustfmt would, which breaks calls with more than 60
+    // characters of arguments.
+    let assert = if solve.len() + answer.len() + 2 <= 60 {
+        format!("    assert_eq!({solve}, {answer});")
+    } else {
+        format!("    assert_eq!(\n        {solve},\n        {answer}\n    );")
+    };
+    format!(
+        "#[test]\nfn day_{day}_part_{part}() {{\n    let input = r{hashes}\"{}\"{hashes};\n{assert}\n}}\n",
+        example.input
+    )
+}
+
+fn generate_examples(args: &ExamplesArgs) -> Result<(), String> {
+    if let Some(year) = args.year {
+        registry(year)?;
+    }
+    // Solvers that panic on an example are reported as skipped, so their
+    // messages are only noise.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut result = Ok(());
+    for registry in YEARS
+        .iter()
+        .filter(|r| args.year.is_none_or(|a| a == r.year))
+    {
+        result = generate_year_examples(registry);
+        if result.is_err() {
+            break;
+        }
+    }
+    panic::set_hook(hook);
+    result
+}
+
+fn generate_year_examples(registry: &Registry) -> Result<(), String> {
+    let year = registry.year;
+    let mut tests = Vec::new();
+    for solution in registry.days {
+        let day = solution.day;
+        let path = aoc::source_path(year, day);
+        let source = fs::read_to_string(&path)
+            .map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+        let examples = examples::statement(&source)
+            .map(examples::extract)
+            .unwrap_or_default();
+        for part in (1..=2).filter(|p| solution.is_solved(*p)) {
+            let label = format!("{year} day {day} part {part}");
+            let mut candidates = examples.iter().filter(|e| e.part == part).peekable();
+            if candidates.peek().is_none() {
+                eprintln!("{label}: skipped, no example found");
+                continue;
+            }
+            // The reason the most likely candidate was skipped, if none of
+            // them agree with the solver.
+            let mut skipped = None;
+            for example in candidates {
+                match solve_example(*solution, example) {
+                    Ok(answer) if answer == Answer::parse(&example.answer) => {
+                        println!("{label}: {answer}");
+                        tests.push(example_test(day, example));
+                        skipped = None;
+                        break;
+                    }
+                    Ok(answer) => skipped.get_or_insert(format!(
+                        "the statement says {} but the solver gives {:?}",
+                        example.answer,
+                        answer.to_string()
+                    )),
+                    Err(e) => skipped.get_or_insert(e),
+                };
+            }
+            if let Some(reason) = skipped {
+                eprintln!("{label}: skipped, {reason}");
+            }
+        }
+    }
+    let src = aoc::workspace().join(year.to_string()).join("src");
+    let path = src.join("examples.rs");
+    let text = format!(
+        "//! Tests of the examples in the puzzle statements, generated by\n//! `cargo run -p aoc -- examples`. Do not edit, run that again instead.\n\nuse common::{{Answer, Solution}};\n\n{}",
+        tests.join("\n")
+    );
+    fs::write(&path, text).map_err(|e| format!("Cannot write {}: {e}", path.display()))?;
+    println!("Written {} tests to {}", tests.len(), path.display());
+    let lib = src.join("lib.rs");
+    if !fs::read_to_string(&lib).is_ok_and(|text| text.contains("mod examples;")) {
+        println!("Add `#[cfg(test)] mod examples;` to {}", lib.display());
+    }
+    Ok(())
+}
+
+fn generate_input(args: &GenerateArgs) -> Result<(), String> {
+    let (year, day) = (args.year, args.day);
+    let solution = registry(year)?
+        .get(day)
+        .ok_or_else(|| format!("{year} day {day} is not implemented"))?;
+    println!("{}", solution.generate(args.seed, args.size));
+    Ok(())
+}
+
+fn fetch(args: &FetchArgs) -> Result<(), String> {
+    let path = input::path(args.year, args.day);
+    match Client::from_env().fetch_input(args.year, args.day, &path, args.force)? {
+        Fetched::Cached => println!("Already have {}", path.display()),
+        Fetched::Downloaded => println!("Saved {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<(), String> {
+    let (year, day, part) = (args.year, args.day, args.part);
+    let answer = match &args.answer {
+        Some(answer) => Answer::parse(answer),
+        None => {
+            let solution = registry(year)?
+                .get(day)
+                .ok_or_else(|| format!("{year} day {day} is not implemented"))?;
+            let path = args.input.clone().unwrap_or_else(|| input::path(year, day));
+            let input = fs::read_to_string(&path)
+                .map_err(|e| format!("Cannot read input {}: {e}", path.display()))?;
+            solution
+                .solve(&input, part)
+                .ok_or_else(|| format!("{year} day {day} part {part} is not implemented"))?
+                .map_err(|e| diagnostic(&path, &input, &e))?
+        }
+    };
+    // Letters drawn as a picture are submitted as text.
+    let answer = answer
+        .read()
+        .map_err(|e| format!("{e}\nRead the letters and submit them with --answer"))?;
+    match &answer {
+        Answer::Text(text) if text.is_empty() || text.contains(char::is_whitespace) => {
+            return Err(format!("Not a submittable answer: {text:?}"));
+        }
+        _ => {}
+    }
+    let mut log = Submissions::load(input::dir().join("submissions.log"))?;
+    log.check(year, day, part, &answer)?;
+    println!("Submitting {answer} for {year} day {day} part {part}");
+    let verdict = Client::from_env().submit_answer(year, day, part, &answer.to_string())?;
+    log.record(Submission {
+        year,
+        day,
+        part,
+        verdict,
+        answer: answer.to_string(),
+    })?;
+    match verdict {
+        Verdict::Right => {
+            println!("That's the right answer");
+            Ok(())
+        }
+        Verdict::Wrong => Err(format!("{answer} is wrong")),
+        Verdict::TooHigh => Err(format!("{answer} is too high")),
+        Verdict::TooLow => Err(format!("{answer} is too low")),
+        Verdict::Wait(Some(wait)) => Err(format!(
+            "Answered too recently, wait {}s before trying again",
+            wait.as_secs()
+        )),
+        Verdict::Wait(None) => Err("Answered too recently, wait before trying again".to_string()),
+        Verdict::WrongLevel => Err(format!(
+            "{year} day {day} part {part} is already solved or not unlocked yet"
+        )),
+    }
+}
+
+fn serve_mock(args: MockArgs) -> Result<(), String> {
+    let server = MockServer::start(args.fixtures, &format!("127.0.0.1:{}", args.port))?;
+    println!("Serving on {}", server.url());
+    server.wait();
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let Cli { command } = Cli::parse();
+    let result = match command {
+        Command::Run(args) if args.all => run_all(&args),
+        Command::Run(args) => match (args.year, args.day) {
+            (Some(year), Some(day)) => run_one(&args, year, day),
+            _ => Err("Expected a year and a day, or --all".to_string()),
+        },
+        Command::Verify(args) => verify(&args),
+        Command::Status(args) => status(&args),
+        Command::New(args) => new_day(&args),
+        Command::Statement(args) => import_statement(&args),
+        Command::Examples(args) => generate_examples(&args),
+        Command::Generate(args) => generate_input(&args),
+        Command::Fetch(args) => fetch(&args),
+        Command::Submit(args) => submit(&args),
+        Command::Mock(args) => serve_mock(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Stand-in for the Advent of Code server, so the client can be tested
+//! offline. It serves files from a fixtures directory:
+//!
+//! - `GET /<year>/day/<day>/input` serves `<year>/day_<day>.txt`.
+//! - `GET /<year>/day/<day>` serves `<year>/day_<day>.html`.
+//! - `POST /<year>/day/<day>/answer` checks the answer against the line of
+//!   `<year>/day_<day>.answers` for the part, and replies with the same
+//!   sentences as the real server.
+//!
+//! Like the real server, it refuses requests without a session cookie, and
+//! after a wrong answer it asks to wait before answering again.
+
+use std::{
+    collections::HashSet,
+    fs, io,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+use tiny_http::{Header, Request, Response, Server};
+
+/// A request received by the mock server.
+#[derive(Clone, Debug)]
+pub struct Received {
+    pub method: String,
+    pub url: String,
+    pub user_agent: Option<String>,
+    pub session: Option<String>,
+    pub body: String,
+}
+
+/// How long the server asks to wait after a wrong answer.
+pub const COOLDOWN: Duration = Duration::from_secs(60);
+
+/// What the server remembers between requests.
+#[derive(Default)]
+struct State {
+    received: Vec<Received>,
+    /// Parts answered correctly, as (year, day, part).
+    solved: HashSet<(u32, u32, u32)>,
+    last_wrong: Option<Instant>,
+}
+
+pub struct MockServer {
+    server: Arc<Server>,
+    state: Arc<Mutex<State>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+fn header(request: &Request, name: &'static str) -> Option<String> {
+    request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv(name))
+        .map(|h| h.value.to_string())
+}
+
+fn session(request: &Request) -> Option<String> {
+    header(request, "Cookie")?.split(';').find_map(|cookie| {
+        cookie
+            .trim()
+            .strip_prefix("session=")
+            .map(|s| s.to_string())
+    })
+}
+
+/// Splits a path such as `/2023/day/7/input` into the year, the day and
+/// whatever comes after.
+fn route(url: &str) -> Option<(u32, u32, &str)> {
+    let mut parts = url.trim_start_matches('/').splitn(4, '/');
+    let year = parts.next()?.parse().ok()?;
+    if parts.next()? != "day" {
+        return None;
+    }
+    let day = parts.next()?.parse().ok()?;
+    Some((year, day, parts.next().unwrap_or("")))
+}
+
+/// Value of a field of a url encoded form.
+fn form_field<'a>(body: &'a str, name: &str) -> Option<&'a str> {
+    body.split('&')
+        .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+}
+
+/// Page with a single paragraph, shaped like the ones the real server
+/// replies to answers with.
+fn page(text: &str) -> Response<io::Cursor<Vec<u8>>> {
+    Response::from_string(format!(
+        "<!DOCTYPE html>\n<html lang=\"en-us\">\n<body>\n<main>\n<article><p>{text}</p></article>\n</main>\n</body>\n</html>\n"
+    ))
+    .with_header(Header::from_bytes("Content-Type", "text/html").unwrap())
+}
+
+fn answer(
+    fixtures: &Path,
+    state: &mut State,
+    (year, day): (u32, u32),
+    body: &str,
+) -> Response<io::Cursor<Vec<u8>>> {
+    let (Some(part), Some(answer)) = (
+        form_field(body, "level").and_then(|l| l.parse::<u32>().ok()),
+        form_field(body, "answer"),
+    ) else {
+        return Response::from_string("400 Bad Request\n").with_status_code(400);
+    };
+    if state.solved.contains(&(year, day, part)) {
+        return page("You don't seem to be solving the right level.  Did you already complete it?");
+    }
+    if let Some(wait) = state
+        .last_wrong
+        .and_then(|t| COOLDOWN.checked_sub(t.elapsed()))
+    {
+        return page(&format!(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {}s left to wait.",
+            wait.as_secs()
+        ));
+    }
+    let Some(expected) = fs::read_to_string(
+        fixtures
+            .join(year.to_string())
+            .join(format!("day_{day}.answers")),
+    )
+    .ok()
+    .and_then(|text| Some(text.lines().nth(part as usize - 1)?.trim().to_string())) else {
+        return Response::from_string("404 Not Found\n").with_status_code(404);
+    };
+    if answer == expected {
+        state.solved.insert((year, day, part));
+        return page("That's the right answer!  You are one gold star closer to saving Christmas.");
+    }
+    state.last_wrong = Some(Instant::now());
+    let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+        (Ok(a), Ok(e)) if a > e => "; your answer is too high",
+        (Ok(a), Ok(e)) if a < e => "; your answer is too low",
+        _ => "",
+    };
+    page(&format!(
+        "That's not the right answer{hint}.  If you're stuck, make sure you're using the full input data."
+    ))
+}
+
+fn respond(
+    fixtures: &Path,
+    state: &mut State,
+    request: &Received,
+) -> Response<io::Cursor<Vec<u8>>> {
+    let html = Header::from_bytes("Content-Type", "text/html").unwrap();
+    let file = match (request.method.as_str(), route(&request.url)) {
+        ("GET", Some((year, day, "input"))) => {
+            if request.session.is_none() {
+                return Response::from_string(
+                    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+                )
+                .with_status_code(400);
+            }
+            fixtures
+                .join(year.to_string())
+                .join(format!("day_{day}.txt"))
+        }
+        ("POST", Some((year, day, "answer"))) => {
+            if request.session.is_none() {
+                return Response::from_string("400 Bad Request\n").with_status_code(400);
+            }
+            return answer(fixtures, state, (year, day), &request.body);
+        }
+        ("GET", Some((year, day, ""))) => fixtures
+            .join(year.to_string())
+            .join(format!("day_{day}.html")),
+        _ => return Response::from_string("404 Not Found\n").with_status_code(404),
+    };
+    match fs::read_to_string(&file) {
+        Ok(text) if file.extension().is_some_and(|e| e == "html") => {
+            Response::from_string(text).with_header(html)
+        }
+        Ok(text) => Response::from_string(text),
+        Err(_) => Response::from_string("404 Not Found\n").with_status_code(404),
+    }
+}
+
+impl MockServer {
+    /// Starts serving `fixtures` on `addr` in a background thread. Use port
+    /// 0 to pick any free port.
+    pub fn start(fixtures: PathBuf, addr: &str) -> Result<MockServer, String> {
+        let server = Arc::new(Server::http(addr).map_err(|e| e.to_string())?);
+        let state = Arc::new(Mutex::new(State::default()));
+        let thread = {
+            let (server, state) = (server.clone(), state.clone());
+            std::thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let mut body = String::new();
+                    let _ = request.as_reader().read_to_string(&mut body);
+                    let info = Received {
+                        method: request.method().to_string(),
+                        url: request.url().to_string(),
+                        user_agent: header(&request, "User-Agent"),
+                        session: session(&request),
+                        body,
+                    };
+                    let mut state = state.lock().unwrap();
+                    let response = respond(&fixtures, &mut state, &info);
+                    state.received.push(info);
+                    drop(state);
+                    // The client hanging up early is not our problem.
+                    let _ = request.respond(response);
+                }
+            })
+        };
+        Ok(MockServer {
+            server,
+            state,
+            thread: Some(thread),
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.server
+            .server_addr()
+            .to_ip()
+            .expect("The mock server listens on TCP")
+    }
+
+    /// Base URL to point the client at.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr())
+    }
+
+    /// Every request received so far, in order.
+    pub fn received(&self) -> Vec<Received> {
+        self.state.lock().unwrap().received.clone()
+    }
+
+    /// Serves requests until the process is killed.
+    pub fn wait(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_route() {
+        assert_eq!(route("/2023/day/7/input"), Some((2023, 7, "input")));
+        assert_eq!(route("/2023/day/7"), Some((2023, 7, "")));
+        assert_eq!(route("/2023/day/7/answer"), Some((2023, 7, "answer")));
+        assert_eq!(route("/2023/week/7"), None);
+        assert_eq!(route("/favicon.ico"), None);
+    }
+
+    #[test]
+    fn t_form_field() {
+        let body = "level=2&answer=1234";
+        assert_eq!(form_field(body, "level"), Some("2"));
+        assert_eq!(form_field(body, "answer"), Some("1234"));
+        assert_eq!(form_field(body, "day"), None);
+    }
+}
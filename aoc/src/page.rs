@@ -0,0 +1,266 @@
+//! Puzzle pages saved from the website, turned into the statement at the
+//! top of each day and into its documentation. Statements are rendered as
+//! markdown, which still reads well as plain text in a comment.
+
+/// A piece of HTML.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    /// Opening tag, with only its name.
+    Open(&'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Splits HTML into tags and the text between them. Comments, doctypes
+/// and attributes are dropped. This is only meant for the handful of tags
+/// the puzzle pages use.
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        let Some(end) = rest[start..].find('>').map(|e| start + e) else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+        let tag = &rest[start + 1..end];
+        let name_end = tag
+            .find(|c: char| c.is_whitespace() || c == '/')
+            .unwrap_or(tag.len());
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !tag.starts_with('!') && name_end > 0 {
+            tokens.push(Token::Open(&tag[..name_end]));
+        }
+        rest = &rest[end + 1..];
+    }
+    tokens
+}
+
+/// Replaces the character references the pages use.
+pub fn decode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                code => {
+                    let code = code.strip_prefix('#')?;
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Greedily wraps the words of `text` into lines of at most `width`
+/// characters, where possible. The first line starts with `first`, and the
+/// rest with `indent`.
+pub fn wrap(text: &str, width: usize, first: &str, indent: &str) -> String {
+    let mut out = String::new();
+    let mut line = first.to_string();
+    let mut empty = true;
+    for word in text.split_whitespace() {
+        if !empty && line.chars().count() + 1 + word.chars().count() > width {
+            out.push_str(&line);
+            out.push('\n');
+            line = indent.to_string();
+            empty = true;
+        }
+        if !empty {
+            line.push(' ');
+        }
+        line.push_str(word);
+        empty = false;
+    }
+    out.push_str(&line);
+    out
+}
+
+/// Width the statements are wrapped to.
+pub const WIDTH: usize = 70;
+
+/// Escapes the characters of prose that markdown would take as markup.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('*', "\\*")
+        .replace('`', "\\`")
+}
+
+/// Inline code, with enough backticks around it for those inside.
+fn code_span(code: &str) -> String {
+    let fence = if code.contains('`') { "``" } else { "`" };
+    let pad = if code.starts_with('`') || code.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    format!("{fence}{pad}{code}{pad}{fence}")
+}
+
+/// The statement of both parts in a puzzle page, as markdown. Paragraphs
+/// are wrapped, emphasis and inline code are kept, code blocks are fenced,
+/// and list items become `* ` bullets. Headings are prefixed by `heading`.
+pub fn statement(html: &str, heading: &str) -> String {
+    /// The kinds of blocks, which decide how they are separated.
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Kind {
+        Heading,
+        Text,
+        Item,
+        Code,
+    }
+    /// Ends the current block.
+    fn finish(text: &mut String, blocks: &mut Vec<(String, Kind)>, kind: Kind, heading: &str) {
+        let block = std::mem::take(text);
+        match kind {
+            Kind::Code => blocks.push((format!("```\n{}\n```", block.trim_end()), kind)),
+            _ if block.trim().is_empty() => {}
+            Kind::Item => blocks.push((wrap(&block, WIDTH, "* ", "  "), kind)),
+            Kind::Heading => blocks.push((format!("{heading}{}", block.trim()), kind)),
+            Kind::Text => blocks.push((wrap(&block, WIDTH, "", ""), kind)),
+        }
+    }
+    let mut blocks = Vec::new();
+    let mut text = String::new();
+    let mut kind = Kind::Text;
+    let mut in_article = false;
+    // Inline code being read, and whether it is emphasised.
+    let mut code: Option<(String, bool)> = None;
+    for token in tokens(html) {
+        match token {
+            Token::Open("article") => in_article = true,
+            Token::Close("article") => {
+                finish(&mut text, &mut blocks, kind, heading);
+                (in_article, kind) = (false, Kind::Text);
+            }
+            _ if !in_article => {}
+            Token::Open("h2" | "p" | "ul" | "li" | "pre")
+            | Token::Close("h2" | "p" | "ul" | "li" | "pre") => {
+                finish(&mut text, &mut blocks, kind, heading);
+                kind = match token {
+                    Token::Open("h2") => Kind::Heading,
+                    Token::Open("li") => Kind::Item,
+                    Token::Open("pre") => Kind::Code,
+                    _ => Kind::Text,
+                };
+            }
+            Token::Text(t) if kind == Kind::Code => text.push_str(&decode(t)),
+            Token::Text(t) => match &mut code {
+                Some((code, _)) => code.push_str(&decode(t)),
+                None => text.push_str(&escape(&decode(t))),
+            },
+            _ if kind == Kind::Code => {}
+            Token::Open("code") => code = Some((String::new(), false)),
+            Token::Close("code") => {
+                if let Some((code, emphasised)) = code.take() {
+                    let span = code_span(&code);
+                    match emphasised {
+                        true => text.push_str(&format!("*{span}*")),
+                        false => text.push_str(&span),
+                    }
+                }
+            }
+            Token::Open("em") | Token::Close("em") => match &mut code {
+                Some((_, emphasised)) => *emphasised = true,
+                None => text.push('*'),
+            },
+            Token::Open(_) | Token::Close(_) => {}
+        }
+    }
+    let mut out = String::new();
+    for (i, (block, kind)) in blocks.iter().enumerate() {
+        if i > 0 {
+            // Items of a list are not separated by blank lines.
+            let items = *kind == Kind::Item && blocks[i - 1].1 == Kind::Item;
+            out.push_str(if items { "\n" } else { "\n\n" });
+        }
+        out.push_str(block);
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_statement() {
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Find the <em>sum</em> of the values &amp; the <code>x &lt; 3</code> ones, like 2*3.</p>
+<p>For example:</p>
+<pre><code>1abc2
+  a&gt;<em>b</em>
+
+c
+</code></pre>
+<ul><li>One item that is long enough to need wrapping onto a second line of text.</li><li>Two</li></ul>
+<p>This produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Again.</p></article>
+</main></body></html>"#;
+        assert_eq!(
+            statement(html, "## "),
+            r"## --- Day 1: Test ---
+
+Find the *sum* of the values & the `x < 3` ones, like 2\*3.
+
+For example:
+
+```
+1abc2
+  a>b
+
+c
+```
+
+* One item that is long enough to need wrapping onto a second line of
+  text.
+* Two
+
+This produces *`142`*.
+
+## --- Part Two ---
+
+Again."
+        );
+        assert!(statement(html, "").starts_with("--- Day 1: Test ---\n\nFind"));
+    }
+
+    #[test]
+    fn t_decode() {
+        assert_eq!(decode("&lt;&#39;&#x41;&gt; &amp;&foo; &"), "<'A> &&foo; &");
+    }
+}
@@ -0,0 +1,272 @@
+//! Source of a new day, and its registration in the crate of its year.
+
+const TEMPLATE: &str = r#"{header}
+
+use common::{random::Rng, ParseError, Solution, Unsolved};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+    type Answer1 = Unsolved;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<&str, ParseError> {
+        Ok(input.trim())
+    }
+
+    fn part_1(_input: &&str) -> Unsolved {
+        Unsolved
+    }
+
+    fn part_2(_input: &&str) -> Unsolved {
+        Unsolved
+    }
+
+    fn generate(_rng: &mut Rng, _size: usize) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_part_1() {
+        Solver::solve_1(EXAMPLE);
+        if let Some(input) = common::input::for_test({year}, {day}) {
+            Solver::solve_1(&input);
+        }
+    }
+
+    #[test]
+    fn t_part_2() {
+        Solver::solve_2(EXAMPLE);
+        if let Some(input) = common::input::for_test({year}, {day}) {
+            Solver::solve_2(&input);
+        }
+    }
+
+    const EXAMPLE: &str = "{example}";
+}
+"#;
+
+/// The comment at the top of a day, holding its statement. `opener` is
+/// `/*`, or `/*!` for an inner doc comment. Block comments nest, so both
+/// their ends are broken up in the statement.
+fn header(opener: &str, statement: &str) -> String {
+    let statement = statement.trim().replace("*/", "* /").replace("/*", "/ *");
+    format!("{opener}\n{statement}\n */")
+}
+
+/// Length of the block comment at the start of `source`, along with any
+/// comments nested in it.
+fn comment_len(source: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = 0;
+    while i < source.len() {
+        if source[i..].starts_with("/*") {
+            depth += 1;
+            i += 2;
+        } else if source[i..].starts_with("*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return Some(i);
+            }
+        } else {
+            i += source[i..].chars().next()?.len_utf8();
+        }
+    }
+    None
+}
+
+/// Replaces the statement at the top of a day's source, or adds one if the
+/// source starts with no comment.
+pub fn with_statement(source: &str, statement: &str) -> String {
+    let comment = source.strip_prefix("/*").and_then(|rest| {
+        let opener = if rest.starts_with('!') { "/*!" } else { "/*" };
+        Some((opener, comment_len(source)?))
+    });
+    match comment {
+        Some((opener, end)) => header(opener, statement) + &source[end..],
+        None => header("/*", statement) + "\n\n" + source,
+    }
+}
+
+/// Source of a day that solves nothing yet. The statement goes in the
+/// header comment, and the example in the `EXAMPLE` constant of the tests.
+pub fn day_source(year: u32, day: u32, statement: Option<&str>, example: Option<&str>) -> String {
+    let statement = statement
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| format!("--- Day {day} ---"));
+    let example = example
+        .map(|e| {
+            format!(
+                "\n{}\n",
+                e.trim_end().replace('\\', "\\\\").replace('"', "\\\"")
+            )
+        })
+        .unwrap_or_default();
+    TEMPLATE
+        .replace("{header}", &header("/*", &statement))
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{example}", &example)
+}
+
+/// Number of the day in a line such as `pub mod day_7;`, or
+/// `Day::new::<day_7::Solver>(7),` when `registry` is set.
+fn line_day(line: &str, registry: bool) -> Option<u32> {
+    let line = line.trim();
+    let number = if registry {
+        line.strip_prefix("Day::new::<day_")?
+            .split_once("::Solver>")?
+            .0
+    } else {
+        line.strip_prefix("pub mod day_")?.strip_suffix(';')?
+    };
+    number.parse().ok()
+}
+
+/// Replaces the lines of days from the first to the last, along with any
+/// blank lines among them, by the lines of `days` in order. rustfmt sorts
+/// modules by name within a group, which would put day 10 before day 2, so
+/// the modules of days 10 and up are kept in a group of their own.
+fn sorted_lines(lines: &mut Vec<String>, days: &[u32], registry: bool) -> Result<(), String> {
+    let what = if registry { "registry" } else { "modules" };
+    let found: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| line_day(l, registry).is_some())
+        .map(|(i, _)| i)
+        .collect();
+    let (Some(&first), Some(&last)) = (found.first(), found.last()) else {
+        return Err(format!("Cannot find the {what} of the days"));
+    };
+    if lines[first..=last]
+        .iter()
+        .any(|l| !l.trim().is_empty() && line_day(l, registry).is_none())
+    {
+        return Err(format!("The {what} of the days are not next to each other"));
+    }
+    let indent: String = lines[first]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    let mut sorted = Vec::new();
+    for (i, day) in days.iter().enumerate() {
+        if registry {
+            sorted.push(format!("{indent}Day::new::<day_{day}::Solver>({day}),"));
+            continue;
+        }
+        if i > 0 && days[i - 1] < 10 && *day >= 10 {
+            sorted.push(String::new());
+        }
+        sorted.push(format!("{indent}pub mod day_{day};"));
+    }
+    lines.splice(first..=last, sorted);
+    Ok(())
+}
+
+/// Adds the day to the modules and the registry in the `lib.rs` of a year.
+/// Both lists are sorted by day, whatever order they were in.
+pub fn register(lib: &str, day: u32) -> Result<String, String> {
+    let mut days: Vec<_> = lib.lines().filter_map(|l| line_day(l, false)).collect();
+    if days.contains(&day) {
+        return Err(format!("Day {day} is already a module"));
+    }
+    days.push(day);
+    days.sort();
+    let mut lines: Vec<_> = lib.lines().map(|l| l.to_string()).collect();
+    sorted_lines(&mut lines, &days, false)?;
+    sorted_lines(&mut lines, &days, true)?;
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_register() {
+        let lib = "use common::{Day, Registry};
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_2;
+
+pub mod day_3;
+
+#[cfg(test)]
+mod examples;
+
+pub const REGISTRY: Registry = Registry {
+    year: 2023,
+    days: &[
+        Day::new::<day_1::Solver>(1),
+        Day::new::<day_2::Solver>(2),
+        Day::new::<day_3::Solver>(3),
+        Day::new::<day_10::Solver>(10),
+    ],
+};
+";
+        assert_eq!(
+            register(lib, 4).unwrap(),
+            "use common::{Day, Registry};
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+
+pub mod day_10;
+
+#[cfg(test)]
+mod examples;
+
+pub const REGISTRY: Registry = Registry {
+    year: 2023,
+    days: &[
+        Day::new::<day_1::Solver>(1),
+        Day::new::<day_2::Solver>(2),
+        Day::new::<day_3::Solver>(3),
+        Day::new::<day_4::Solver>(4),
+        Day::new::<day_10::Solver>(10),
+    ],
+};
+"
+        );
+        assert!(register(lib, 2).is_err());
+        assert!(register("pub mod day_1;\nfn f() {}\npub mod day_2;\n", 3).is_err());
+    }
+
+    #[test]
+    fn t_day_source() {
+        let source = day_source(2024, 7, Some("--- Day 7: Test ---"), Some("a \"b\"\n"));
+        assert!(source.starts_with("/*\n--- Day 7: Test ---\n */\n"));
+        assert!(source.contains("common::input::for_test(2024, 7)"));
+        assert!(source.contains("const EXAMPLE: &str = \"\na \\\"b\\\"\n\";"));
+        assert!(day_source(2024, 7, None, None).contains("const EXAMPLE: &str = \"\";"));
+    }
+
+    #[test]
+    fn t_with_statement() {
+        let statement = "--- Day 7 ---\n\nAll */ of /* it.";
+        let expected = "/*\n--- Day 7 ---\n\nAll * / of / * it.\n */\n\nuse common;\n";
+        assert_eq!(
+            with_statement("/*\nOld.\n*/\n\nuse common;\n", statement),
+            expected
+        );
+        assert_eq!(with_statement("/**/\n\nuse common;\n", statement), expected);
+        // The old statement has a comment of its own.
+        assert_eq!(
+            with_statement("/*\nOld /* nested */ one.\n*/\n\nuse common;\n", statement),
+            expected
+        );
+        assert_eq!(with_statement("use common;\n", statement), expected);
+        assert!(with_statement("/*!\nOld.\n */\nuse common;\n", statement)
+            .starts_with("/*!\n--- Day 7"));
+    }
+}
@@ -0,0 +1,128 @@
+//! Progress of a year, from what its registry implements and what its
+//! answers file records. Nothing is solved to find it out, `verify` is for
+//! checking the answers.
+
+use crate::answers::Answers;
+use common::Registry;
+use serde_json::{json, Value};
+
+/// What is known about one part of a puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Star {
+    /// Implemented, with an answer to verify it against.
+    Solved,
+    /// Implemented, but with no answer on record.
+    Unverified,
+    Unimplemented,
+}
+
+impl Star {
+    pub const ALL: [Star; 3] = [Star::Solved, Star::Unverified, Star::Unimplemented];
+
+    /// How the part is drawn in the calendar.
+    pub fn symbol(self) -> char {
+        match self {
+            Star::Solved => '*',
+            Star::Unverified => '?',
+            Star::Unimplemented => '.',
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Star::Solved => "solved",
+            Star::Unverified => "unverified",
+            Star::Unimplemented => "unimplemented",
+        }
+    }
+}
+
+/// Both parts of every day of a year.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Status {
+    pub year: u32,
+    /// Indexed by the day, starting from day 1.
+    pub days: [[Star; 2]; 25],
+}
+
+impl Status {
+    pub fn new(registry: &Registry, answers: &Answers) -> Status {
+        let mut days = [[Star::Unimplemented; 2]; 25];
+        for (day, parts) in (1..).zip(&mut days) {
+            for (part, star) in (1..).zip(parts) {
+                if !registry.get(day).is_some_and(|d| d.is_solved(part)) {
+                    continue;
+                }
+                *star = match answers.get(day, part) {
+                    Some(_) => Star::Solved,
+                    None => Star::Unverified,
+                };
+            }
+        }
+        Status {
+            year: registry.year,
+            days,
+        }
+    }
+
+    /// Number of parts with the given star.
+    pub fn count(&self, star: Star) -> usize {
+        self.days.iter().flatten().filter(|s| **s == star).count()
+    }
+
+    /// The year as a calendar of five weeks of five days, each with the
+    /// symbols of its two parts, under a line with the counts.
+    pub fn calendar(&self) -> String {
+        let counts: Vec<_> = Star::ALL
+            .iter()
+            .map(|s| format!("{} {}", self.count(*s), s.name()))
+            .collect();
+        let mut out = format!("{}: {}\n", self.year, counts.join(", "));
+        for week in (1..).zip(&self.days).collect::<Vec<_>>().chunks(5) {
+            let cells: Vec<_> = week
+                .iter()
+                .map(|(day, [one, two])| format!("{day:>2} {}{}", one.symbol(), two.symbol()))
+                .collect();
+            out.push_str(&cells.join("   "));
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> Value {
+        let days: Vec<_> = (1..)
+            .zip(&self.days)
+            .map(
+                |(day, [one, two])| json!({"day": day, "part_1": one.name(), "part_2": two.name()}),
+            )
+            .collect();
+        let mut value = json!({"year": self.year, "days": days});
+        for star in Star::ALL {
+            value[star.name()] = json!(self.count(star));
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_status() {
+        let answers = Answers::parse("[day_1]\npart_1 = 1\n\n[day_21]\npart_1 = 1\npart_2 = 2\n");
+        let status = Status::new(&adventofcode_2023::REGISTRY, &answers.unwrap());
+        assert_eq!(status.days[0], [Star::Solved, Star::Unverified]);
+        // Day 21 only has its first part.
+        assert_eq!(status.days[20], [Star::Solved, Star::Unimplemented]);
+        assert_eq!(status.days[24], [Star::Unimplemented; 2]);
+        let calendar = status.calendar();
+        assert!(calendar.starts_with("2023: 2 solved, "));
+        assert!(calendar.contains("\n 1 *?    2 ??    3 ??"));
+        assert!(calendar.ends_with("\n21 *.   22 ..   23 ..   24 ..   25 ..\n"));
+        let json = status.to_json();
+        assert_eq!(json["year"], 2023);
+        assert_eq!(json["solved"], 2);
+        assert_eq!(json["days"][20]["part_2"], "unimplemented");
+    }
+}
@@ -0,0 +1,176 @@
+//! Local log of the answers submitted to the server, so that an answer the
+//! server already rejected is never sent again. Each line of the log holds
+//! the year, the day, the part, the verdict and the answer, separated by
+//! tabs.
+
+use crate::client::Verdict;
+use common::Answer;
+use std::{fs, io::Write, path::PathBuf};
+
+/// A submitted answer and the verdict it got.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+fn verdict_name(verdict: Verdict) -> Option<&'static str> {
+    match verdict {
+        Verdict::Right => Some("right"),
+        Verdict::Wrong => Some("wrong"),
+        Verdict::TooHigh => Some("too-high"),
+        Verdict::TooLow => Some("too-low"),
+        // Nothing is learned about the answer from these.
+        Verdict::Wait(_) | Verdict::WrongLevel => None,
+    }
+}
+
+fn parse_line(line: &str) -> Option<Submission> {
+    let mut fields = line.splitn(5, '\t');
+    let mut number = || fields.next()?.parse().ok();
+    let (year, day, part) = (number()?, number()?, number()?);
+    let verdict = match fields.next()? {
+        "right" => Verdict::Right,
+        "wrong" => Verdict::Wrong,
+        "too-high" => Verdict::TooHigh,
+        "too-low" => Verdict::TooLow,
+        _ => return None,
+    };
+    Some(Submission {
+        year,
+        day,
+        part,
+        verdict,
+        answer: fields.next()?.to_string(),
+    })
+}
+
+pub struct Submissions {
+    path: PathBuf,
+    entries: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Reads the log at `path`. A missing log is an empty one.
+    pub fn load(path: PathBuf) -> Result<Submissions, String> {
+        let entries = match fs::read_to_string(&path) {
+            Ok(text) => text
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(i, line)| {
+                    parse_line(line)
+                        .ok_or_else(|| format!("{}:{}: malformed entry", path.display(), i + 1))
+                })
+                .collect::<Result<_, _>>()?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("Cannot read {}: {e}", path.display())),
+        };
+        Ok(Submissions { path, entries })
+    }
+
+    /// Previous submissions to the given part, oldest first.
+    pub fn of(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Submission> {
+        self.entries
+            .iter()
+            .filter(move |s| (s.year, s.day, s.part) == (year, day, part))
+    }
+
+    /// Checks the answer against what the server already said about the
+    /// part. Besides answers that were rejected outright, numeric answers
+    /// are refused when a previous answer that was too high or too low
+    /// already rules them out.
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &Answer) -> Result<(), String> {
+        for prev in self.of(year, day, part) {
+            let prev_answer = Answer::parse(&prev.answer);
+            let bound = answer.as_int().zip(prev_answer.as_int());
+            match prev.verdict {
+                Verdict::Right => {
+                    return Err(format!("Already solved, the answer was {}", prev.answer))
+                }
+                _ if prev_answer == *answer => {
+                    return Err(format!("{answer} was already rejected"));
+                }
+                Verdict::TooHigh if bound.is_some_and(|(v, p)| v >= p) => {
+                    return Err(format!("{} was too high already", prev.answer));
+                }
+                Verdict::TooLow if bound.is_some_and(|(v, p)| v <= p) => {
+                    return Err(format!("{} was too low already", prev.answer));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends a submission to the log. Verdicts that say nothing about the
+    /// answer are not kept.
+    pub fn record(&mut self, submission: Submission) -> Result<(), String> {
+        let Some(name) = verdict_name(submission.verdict) else {
+            return Ok(());
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Cannot create {}: {e}", dir.display()))?;
+        }
+        let Submission {
+            year,
+            day,
+            part,
+            answer,
+            ..
+        } = &submission;
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{year}\t{day}\t{part}\t{name}\t{answer}"))
+            .map_err(|e| format!("Cannot write {}: {e}", self.path.display()))?;
+        self.entries.push(submission);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    fn submission(part: u32, verdict: Verdict, answer: &str) -> Submission {
+        Submission {
+            year: 2023,
+            day: 1,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn t_submissions() {
+        let path = env::temp_dir().join(format!("aoc-{}-submissions.log", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut log = Submissions::load(path.clone()).unwrap();
+        assert_eq!(log.check(2023, 1, 1, &Answer::from("100")), Ok(()));
+        log.record(submission(1, Verdict::TooHigh, "100")).unwrap();
+        log.record(submission(1, Verdict::TooLow, "10")).unwrap();
+        log.record(submission(1, Verdict::Wrong, "abc")).unwrap();
+        log.record(submission(1, Verdict::Wait(None), "50"))
+            .unwrap();
+        // The log survives a reload.
+        let mut log = Submissions::load(path.clone()).unwrap();
+        assert_eq!(log.of(2023, 1, 1).count(), 3);
+        assert!(log.check(2023, 1, 1, &Answer::from(100usize)).is_err());
+        assert!(log.check(2023, 1, 1, &Answer::from("120")).is_err());
+        assert!(log.check(2023, 1, 1, &Answer::from("7")).is_err());
+        assert!(log.check(2023, 1, 1, &Answer::from("abc")).is_err());
+        assert_eq!(log.check(2023, 1, 1, &Answer::from("50")), Ok(()));
+        // Other parts are unaffected.
+        assert_eq!(log.check(2023, 1, 2, &Answer::from("100")), Ok(()));
+        log.record(submission(1, Verdict::Right, "50")).unwrap();
+        assert!(log.check(2023, 1, 1, &Answer::from("51")).is_err());
+        let _ = fs::remove_file(&path);
+    }
+}
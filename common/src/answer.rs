@@ -0,0 +1,153 @@
+//! One type for the answers of every day, whatever each solver returns, so
+//! they can be printed, checked and submitted the same way.
+
+use crate::ocr;
+use std::fmt::{self, Display};
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    /// Whole numbers, whether the solver returned them signed or not.
+    Int(i128),
+    /// A single line of text, such as the crates on top of the stacks.
+    Text(String),
+    /// Several lines of text that draw a picture, such as letters on a
+    /// screen, to be read by eye.
+    Art(String),
+}
+
+impl Answer {
+    /// Reads an answer from text, as a number if it is written the way a
+    /// number is printed, so that `0123` stays text.
+    pub fn parse(text: &str) -> Answer {
+        let text = text.trim_matches(['\n', '\r']);
+        let number = text.trim();
+        if let Some(n) = number
+            .parse::<i128>()
+            .ok()
+            .filter(|n| n.to_string() == number)
+        {
+            Answer::Int(n)
+        } else if text.contains('\n') {
+            Answer::Art(text.to_string())
+        } else {
+            Answer::Text(number.to_string())
+        }
+    }
+
+    /// The answer as it would be typed in, with the letters of a picture
+    /// read off it.
+    pub fn read(&self) -> Result<Answer, ocr::UnknownGlyph> {
+        match self {
+            Answer::Art(art) => ocr::read(art).map(Answer::Text),
+            answer => Ok(answer.clone()),
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Answer::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Lines of a picture, without trailing whitespace, which screens
+    /// don't show.
+    fn art_lines(art: &str) -> impl Iterator<Item = &str> {
+        art.lines().map(str::trim_end)
+    }
+}
+
+impl PartialEq for Answer {
+    /// Numbers are equal by value, so `5usize` and `5i64` are the same
+    /// answer. Pictures are equal line by line, ignoring trailing spaces,
+    /// and equal to text that reads the same as the letters they draw.
+    fn eq(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Art(a), Answer::Art(b)) => Answer::art_lines(a).eq(Answer::art_lines(b)),
+            (Answer::Art(art), Answer::Text(text)) | (Answer::Text(text), Answer::Art(art)) => {
+                ocr::read(art).is_ok_and(|letters| letters == *text)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(text) | Answer::Art(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(u32, u64, usize, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::parse(&text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::parse(text)
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Answer {
+        answer.map_or_else(|| Answer::Text("none".to_string()), Into::into)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_answer() {
+        assert_eq!(Answer::from(5usize), Answer::from(5i64));
+        assert_eq!(Answer::from(-3isize), Answer::parse(" -3\n"));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::parse("18446744073709551615")
+        );
+        assert_ne!(Answer::from(1usize), Answer::from(-1i64));
+        assert_eq!(Answer::parse("CMZ"), Answer::Text("CMZ".to_string()));
+        assert_ne!(Answer::parse("CMZ"), Answer::parse("cmz"));
+        assert_eq!(Answer::parse("0123"), Answer::Text("0123".to_string()));
+        assert_ne!(Answer::parse("+5"), Answer::from(5usize));
+        assert_eq!(Answer::from(Some(5usize)), Answer::from(5i64));
+        assert_eq!(Answer::from(None::<usize>).to_string(), "none");
+        let art = Answer::from("#..#\n####".to_string());
+        assert!(matches!(art, Answer::Art(_)));
+        assert_eq!(art, Answer::parse("\n#..#  \n####\n"));
+        assert_ne!(art, Answer::parse("#..#\n#..#"));
+        assert_eq!(art.to_string(), "#..#\n####");
+        assert_eq!(Answer::from(12usize).as_int(), Some(12));
+        let letters = ".##..###.\n#..#.#..#\n#..#.###.\n####.#..#\n#..#.#..#\n#..#.###.";
+        assert_eq!(Answer::parse(letters), Answer::parse("AB"));
+        assert_eq!(Answer::parse(letters).read(), Ok(Answer::parse("AB")));
+        assert_ne!(art, Answer::parse("AB"));
+        assert!(art.read().is_err());
+        assert_eq!(Answer::parse("12a").as_int(), None);
+    }
+}
@@ -0,0 +1,388 @@
+//! Parser combinators for the input formats of the puzzles: numbers, lists,
+//! lines, sections separated by blank lines and records of keys and values.
+//!
+//! A parser is a function that takes the text left to parse, and returns
+//! what it parsed along with the text after it. Parsers are built by
+//! combining smaller ones, and a whole input is parsed with `parse_all`.
+//! Errors point at the slice of the input where the parser got stuck, so
+//! they are located in the input like any other `ParseError`.
+
+use crate::{parse::Expected, ParseError};
+use std::{str::FromStr, sync::Arc};
+
+/// What a parser parsed, and the rest of the text after it.
+pub type PResult<'a, T> = Result<(T, &'a str), ParseError>;
+
+/// Anything that can be used as a parser, which is any function from the
+/// text to parse to a `PResult`.
+pub trait Parser<'a, T>: Fn(&'a str) -> PResult<'a, T> {}
+
+impl<'a, T, F: Fn(&'a str) -> PResult<'a, T>> Parser<'a, T> for F {}
+
+/// Parses all of `input` with `parser`, apart from surrounding whitespace.
+pub fn parse_all<'a, T>(parser: impl Parser<'a, T>, input: &'a str) -> Result<T, ParseError> {
+    let (value, rest) = parser(input.trim())?;
+    if rest.is_empty() {
+        Ok(value)
+    } else {
+        Err(ParseError::at(rest.trim_start(), "the end of the input"))
+    }
+}
+
+/// Exactly the text `prefix`.
+pub fn tag<'a>(prefix: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(prefix) {
+        Some(rest) => Ok((&input[..prefix.len()], rest)),
+        None => Err(ParseError::at(input, Expected::Literal(prefix))),
+    }
+}
+
+/// The longest prefix of characters that satisfy `pred`, which can be
+/// empty.
+pub fn take_while<'a>(pred: impl Fn(char) -> bool) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input.find(|c| !pred(c)).unwrap_or(input.len());
+        Ok(input.split_at(end))
+    }
+}
+
+/// Like `take_while`, but fails unless there is at least one character.
+/// `expected` describes the characters.
+pub fn token<'a>(pred: impl Fn(char) -> bool, expected: &'static str) -> impl Parser<'a, &'a str> {
+    let take = take_while(pred);
+    move |input: &'a str| match take(input)? {
+        ("", _) => Err(ParseError::at(input, expected)),
+        taken => Ok(taken),
+    }
+}
+
+/// A line break, or the end of the text.
+pub fn eol<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix('\n') {
+        Some(rest) => Ok((&input[..1], rest)),
+        None if input.is_empty() => Ok((input, input)),
+        None => Err(ParseError::at(input, "the end of the line")),
+    }
+}
+
+/// A number without a sign.
+pub fn unsigned<'a, T: FromStr>() -> impl Parser<'a, T> {
+    let digits = token(|c| c.is_ascii_digit(), "a number");
+    move |input: &'a str| {
+        let (digits, rest) = digits(input)?;
+        let n = digits
+            .parse()
+            .map_err(|_| ParseError::at(input, "a number"))?;
+        Ok((n, rest))
+    }
+}
+
+/// A number with an optional minus sign.
+pub fn signed<'a, T: FromStr>() -> impl Parser<'a, T> {
+    let digits = token(|c| c.is_ascii_digit(), "a number");
+    move |input: &'a str| {
+        let (_, rest) = digits(input.strip_prefix('-').unwrap_or(input))?;
+        let n = input[..input.len() - rest.len()]
+            .parse()
+            .map_err(|_| ParseError::at(input, "a number"))?;
+        Ok((n, rest))
+    }
+}
+
+/// The value of the first of `options` whose text comes next. The error
+/// lists all the options.
+pub fn choice<'a, T: Clone, const N: usize>(options: [(&'static str, T); N]) -> impl Parser<'a, T> {
+    let expected: Arc<str> = match options.split_last() {
+        Some(((last, _), [])) => last.to_string(),
+        Some(((last, _), rest)) => {
+            let rest: Vec<_> = rest.iter().map(|(text, _)| *text).collect();
+            format!("{} or {last}", rest.join(", "))
+        }
+        None => String::from("nothing"),
+    }
+    .into();
+    move |input: &'a str| {
+        options
+            .iter()
+            .find_map(|(text, value)| Some((value.clone(), input.strip_prefix(text)?)))
+            .ok_or_else(|| ParseError::at(input, Expected::Owned(expected.clone())))
+    }
+}
+
+/// What `parser` parsed, converted with `f`.
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: &'a str| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// What `parser` parsed, converted with `f`, which fails with `expected`
+/// at the start of the parsed text if `f` returns `None`.
+pub fn try_map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    expected: &'static str,
+    f: impl Fn(T) -> Option<U>,
+) -> impl Parser<'a, U> {
+    move |input: &'a str| {
+        let (value, rest) = parser(input)?;
+        let value = f(value).ok_or_else(|| ParseError::at(input, expected))?;
+        Ok((value, rest))
+    }
+}
+
+/// What `first` parses, or if it fails, what `second` parses.
+pub fn or<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: &'a str| first(input).or_else(|_| second(input))
+}
+
+/// What `parser` parses, or `None` without consuming anything if it fails.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: &'a str| match parser(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// `first` followed by `second`.
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first(input)?;
+        let (b, rest) = second(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// `first` followed by `parser`, keeping only what `parser` parsed.
+pub fn preceded<'a, A, T>(
+    first: impl Parser<'a, A>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    map(pair(first, parser), |(_, value)| value)
+}
+
+/// `parser` followed by `last`, keeping only what `parser` parsed.
+pub fn terminated<'a, T, B>(
+    parser: impl Parser<'a, T>,
+    last: impl Parser<'a, B>,
+) -> impl Parser<'a, T> {
+    map(pair(parser, last), |(value, _)| value)
+}
+
+/// `parser` between `open` and `close`, such as a list in brackets.
+pub fn delimited<'a, A, T, B>(
+    open: impl Parser<'a, A>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, B>,
+) -> impl Parser<'a, T> {
+    preceded(open, terminated(parser, close))
+}
+
+/// One or more of what `parser` parses, separated by `sep`.
+pub fn separated<'a, T>(parser: impl Parser<'a, T>, sep: &'static str) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = parser(input)?;
+        let mut items = vec![first];
+        while let Some(next) = rest.strip_prefix(sep) {
+            let (item, after) = parser(next)?;
+            items.push(item);
+            rest = after;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// Records such as `x=1,y=2`, as the list of keys and values. `assign` is
+/// the text between a key and its value, and `sep` the text between two
+/// fields.
+pub fn record<'a, K, V>(
+    key: impl Parser<'a, K>,
+    assign: &'static str,
+    value: impl Parser<'a, V>,
+    sep: &'static str,
+) -> impl Parser<'a, Vec<(K, V)>> {
+    separated(pair(terminated(key, tag(assign)), value), sep)
+}
+
+/// One or more lines, each of them all parsed by `parser`, up to a blank
+/// line or the end of the text. The line break after the last line is left
+/// in the rest.
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let mut items = Vec::new();
+        let mut rest = input;
+        loop {
+            let (item, after) = parser(rest)?;
+            items.push(item);
+            match after.strip_prefix('\n') {
+                Some(next) if !next.is_empty() && !next.starts_with('\n') => rest = next,
+                _ if after.is_empty() || after.starts_with('\n') => return Ok((items, after)),
+                _ => return Err(ParseError::at(after, "the end of the line")),
+            }
+        }
+    }
+}
+
+/// One or more sections, each parsed by `parser`, separated by blank lines.
+pub fn sections<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let mut items = Vec::new();
+        let mut rest = input;
+        loop {
+            let (item, after) = parser(rest)?;
+            items.push(item);
+            let next = after.trim_start_matches('\n');
+            if next.is_empty() || next.len() == after.len() {
+                return Ok((items, after));
+            }
+            rest = next;
+        }
+    }
+}
+
+/// Every match of `parser` in the text, skipping over anything it doesn't
+/// match. Matches don't overlap. Never fails, and consumes all the text.
+pub fn scan<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let mut items = Vec::new();
+        let mut rest = input;
+        while let Some(c) = rest.chars().next() {
+            match parser(rest) {
+                Ok((item, after)) if after.len() < rest.len() => {
+                    items.push(item);
+                    rest = after;
+                }
+                _ => rest = &rest[c.len_utf8()..],
+            }
+        }
+        Ok((items, rest))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    /// The system allocator, counting the allocations of each thread so
+    /// that tests running in parallel don't see each other's.
+    struct Counting;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    fn allocations() -> usize {
+        ALLOCATIONS.with(Cell::get)
+    }
+
+    #[test]
+    fn t_basic() {
+        assert_eq!(unsigned::<u32>()("12ab"), Ok((12, "ab")));
+        assert_eq!(signed::<i64>()("-12, 3"), Ok((-12, ", 3")));
+        assert_eq!(signed::<i64>()("7"), Ok((7, "")));
+        assert!(unsigned::<u32>()("-1").is_err());
+        assert!(unsigned::<u8>()("300").is_err());
+        assert_eq!(tag("ab")("abc"), Ok(("ab", "c")));
+        assert_eq!(tag("ab")("b").unwrap_err().expected, "\"ab\"");
+        assert_eq!(take_while(|c| c == 'a')("bc"), Ok(("", "bc")));
+        assert_eq!(eol()("\nx"), Ok(("\n", "x")));
+        assert_eq!(eol()(""), Ok(("", "")));
+        assert!(eol()("x").is_err());
+        assert_eq!(token(char::is_alphabetic, "a word")("ab1"), Ok(("ab", "1")));
+        let color = choice([("red", 0), ("green", 1), ("blue", 2)]);
+        assert_eq!(color("blue!"), Ok((2, "!")));
+        assert_eq!(color("pink").unwrap_err().expected, "red, green or blue");
+        assert_eq!(opt(tag("x"))("y"), Ok((None, "y")));
+        let instruction = or(map(tag("do()"), |_| true), map(tag("don't()"), |_| false));
+        assert_eq!(instruction("don't()"), Ok((false, "")));
+        let digit = try_map(unsigned::<u32>(), "a digit", |n| (n < 10).then_some(n));
+        assert_eq!(digit("12").unwrap_err().expected, "a digit");
+    }
+
+    #[test]
+    fn t_structure() {
+        let list = delimited(tag("["), separated(signed::<i32>(), ","), tag("]"));
+        assert_eq!(list("[1,-2,3]"), Ok((vec![1, -2, 3], "")));
+        let fields = record(
+            token(char::is_alphabetic, "a key"),
+            "=",
+            unsigned::<u32>(),
+            ",",
+        );
+        assert_eq!(fields("x=1,yy=2}"), Ok((vec![("x", 1), ("yy", 2)], "}")));
+        let input = "1 2\n3 4\n\n5 6\n\n\n7 8";
+        let row = separated(unsigned::<u32>(), " ");
+        assert_eq!(
+            parse_all(sections(lines(&row)), input),
+            Ok(vec![
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![5, 6]],
+                vec![vec![7, 8]]
+            ])
+        );
+        assert_eq!(
+            scan(preceded(tag("n"), unsigned::<u32>()))("an1xn23n"),
+            Ok((vec![1, 23], ""))
+        );
+    }
+
+    #[test]
+    fn t_scan_failures_are_free() {
+        // `scan` tries its parser at every offset, so failed attempts must
+        // not allocate, or scanning a whole input gets slow.
+        let mul = preceded(
+            tag("mul("),
+            pair(
+                terminated(unsigned::<u32>(), tag(",")),
+                terminated(unsigned::<u32>(), tag(")")),
+            ),
+        );
+        let instruction = or(
+            map(mul, Some),
+            map(choice([("do()", ()), ("don't()", ())]), |_| None),
+        );
+        let input = "xmul(2,4]&mul[3,7]!^don_t()_mul(5,5+mul(32,64](mul(11,8do_";
+        let before = allocations();
+        let (items, _) = scan(&instruction)(input).unwrap();
+        assert_eq!(allocations() - before, 0);
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn t_errors() {
+        let input = "1 2\n3 x\n";
+        let row = separated(unsigned::<u32>(), " ");
+        let err = parse_all(lines(&row), input).unwrap_err().locate(input);
+        assert_eq!(err.to_string(), "line 2, column 3: expected a number");
+        let input = "1 2\n3 4;";
+        let err = parse_all(lines(&row), input).unwrap_err().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected the end of the line"
+        );
+        let input = "1 2\n\n3 4\n5";
+        let err = parse_all(lines(&row), input).unwrap_err().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected the end of the input"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! Cycle detection for simulations that are run for far more steps than
+//! could be simulated, such as a billion. The states must eventually
+//! repeat, after which the state at any step is one that was seen before.
+//!
+//! Every state is kept, indexed by its hash, so each step is taken only
+//! once. Steps are usually far more expensive than hashing the state, which
+//! makes this quicker than Brent's algorithm despite the memory. Steps
+//! update a state in place, so large states such as grids are not
+//! reallocated each step.
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// Where the states start repeating, and how often.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step of the cycle.
+    pub start: usize,
+    /// Number of steps after which the states repeat.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// States from `initial` on, up to the first repeat or step `limit`,
+/// whichever comes first, along with the cycle if it was found.
+fn walk<S: Clone + Eq + Hash>(
+    initial: &S,
+    mut step: impl FnMut(&mut S),
+    limit: usize,
+) -> (Vec<S>, Option<Cycle>) {
+    let mut states = vec![initial.clone()];
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut state = initial.clone();
+    while states.len() <= limit {
+        step(&mut state);
+        match seen.entry(state.clone()) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                let period = states.len() - start;
+                return (states, Some(Cycle { start, period }));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(states.len());
+                states.push(state.clone());
+            }
+        }
+    }
+    (states, None)
+}
+
+/// Finds the cycle of the states reached from `initial` by applying `step`
+/// over and over. Takes `start + period` steps, and never returns if the
+/// states don't repeat.
+pub fn find<S: Clone + Eq + Hash>(initial: &S, step: impl FnMut(&mut S)) -> Cycle {
+    walk(initial, step, usize::MAX)
+        .1
+        .expect("There is no end to the steps without a cycle")
+}
+
+/// The state after `n` steps from `initial`. Takes at most `start + period`
+/// steps whatever `n` is.
+pub fn nth<S: Clone + Eq + Hash>(initial: &S, step: impl FnMut(&mut S), n: usize) -> S {
+    let (mut states, cycle) = walk(initial, step, n);
+    let index = cycle.map_or(n, |c| c.index(n));
+    states.swap_remove(index)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_find() {
+        let at = |start, period| Cycle { start, period };
+        // 0, 1, 2, 3, 4, 5, 6, 2, 3, ...
+        let step = |x: &mut usize| *x = if *x == 6 { 2 } else { *x + 1 };
+        let cycle = find(&0, step);
+        assert_eq!(cycle, at(2, 5));
+        assert_eq!(cycle.index(1), 1);
+        assert_eq!(cycle.index(7), 2);
+        assert_eq!(cycle.index(13), 3);
+        assert_eq!(find(&3, |_: &mut usize| {}), at(0, 1));
+        assert_eq!(find(&0, |x: &mut usize| *x = (*x + 1) % 7), at(0, 7));
+    }
+
+    #[test]
+    fn t_nth() {
+        let step = |x: &mut u64| *x = (*x * *x + 1) % 1009;
+        let mut states = vec![2];
+        for i in 0..2000 {
+            let mut next = states[i];
+            step(&mut next);
+            states.push(next);
+        }
+        for n in [0, 1, 10, 100, 1999, 2000] {
+            assert_eq!(nth(&2, step, n), states[n]);
+        }
+        let cycle = find(&2, step);
+        assert_eq!(
+            nth(&2, step, 1_000_000_000),
+            states[cycle.index(1_000_000_000)]
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Directed graphs of named nodes, such as modules wired to each other or
+//! rules about which page comes first.
+//!
+//! Nodes are numbered in the order they are added, and their names are
+//! interned, so the algorithms work on plain indices and the names are only
+//! looked up while building the graph or printing it. Edges keep the order
+//! they were added in, and the same edge can be added more than once.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+    hash::Hash,
+};
+
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    names: Vec<N>,
+    indices: HashMap<N, usize>,
+    edges: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+/// A cycle that keeps the graph from being sorted, as nodes that each have
+/// an edge to the next, and the last one to the first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cyclic {
+    pub cycle: Vec<usize>,
+}
+
+impl Display for Cyclic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The graph has a cycle through the nodes")?;
+        for node in &self.cycle {
+            write!(f, " {node}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Cyclic {}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Graph {
+            names: Vec::new(),
+            indices: HashMap::new(),
+            edges: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+
+    /// Index of the node called `name`, which is added if it is not in the
+    /// graph yet.
+    pub fn node(&mut self, name: N) -> usize {
+        if let Some(&index) = self.indices.get(&name) {
+            return index;
+        }
+        let index = self.names.len();
+        self.indices.insert(name.clone(), index);
+        self.names.push(name);
+        self.edges.push(Vec::new());
+        self.incoming.push(Vec::new());
+        index
+    }
+
+    /// Index of the node called `name`, if there is one.
+    pub fn index(&self, name: &N) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn name(&self, node: usize) -> &N {
+        &self.names[node]
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        self.edges[from].push(to);
+        self.incoming[to].push(from);
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Nodes that `node` has edges to, in the order the edges were added.
+    pub fn edges(&self, node: usize) -> &[usize] {
+        &self.edges[node]
+    }
+
+    /// Nodes that have edges to `node`, in the order the edges were added.
+    pub fn incoming(&self, node: usize) -> &[usize] {
+        &self.incoming[node]
+    }
+
+    /// The graph of only the nodes called `names`, numbered in that order,
+    /// and the edges between them. Names that are not in this graph become
+    /// nodes without any edges.
+    pub fn subgraph(&self, names: &[N]) -> Graph<N> {
+        let mut graph = Graph::new();
+        for name in names {
+            graph.node(name.clone());
+        }
+        for from in 0..graph.len() {
+            let Some(node) = self.index(graph.name(from)) else {
+                continue;
+            };
+            for &to in &self.edges[node] {
+                if let Some(to) = graph.index(&self.names[to]) {
+                    graph.add_edge(from, to);
+                }
+            }
+        }
+        graph
+    }
+
+    /// Nodes ordered so that every edge goes from a node to a later one.
+    /// Among the nodes that could come next, the one added first does.
+    pub fn toposort(&self) -> Result<Vec<usize>, Cyclic> {
+        let mut waiting: Vec<usize> = self.incoming.iter().map(Vec::len).collect();
+        // Kept in reverse, so the smallest index is popped first.
+        let mut ready: Vec<usize> = (0..self.len()).rev().filter(|&n| waiting[n] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = ready.pop() {
+            order.push(node);
+            for &next in &self.edges[node] {
+                waiting[next] -= 1;
+                if waiting[next] == 0 {
+                    let at = ready.partition_point(|&n| n > next);
+                    ready.insert(at, next);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            return Ok(order);
+        }
+        // Every node left has an edge from another node left, so walking
+        // those edges backwards must come back to a node already seen.
+        let mut sorted = vec![false; self.len()];
+        for &node in &order {
+            sorted[node] = true;
+        }
+        let mut seen = vec![None; self.len()];
+        let mut walk = Vec::new();
+        let mut node = (0..self.len()).find(|&n| !sorted[n]).unwrap();
+        while seen[node].is_none() {
+            seen[node] = Some(walk.len());
+            walk.push(node);
+            node = *self.incoming[node].iter().find(|&&n| !sorted[n]).unwrap();
+        }
+        let mut cycle = walk.split_off(seen[node].unwrap());
+        cycle.reverse();
+        Err(Cyclic { cycle })
+    }
+
+    /// Strongly connected components, each a group of nodes that can all
+    /// reach each other. They are listed in reverse topological order, so no
+    /// component has an edge to one that comes after it.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        // Tarjan's algorithm, with the recursion on a stack of our own so
+        // long paths don't overflow the call stack.
+        let mut order: Vec<Option<usize>> = vec![None; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut count = 0;
+        for root in 0..self.len() {
+            if order[root].is_some() {
+                continue;
+            }
+            // Nodes being visited, with the next of their edges to follow.
+            let mut calls = vec![(root, 0)];
+            order[root] = Some(count);
+            low[root] = count;
+            count += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            while let Some(&(node, edge)) = calls.last() {
+                if let Some(&next) = self.edges[node].get(edge) {
+                    calls.last_mut().unwrap().1 += 1;
+                    match order[next] {
+                        None => {
+                            order[next] = Some(count);
+                            low[next] = count;
+                            count += 1;
+                            stack.push(next);
+                            on_stack[next] = true;
+                            calls.push((next, 0));
+                        }
+                        Some(o) if on_stack[next] => low[node] = low[node].min(o),
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+                if order[node] == Some(low[node]) {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// Whether each node can be reached from `start` by following edges.
+    /// `start` can always reach itself.
+    pub fn reachable(&self, start: usize) -> Vec<bool> {
+        let mut reached = vec![false; self.len()];
+        let mut stack = vec![start];
+        reached[start] = true;
+        while let Some(node) = stack.pop() {
+            for &next in &self.edges[node] {
+                if !reached[next] {
+                    reached[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        reached
+    }
+}
+
+impl<N: Clone + Eq + Hash + Display> Graph<N> {
+    /// The graph in the DOT language of Graphviz, to be drawn with something
+    /// like `dot -Tsvg`.
+    pub fn to_dot(&self) -> String {
+        let quote =
+            |node: usize| format!("\"{}\"", self.names[node].to_string().replace('"', "\\\""));
+        let mut dot = String::from("digraph {\n");
+        for node in 0..self.len() {
+            writeln!(dot, "    {};", quote(node)).unwrap();
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &to in edges {
+                writeln!(dot, "    {} -> {};", quote(from), quote(to)).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn graph(edges: &[(&'static str, &'static str)]) -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for &(from, to) in edges {
+            let (from, to) = (graph.node(from), graph.node(to));
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    #[test]
+    fn t_build() {
+        let g = graph(&[("a", "b"), ("a", "c"), ("c", "b"), ("a", "b")]);
+        assert_eq!(g.len(), 3);
+        assert_eq!(g.index(&"c"), Some(2));
+        assert_eq!(g.index(&"d"), None);
+        assert_eq!(*g.name(1), "b");
+        assert_eq!(g.edges(0), [1, 2, 1]);
+        assert_eq!(g.incoming(1), [0, 2, 0]);
+        assert_eq!(g.reachable(2), [false, true, true]);
+        let sub = g.subgraph(&["c", "b", "x"]);
+        assert_eq!(sub.len(), 3);
+        assert_eq!(sub.edges(0), [1]);
+        assert!(sub.edges(2).is_empty());
+        assert_eq!(
+            graph(&[("a", "b\"")]).to_dot(),
+            "digraph {\n    \"a\";\n    \"b\\\"\";\n    \"a\" -> \"b\\\"\";\n}\n"
+        );
+    }
+
+    #[test]
+    fn t_toposort() {
+        let g = graph(&[("d", "b"), ("c", "a"), ("a", "b"), ("e", "a")]);
+        // Nodes are d, b, c, a, e.
+        assert_eq!(g.toposort(), Ok(vec![0, 2, 4, 3, 1]));
+        let g = graph(&[("x", "a"), ("a", "b"), ("b", "c"), ("c", "a"), ("c", "y")]);
+        let Err(Cyclic { cycle }) = g.toposort() else {
+            panic!("Expecting a cycle");
+        };
+        let mut names: Vec<_> = cycle.iter().map(|&n| *g.name(n)).collect();
+        let first = names.iter().position(|&n| n == "a").unwrap();
+        names.rotate_left(first);
+        assert_eq!(names, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn t_components() {
+        let g = graph(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "d"),
+            ("f", "f"),
+        ]);
+        let components: Vec<Vec<&str>> = g
+            .components()
+            .into_iter()
+            .map(|c| {
+                let mut names: Vec<_> = c.iter().map(|&n| *g.name(n)).collect();
+                names.sort();
+                names
+            })
+            .collect();
+        assert_eq!(components, [vec!["d", "e"], vec!["a", "b", "c"], vec!["f"]]);
+        assert!(Graph::<u32>::new().components().is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+//! Puzzle inputs are kept out of the sources, as plain text files laid out
+//! as `<year>/day_<n>.txt` under an inputs directory. The answers for those
+//! inputs sit next to them in `<year>/answers.toml`.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable that overrides the inputs directory.
+pub const DIR_VAR: &str = "AOC_INPUTS";
+
+/// The inputs directory. This is `$AOC_INPUTS` if it is set, otherwise the
+/// `inputs` directory at the root of the workspace.
+pub fn dir() -> PathBuf {
+    match env::var_os(DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("The common crate must be inside the workspace")
+            .join("inputs"),
+    }
+}
+
+/// Path of the input file for the given day.
+pub fn path(year: u32, day: u32) -> PathBuf {
+    dir().join(year.to_string()).join(format!("day_{day}.txt"))
+}
+
+/// Path of the answers file for the given year.
+pub fn answers_path(year: u32) -> PathBuf {
+    dir().join(year.to_string()).join("answers.toml")
+}
+
+/// Reads the input for the given day.
+pub fn load(year: u32, day: u32) -> io::Result<String> {
+    fs::read_to_string(path(year, day))
+}
+
+/// Reads the input for the given day in a test. Returns `None` if the file
+/// doesn't exist, so the test can skip the checks that need it. Any other
+/// error fails the test.
+pub fn for_test(year: u32, day: u32) -> Option<String> {
+    let path = path(year, day);
+    match fs::read_to_string(&path) {
+        Ok(input) => Some(input),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            eprintln!("Skipping, {} not found", path.display());
+            None
+        }
+        Err(e) => panic!("Cannot read {}: {e}", path.display()),
+    }
+}
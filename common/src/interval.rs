@@ -0,0 +1,197 @@
+//! Sets of numbers stored as ranges, for puzzles about ranges far too large
+//! to handle one number at a time.
+//!
+//! The set keeps its ranges sorted, without overlaps, and merges ranges
+//! that touch, so there is exactly one way of storing every set. Ranges are
+//! half open, like `start..end`.
+
+use std::ops::{Add, Range, Sub};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// Adds every number in `range` to the set.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // Ranges from `first` up to `last` overlap or touch the new one.
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges of the set, in order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// The smallest number in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.contains(&value))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // The range that ends first can't overlap anything else.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The numbers in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+        for range in &self.ranges {
+            let mut start = range.start;
+            // Ranges of `other` that end before this one can't overlap any
+            // later ones either.
+            while others.next_if(|o| o.end <= range.start).is_some() {}
+            for cut in others.clone().take_while(|o| o.start < range.end) {
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Whether every number of `other` is in this set.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    /// Whether the two sets have any number in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T>> IntervalSet<T> {
+    /// Every number of the set moved up by `offset`.
+    pub fn shift(&self, offset: T) -> Self {
+        IntervalSet {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|r| r.start + offset..r.end + offset)
+                .collect(),
+        }
+    }
+}
+
+impl<T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>> IntervalSet<T> {
+    /// How many numbers the set covers.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::default(), |total, r| total + (r.end - r.start))
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn t_insert() {
+        let mut s = set(&[10..20, 0..5, 30..40]);
+        assert_eq!(s.ranges(), [0..5, 10..20, 30..40]);
+        // Touching ranges are merged, and so is everything in between.
+        s.insert(5..10);
+        assert_eq!(s.ranges(), [0..20, 30..40]);
+        s.insert(15..35);
+        assert_eq!(s, IntervalSet::from(0..40));
+        s.insert(3..3);
+        s.insert(-10..-5);
+        assert_eq!(s.ranges(), [-10..-5, 0..40]);
+        assert_eq!(s.len(), 45);
+        assert_eq!(s.min(), Some(-10));
+        assert!(s.contains(-10) && s.contains(39) && !s.contains(40) && !s.contains(-1));
+        assert_eq!(IntervalSet::<i64>::new().len(), 0);
+    }
+
+    #[test]
+    fn t_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        assert_eq!(a.union(&b), IntervalSet::from(0..40));
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), [10..20, 30..40]);
+        assert_eq!(
+            a.difference(&set(&[-5..0, 2..3, 4..6, 30..31])).ranges(),
+            [0..2, 3..4, 6..10, 20..30]
+        );
+        assert_eq!(a.shift(-5).ranges(), [-5..5, 15..25]);
+        assert!(a.overlaps(&b) && !a.overlaps(&IntervalSet::from(10..20)));
+        assert!(a.is_superset(&set(&[2..4, 20..30])) && !a.is_superset(&b));
+        assert!(a.is_superset(&IntervalSet::new()));
+    }
+}
@@ -0,0 +1,213 @@
+pub mod answer;
+pub mod combinator;
+pub mod cycle;
+pub mod graph;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod memo;
+pub mod ocr;
+pub mod parse;
+pub mod random;
+pub mod search;
+
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+pub use answer::Answer;
+pub use parse::ParseError;
+use random::Rng;
+
+/// Types that can be returned as the answer to one part of a puzzle.
+pub trait Output: Into<Answer> {
+    /// False for parts that don't have a working solution yet.
+    const SOLVED: bool = true;
+}
+
+impl Output for usize {}
+impl Output for i64 {}
+impl Output for isize {}
+impl Output for String {}
+
+/// No answer, for inputs that have none, such as a network where the
+/// ghosts never line up.
+impl<T: Output> Output for Option<T> {
+    const SOLVED: bool = T::SOLVED;
+}
+
+/// Answer of a part that has not been solved yet.
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+impl From<Unsolved> for Answer {
+    fn from(_: Unsolved) -> Answer {
+        Answer::Text(Unsolved.to_string())
+    }
+}
+
+impl Output for Unsolved {
+    const SOLVED: bool = false;
+}
+
+/// Solution to both parts of a day's puzzle. The input is parsed once and
+/// the parsed form is shared by both parts.
+pub trait Solution {
+    /// Parsed puzzle input, which may borrow from the input text.
+    type Parsed<'a>;
+    type Answer1: Output;
+    type Answer2: Output;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part_1(parsed: &Self::Parsed<'_>) -> Self::Answer1;
+
+    fn part_2(parsed: &Self::Parsed<'_>) -> Self::Answer2;
+
+    /// Random input in the format of the puzzle, which both parts must be
+    /// able to solve. `size` scales the input, as the number of lines or
+    /// the side of the map, or whatever grows the puzzle.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    /// Parses `input` and locates any error in it.
+    fn parse_located(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::parse(input).map_err(|e| e.locate(input))
+    }
+
+    /// Parses `input` and solves part 1. Panics if `input` is malformed.
+    fn solve_1(input: &str) -> Self::Answer1 {
+        Self::part_1(&Self::parse_located(input).unwrap_or_else(|e| panic!("{e}")))
+    }
+
+    /// Parses `input` and solves part 2. Panics if `input` is malformed.
+    fn solve_2(input: &str) -> Self::Answer2 {
+        Self::part_2(&Self::parse_located(input).unwrap_or_else(|e| panic!("{e}")))
+    }
+}
+
+/// Type erased entry of a registry, so days with different `Solution` types
+/// can be stored and run side by side.
+#[derive(Clone, Copy)]
+pub struct Day {
+    /// Day of the month, from 1 to 25.
+    pub day: u32,
+    solved: [bool; 2],
+    run: fn(&str, u32) -> Result<Timed, ParseError>,
+    bench: fn(&str, &mut dyn Timer) -> Result<(), ParseError>,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+/// The answer to a part, with how long it took to get.
+#[derive(Clone, Debug)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Measures the stages of a solution, see `Day::bench`.
+pub trait Timer {
+    /// Times `f`, which runs the stage named `stage` once per call.
+    fn time(&mut self, stage: &str, f: &mut dyn FnMut());
+}
+
+fn run<S: Solution>(input: &str, part: u32) -> Result<Timed, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse_located(input)?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part_1(&parsed).into(),
+        _ => S::part_2(&parsed).into(),
+    };
+    Ok(Timed {
+        answer,
+        parse,
+        solve: start.elapsed(),
+    })
+}
+
+fn bench<S: Solution>(input: &str, timer: &mut dyn Timer) -> Result<(), ParseError> {
+    let parsed = S::parse_located(input)?;
+    timer.time("parse", &mut || {
+        let _ = black_box(S::parse(black_box(input)));
+    });
+    if S::Answer1::SOLVED {
+        timer.time("part_1", &mut || {
+            black_box(S::part_1(black_box(&parsed)));
+        });
+    }
+    if S::Answer2::SOLVED {
+        timer.time("part_2", &mut || {
+            black_box(S::part_2(black_box(&parsed)));
+        });
+    }
+    Ok(())
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u32) -> Day {
+        Day {
+            day,
+            solved: [S::Answer1::SOLVED, S::Answer2::SOLVED],
+            run: run::<S>,
+            bench: bench::<S>,
+            generate: S::generate,
+        }
+    }
+
+    /// Whether `part` (1 or 2) has a working solution.
+    pub fn is_solved(&self, part: u32) -> bool {
+        matches!(part, 1 | 2) && self.solved[part as usize - 1]
+    }
+
+    /// Solves `part` for the given input. Returns `None` if that part is
+    /// not solved, and an error if the input is malformed.
+    pub fn solve(&self, input: &str, part: u32) -> Option<Result<Answer, ParseError>> {
+        self.solve_timed(input, part)
+            .map(|timed| timed.map(|t| t.answer))
+    }
+
+    /// Same as `solve`, also timing the parsing and the solving, each run
+    /// once.
+    pub fn solve_timed(&self, input: &str, part: u32) -> Option<Result<Timed, ParseError>> {
+        if self.is_solved(part) {
+            Some((self.run)(input, part))
+        } else {
+            None
+        }
+    }
+
+    /// Hands parsing and every solved part to `timer` separately, all on
+    /// the given input. Fails without timing anything if the input is
+    /// malformed.
+    pub fn bench(&self, input: &str, timer: &mut dyn Timer) -> Result<(), ParseError> {
+        (self.bench)(input, timer)
+    }
+
+    /// Random input of the given size, the same every time for the same
+    /// seed.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+/// All the days implemented for one year.
+pub struct Registry {
+    pub year: u32,
+    /// Sorted by day.
+    pub days: &'static [Day],
+}
+
+impl Registry {
+    pub fn get(&self, day: u32) -> Option<&Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
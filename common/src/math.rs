@@ -0,0 +1,150 @@
+//! Number theory that keeps coming up in puzzles about cycles and remainders.
+
+/// Greatest common divisor, using Euclid's algorithm.
+pub fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple of two numbers, or `None` if it doesn't fit in a
+/// `usize`.
+pub fn lcm(a: usize, b: usize) -> Option<usize> {
+    if a == 0 || b == 0 {
+        Some(0)
+    } else {
+        (a / gcd(a, b)).checked_mul(b)
+    }
+}
+
+/// Least common multiple of all the numbers. This is 1 if there are none,
+/// and `None` if it doesn't fit in a `usize`.
+pub fn lcm_all(nums: impl IntoIterator<Item = usize>) -> Option<usize> {
+    nums.into_iter().try_fold(1, lcm)
+}
+
+/// Extended Euclidean algorithm. Returns `(g, x, y)` such that `g` is the
+/// non-negative gcd of `a` and `b`, and `a * x + b * y == g`.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// Inverse of `a` modulo `m`, in the range `0..m`. Returns `None` if `a` and
+/// `m` are not coprime.
+pub fn modinv(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = egcd(a.rem_euclid(m), m);
+    if g == 1 {
+        Some(x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+/// Computes `base ^ exp mod m` by repeated squaring.
+pub fn modpow(base: u64, mut exp: u64, m: u64) -> u64 {
+    if m == 1 {
+        return 0;
+    }
+    let m = m as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1u128;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Chinese remainder theorem, generalized to moduli that need not be
+/// coprime. Each congruence is a `(residue, modulus)` pair, and the moduli
+/// must be positive. Returns `(x, m)` where `m` is the lcm of the moduli
+/// and `x` in `0..m` satisfies every congruence, or `None` if the
+/// congruences contradict each other or `m` doesn't fit in an `i64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0i64, 1i64), |(x, m), (a, n)| {
+            assert!(n > 0, "Moduli must be positive");
+            let a = a.rem_euclid(n);
+            let (g, p, _) = egcd(m, n);
+            let diff = a - x;
+            if diff % g != 0 {
+                return None;
+            }
+            // x + m * k satisfies both when m * k = diff (mod n).
+            let step = n / g;
+            let k = (diff / g) as i128 * p as i128 % step as i128;
+            let lcm = m as i128 * step as i128;
+            let x = (x as i128 + m as i128 * k).rem_euclid(lcm);
+            // x is below lcm, so it fits whenever lcm does.
+            Some((x as i64, i64::try_from(lcm).ok()?))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_gcd_lcm() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(usize::MAX, 2), None);
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm_all([1 << 62, 1 << 40, 2]), Some(1 << 62));
+        assert_eq!(lcm_all([1 << 62, 5, 1 << 40]), None);
+    }
+
+    #[test]
+    fn t_egcd() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5), (35, 64)] {
+            let (g, x, y) = egcd(a, b);
+            assert_eq!(
+                g,
+                gcd(a.unsigned_abs() as usize, b.unsigned_abs() as usize) as i64
+            );
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn t_modular() {
+        assert_eq!(modpow(4, 13, 497), 445);
+        assert_eq!(modpow(2, 0, 7), 1);
+        assert_eq!(modpow(u64::MAX, 2, u64::MAX - 1), 1);
+        assert_eq!(modinv(3, 11), Some(4));
+        assert_eq!(modinv(-3, 11), Some(7));
+        assert_eq!(modinv(6, 9), None);
+    }
+
+    #[test]
+    fn t_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli that share factors.
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        // The lcm of the moduli is past i64::MAX.
+        assert_eq!(crt([(0, i64::MAX), (1, 2)]), None);
+    }
+}
@@ -0,0 +1,91 @@
+//! Memoization of recursive functions, such as counting the ways to match a
+//! pattern, where the same arguments come up over and over.
+//!
+//! The function is written as usual, except that it takes a `Memo` and
+//! makes its recursive calls through it. Every result is cached by the
+//! memo, so the function can return from anywhere without having to
+//! remember to fill in the cache. The cache lives as long as the memo, so a
+//! new memo starts from scratch, and it counts how often it was hit.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// How often the results were found in the cache, and how often they had to
+/// be computed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+/// Cache for the results of `f`, keyed by its arguments. `f` must be a
+/// function, or a closure that doesn't capture anything, so that it can
+/// call the memo it is given.
+pub struct Memo<K, V> {
+    f: fn(&mut Memo<K, V>, K) -> V,
+    cache: HashMap<K, V>,
+    stats: Stats,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new(f: fn(&mut Memo<K, V>, K) -> V) -> Self {
+        Memo {
+            f,
+            cache: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+
+    /// Result of the function for `args`, computed only if it is not in the
+    /// cache yet.
+    pub fn call(&mut self, args: K) -> V {
+        if let Some(value) = self.cache.get(&args) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = (self.f)(self, args.clone());
+        self.cache.insert(args, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.call(n - 1) + memo.call(n - 2)
+    }
+
+    #[test]
+    fn t_memo() {
+        let mut memo = Memo::new(fibonacci);
+        assert_eq!(memo.call(90), 2880067194370816120);
+        // Every number up to 90 is computed once, and every other call is
+        // a hit.
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 88,
+                misses: 91
+            }
+        );
+        assert_eq!(memo.call(50), 12586269025);
+        assert_eq!(memo.stats().hits, 89);
+        // Borrowed arguments work as long as they outlive the memo.
+        let text = String::from("abcabc");
+        let mut memo = Memo::new(|memo: &mut Memo<&str, usize>, s: &str| match s.len() {
+            0 => 0,
+            _ => memo.call(&s[1..]) + usize::from(s.starts_with('a')),
+        });
+        assert_eq!(memo.call(&text), 2);
+        assert_eq!(memo.stats(), Stats { hits: 0, misses: 7 });
+    }
+}
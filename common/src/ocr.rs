@@ -0,0 +1,138 @@
+//! Reads letters drawn on a screen of pixels, as some puzzles answer with.
+//! The letters use the font of those puzzles, 4 pixels wide and 6 tall,
+//! with a column of dark pixels between them. Lit pixels are `#` and dark
+//! ones `.`, like the puzzles draw them.
+
+use std::fmt::Display;
+
+/// Width of a letter along with the gap after it.
+const STRIDE: usize = 5;
+const WIDTH: usize = 4;
+const HEIGHT: usize = 6;
+
+/// Every letter the font is known to have, with its pixels row by row.
+const FONT: [(char, &str); 17] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// A letter that is not in the font.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// Position of the letter on the screen, starting at 0.
+    pub index: usize,
+    /// Its pixels, a row per line, along with the gap after it.
+    pub pixels: String,
+}
+
+impl Display for UnknownGlyph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown letter at {}:\n{}", self.index + 1, self.pixels)
+    }
+}
+
+impl std::error::Error for UnknownGlyph {}
+
+/// The letters drawn on the screen, which is a line of text per row of
+/// pixels. Blank lines around the screen are ignored, and so are columns
+/// without a single lit pixel, such as those after the last letter.
+pub fn read(screen: &str) -> Result<String, UnknownGlyph> {
+    let rows: Vec<Vec<bool>> = screen
+        .trim_matches('\n')
+        .lines()
+        .map(|row| row.chars().map(|c| c == '#').collect())
+        .collect();
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let lit = |row: usize, col: usize| rows.get(row).and_then(|r| r.get(col)) == Some(&true);
+    let mut letters = String::new();
+    for (index, left) in (0..width).step_by(STRIDE).enumerate() {
+        let pixels: String = (0..HEIGHT)
+            .flat_map(|row| (left..left + WIDTH).map(move |col| (row, col)))
+            .map(|(row, col)| if lit(row, col) { '#' } else { '.' })
+            .collect();
+        if !pixels.contains('#') {
+            continue;
+        }
+        let letter = FONT
+            .iter()
+            .find(|(_, glyph)| *glyph == pixels)
+            .map(|(letter, _)| *letter);
+        // Anything lit in the gap, or below the letters, is not the font.
+        let stray = (0..rows.len()).any(|row| lit(row, left + WIDTH))
+            || (HEIGHT..rows.len()).any(|row| (left..left + WIDTH).any(|col| lit(row, col)));
+        match letter {
+            Some(letter) if !stray => letters.push(letter),
+            _ => {
+                let pixels = (0..rows.len().max(HEIGHT))
+                    .map(|row| {
+                        (left..(left + STRIDE).min(width))
+                            .map(|col| if lit(row, col) { '#' } else { '.' })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                return Err(UnknownGlyph { index, pixels });
+            }
+        }
+    }
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_read() {
+        let screen = "
+###..###....##.#....####.#..#.#....###..
+#..#.#..#....#.#....#....#..#.#....#..#.
+###..#..#....#.#....###..#..#.#....#..#.
+#..#.###.....#.#....#....#..#.#....###..
+#..#.#.#..#..#.#....#....#..#.#....#....
+###..#..#..##..####.#.....##..####.#....
+";
+        assert_eq!(read(screen), Ok("BRJLFULP".to_string()));
+        let font: String = FONT.iter().map(|(letter, _)| *letter).collect();
+        let screen: String = (0..HEIGHT)
+            .map(|row| {
+                FONT.iter()
+                    .map(|(_, glyph)| format!("{}.", &glyph[row * WIDTH..(row + 1) * WIDTH]))
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect();
+        assert_eq!(read(&screen), Ok(font));
+        assert_eq!(read(""), Ok(String::new()));
+    }
+
+    #[test]
+    fn t_unknown() {
+        let screen = ".##..#...\n#..#.#...\n#..#.#...\n####.#...\n#..#.#...\n#..#.###.";
+        assert_eq!(
+            read(screen),
+            Err(UnknownGlyph {
+                index: 1,
+                pixels: "#...\n#...\n#...\n#...\n#...\n###.".to_string(),
+            })
+        );
+        // The gap between letters must be dark.
+        let screen = ".##.#\n#..#.\n#..#.\n####.\n#..#.\n#..#.";
+        assert_eq!(read(screen).unwrap_err().pixels, screen);
+    }
+}
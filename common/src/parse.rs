@@ -0,0 +1,185 @@
+//! Errors for malformed puzzle inputs, along with helpers for the steps
+//! most parsers share.
+//!
+//! Parsers work on slices of the input text, and report an error at the
+//! slice where they got stuck. The error remembers where that slice lives
+//! in memory, and `ParseError::locate` later turns that into a line and a
+//! column of the whole input. This way helpers that only see one line or
+//! one token can still report a useful position.
+
+use std::{fmt::Display, str::FromStr, sync::Arc};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the error, starting at 1. This is 0 if the error has not been
+    /// located in the input.
+    pub line: usize,
+    /// Column of the error in characters, starting at 1.
+    pub column: usize,
+    /// Description of what the parser expected to find.
+    pub expected: Expected,
+    addr: usize,
+}
+
+impl ParseError {
+    /// Error at the start of `text`, which should be a slice of the input.
+    pub fn at(text: &str, expected: impl Into<Expected>) -> ParseError {
+        ParseError {
+            line: 0,
+            column: 0,
+            expected: expected.into(),
+            addr: text.as_ptr() as usize,
+        }
+    }
+
+    /// Error right after the end of `text`, for when something is missing.
+    pub fn after(text: &str, expected: impl Into<Expected>) -> ParseError {
+        ParseError::at(&text[text.len()..], expected)
+    }
+
+    /// Fills in the line and the column of the error in `input`. The
+    /// position is left as it is if the error was not raised on a slice of
+    /// `input`.
+    pub fn locate(mut self, input: &str) -> ParseError {
+        let start = input.as_ptr() as usize;
+        if self.line != 0 || self.addr < start || self.addr > start + input.len() {
+            return self;
+        }
+        let before = &input[..(self.addr - start)];
+        self.line = 1 + before.matches('\n').count();
+        self.column = 1 + before
+            .rsplit_once('\n')
+            .map_or(before, |(_, last)| last)
+            .chars()
+            .count();
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line == 0 {
+            write!(f, "expected {}", self.expected)
+        } else {
+            write!(
+                f,
+                "line {}, column {}: expected {}",
+                self.line, self.column, self.expected
+            )
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// What a parser expected to find. Parsers often fail many times before
+/// one of them succeeds, so making this must not allocate in the common
+/// cases: fixed descriptions are kept as they are, and literals are only
+/// quoted when the error is shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    /// A fixed description, such as "a number".
+    Text(&'static str),
+    /// A piece of text that should appear literally.
+    Literal(&'static str),
+    /// A description built at run time.
+    Owned(Arc<str>),
+}
+
+impl From<&'static str> for Expected {
+    fn from(text: &'static str) -> Expected {
+        Expected::Text(text)
+    }
+}
+
+impl From<String> for Expected {
+    fn from(text: String) -> Expected {
+        Expected::Owned(text.into())
+    }
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Text(text) => f.write_str(text),
+            Expected::Literal(text) => write!(f, "{text:?}"),
+            Expected::Owned(text) => f.write_str(text),
+        }
+    }
+}
+
+impl PartialEq<&str> for Expected {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Expected::Text(text) => text == other,
+            Expected::Literal(text) => other
+                .strip_prefix('"')
+                .and_then(|rest| rest.strip_suffix('"'))
+                .is_some_and(|quoted| text.escape_debug().eq(quoted.chars())),
+            Expected::Owned(text) => &**text == *other,
+        }
+    }
+}
+
+/// Parses `text` as a number.
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| ParseError::at(text, "a number"))
+}
+
+/// Splits `text` around the first occurrence of `delim`.
+pub fn split_once<'a>(text: &'a str, delim: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delim)
+        .ok_or_else(|| ParseError::after(text, format!("{delim:?}")))
+}
+
+/// The rest of `text` after `prefix`.
+pub fn strip_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(text, format!("{prefix:?}")))
+}
+
+/// The next item of `iter`. `prev` is the text just before the item, used
+/// to point at where the item is missing.
+pub fn next<'a>(
+    iter: &mut impl Iterator<Item = &'a str>,
+    prev: &str,
+    expected: &'static str,
+) -> Result<&'a str, ParseError> {
+    iter.next().ok_or_else(|| ParseError::after(prev, expected))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_locate() {
+        let input = "first line\nsecond ⋆ line\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(&line[11..], "a word").locate(input);
+        assert_eq!((err.line, err.column), (2, 10));
+        assert_eq!(err.to_string(), "line 2, column 10: expected a word");
+        let err = ParseError::after(input, "more").locate(input);
+        assert_eq!((err.line, err.column), (3, 1));
+        // Errors outside the input are left as they are.
+        let err = ParseError::at("elsewhere", "a word").locate(input);
+        assert_eq!(err.to_string(), "expected a word");
+    }
+
+    #[test]
+    fn t_helpers() {
+        let input = "a = 12\nb = x";
+        let mut lines = input.lines();
+        let (_, rhs) = split_once(lines.next().unwrap(), " = ").unwrap();
+        assert_eq!(number::<u32>(rhs), Ok(12));
+        let (_, rhs) = split_once(lines.next().unwrap(), " = ").unwrap();
+        let err = number::<u32>(rhs).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (2, 5));
+        let err = split_once(input, " : ").unwrap_err().locate(input);
+        assert_eq!(err.to_string(), "line 2, column 6: expected \" : \"");
+        assert_eq!(strip_prefix("b = x", "b"), Ok(" = x"));
+        let err = next(&mut lines, input, "a line").unwrap_err().locate(input);
+        assert_eq!(err.line, 2);
+        assert_eq!(err.expected, "a line");
+    }
+}
@@ -0,0 +1,110 @@
+//! Seeded random numbers, for making up puzzle inputs that can be made
+//! again from the same seed.
+//!
+//! The generator is SplitMix64, which is small and fast, and random enough
+//! for test inputs. The same seed gives the same numbers on every platform,
+//! so an input that breaks a solution can be reproduced from its seed.
+
+use std::ops::{Bound, RangeBounds};
+
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must be bounded and not empty, such as
+    /// `1..10` or `-5..=5`.
+    pub fn range<T, R>(&mut self, range: R) -> T
+    where
+        T: Copy + TryFrom<i128> + TryInto<i128>,
+        R: RangeBounds<T>,
+    {
+        let wide = |value: T| -> i128 {
+            value
+                .try_into()
+                .unwrap_or_else(|_| panic!("Range out of bounds"))
+        };
+        let start = match range.start_bound() {
+            Bound::Included(&s) => wide(s),
+            Bound::Excluded(&s) => wide(s) + 1,
+            Bound::Unbounded => panic!("The range must have a start"),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => wide(e) + 1,
+            Bound::Excluded(&e) => wide(e),
+            Bound::Unbounded => panic!("The range must have an end"),
+        };
+        assert!(start < end, "The range must not be empty");
+        let span = (end - start) as u128;
+        // Scaling instead of taking the remainder keeps small ranges even.
+        let offset = (self.next_u64() as u128 * span) >> 64;
+        T::try_from(start + offset as i128).unwrap_or_else(|_| panic!("Range out of bounds"))
+    }
+
+    /// True with the given probability, from 0 to 1.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    /// A string of `len` characters, each picked from `chars`.
+    pub fn string(&mut self, chars: &str, len: usize) -> String {
+        let chars: Vec<char> = chars.chars().collect();
+        (0..len).map(|_| *self.pick(&chars)).collect()
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_random() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let first: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        assert_eq!(first, (0..5).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(Rng::new(43).next_u64(), first[0]);
+        let mut rng = Rng::new(7);
+        let mut seen = [0; 11];
+        for _ in 0..1000 {
+            let n: i32 = rng.range(-5..=5);
+            seen[(n + 5) as usize] += 1;
+            assert!((10..20).contains(&rng.range(10usize..20)));
+        }
+        assert!(seen.iter().all(|&count| count > 50));
+        assert_eq!(rng.range(3u8..4), 3);
+        assert_eq!(rng.range(i64::MIN..=i64::MIN), i64::MIN);
+        assert!((0..1000).filter(|_| rng.chance(0.25)).count().abs_diff(250) < 60);
+        assert!(!rng.chance(0.0) && rng.chance(1.0));
+        assert!(rng.string("ab", 20).chars().all(|c| c == 'a' || c == 'b'));
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,345 @@
+//! Shortest path searches over any state type. The graph is never built up
+//! front. Instead, each search takes a closure that lists the neighbours of
+//! a state, so states can carry whatever the puzzle needs, such as the
+//! direction of travel along with the position.
+//!
+//! Every search accepts several start states and stops at the first state
+//! that satisfies the target predicate, which covers multiple targets.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// A shortest path, along with its total cost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: usize,
+    /// Every state along the path, from the start to the target inclusive.
+    pub states: Vec<S>,
+}
+
+/// States seen by a search, each with the best known cost of reaching it and
+/// the key of the state it was reached from. Every state is kept once, under
+/// a key of its own.
+trait Visited<S: Clone> {
+    /// Records reaching `state` at `cost` from the state with key `from`.
+    /// Returns the key of the state if this is the cheapest way seen so far
+    /// of reaching it.
+    fn improve(&mut self, state: S, cost: usize, from: Option<usize>) -> Option<usize>;
+
+    fn cost(&self, key: usize) -> usize;
+
+    /// The state with `key`, and the key of the state it was reached from.
+    fn get(&self, key: usize) -> (&S, Option<usize>);
+
+    /// Walks back from the state with `key` to the start it was reached
+    /// from.
+    fn path(&self, key: usize) -> Path<S> {
+        let mut states = Vec::new();
+        let mut next = Some(key);
+        while let Some(key) = next {
+            let (state, from) = self.get(key);
+            states.push(state.clone());
+            next = from;
+        }
+        states.reverse();
+        Path {
+            cost: self.cost(key),
+            states,
+        }
+    }
+}
+
+/// States of any kind, keyed in the order they were first seen and looked
+/// up by their hash.
+struct Hashed<S> {
+    entries: Vec<(S, usize, Option<usize>)>,
+    keys: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> for Hashed<S> {
+    fn improve(&mut self, state: S, cost: usize, from: Option<usize>) -> Option<usize> {
+        match self.keys.entry(state) {
+            Entry::Occupied(entry) => {
+                let key = *entry.get();
+                let best = &mut self.entries[key];
+                if best.1 <= cost {
+                    return None;
+                }
+                best.1 = cost;
+                best.2 = from;
+                Some(key)
+            }
+            Entry::Vacant(entry) => {
+                let key = self.entries.len();
+                self.entries.push((entry.key().clone(), cost, from));
+                entry.insert(key);
+                Some(key)
+            }
+        }
+    }
+
+    fn cost(&self, key: usize) -> usize {
+        self.entries[key].1
+    }
+
+    fn get(&self, key: usize) -> (&S, Option<usize>) {
+        (&self.entries[key].0, self.entries[key].2)
+    }
+}
+
+/// States numbered by `number`, which stands in for hashing them. Each
+/// number has a slot with the best cost of its state and the key of the
+/// state, if it was seen. Most lookups only compare costs, so the slots are
+/// kept small and the states apart.
+struct Dense<S, F> {
+    slots: Vec<(usize, usize)>,
+    entries: Vec<(S, Option<usize>)>,
+    number: F,
+}
+
+impl<S: Clone, F: Fn(&S) -> usize> Visited<S> for Dense<S, F> {
+    fn improve(&mut self, state: S, cost: usize, from: Option<usize>) -> Option<usize> {
+        let slot = &mut self.slots[(self.number)(&state)];
+        if slot.0 <= cost {
+            return None;
+        }
+        if slot.0 == usize::MAX {
+            slot.1 = self.entries.len();
+            self.entries.push((state, from));
+        } else {
+            self.entries[slot.1].1 = from;
+        }
+        slot.0 = cost;
+        Some(slot.1)
+    }
+
+    fn cost(&self, key: usize) -> usize {
+        self.slots[(self.number)(&self.entries[key].0)].0
+    }
+
+    fn get(&self, key: usize) -> (&S, Option<usize>) {
+        let (state, from) = &self.entries[key];
+        (state, *from)
+    }
+}
+
+/// Dijkstra's algorithm. `neighbours` lists the states reachable from a
+/// state, each with the cost of that step.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_target: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, neighbours, |_| 0, is_target)
+}
+
+/// A* search. `heuristic` estimates the remaining cost from a state to the
+/// nearest target, and must never overestimate it for the path to be the
+/// shortest.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> usize,
+    is_target: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let visited = Hashed {
+        entries: Vec::new(),
+        keys: HashMap::new(),
+    };
+    search(visited, starts, neighbours, heuristic, is_target)
+}
+
+/// Same as `astar`, for states that `number` maps to distinct numbers below
+/// `count`. Looking states up by their number is much faster than hashing
+/// them, when there are millions of states to go through.
+pub fn astar_dense<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    count: usize,
+    number: impl Fn(&S) -> usize,
+    neighbours: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> usize,
+    is_target: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let visited = Dense {
+        slots: vec![(usize::MAX, 0); count],
+        entries: Vec::new(),
+        number,
+    };
+    search(visited, starts, neighbours, heuristic, is_target)
+}
+
+fn search<S, I>(
+    mut visited: impl Visited<S>,
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut open = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(i) = visited.improve(start, 0, None) {
+            open.push(Reverse((estimate, 0, i)));
+        }
+    }
+    while let Some(Reverse((_, cost, i))) = open.pop() {
+        if cost > visited.cost(i) {
+            // Stale entry, this state was since reached more cheaply.
+            continue;
+        }
+        let state = visited.get(i).0.clone();
+        if is_target(&state) {
+            return Some(visited.path(i));
+        }
+        for (next, step) in neighbours(&state) {
+            let ncost = cost + step;
+            let estimate = ncost + heuristic(&next);
+            if let Some(n) = visited.improve(next, ncost, Some(i)) {
+                open.push(Reverse((estimate, ncost, n)));
+            }
+        }
+    }
+    None
+}
+
+/// Breadth first search, for graphs where every step costs 1.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Hashed {
+        entries: Vec::new(),
+        keys: HashMap::new(),
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Some(i) = visited.improve(start, 0, None) {
+            queue.push_back(i);
+        }
+    }
+    while let Some(i) = queue.pop_front() {
+        let (state, cost) = (visited.get(i).0.clone(), visited.cost(i));
+        if is_target(&state) {
+            return Some(visited.path(i));
+        }
+        for next in neighbours(&state) {
+            if let Some(n) = visited.improve(next, cost + 1, Some(i)) {
+                queue.push_back(n);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Weighted edges of a small directed graph.
+    const EDGES: [(u8, u8, usize); 7] = [
+        (b'a', b'b', 7),
+        (b'a', b'c', 2),
+        (b'c', b'b', 3),
+        (b'b', b'd', 1),
+        (b'c', b'd', 8),
+        (b'd', b'e', 1),
+        (b'x', b'e', 1),
+    ];
+
+    fn edges(from: &u8) -> impl Iterator<Item = (u8, usize)> {
+        let from = *from;
+        EDGES
+            .iter()
+            .filter(move |(a, ..)| *a == from)
+            .map(|(_, b, cost)| (*b, *cost))
+    }
+
+    #[test]
+    fn t_dijkstra() {
+        let path = dijkstra([b'a'], edges, |s| *s == b'e').unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.states, b"acbde");
+        // Multiple starts and targets.
+        let path = dijkstra([b'a', b'x'], edges, |s| matches!(s, b'd' | b'e')).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.states, b"xe");
+        assert_eq!(dijkstra([b'e'], edges, |s| *s == b'a'), None);
+        let path = dijkstra([b'a'], edges, |s| *s == b'a').unwrap();
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.states, b"a");
+    }
+
+    #[test]
+    fn t_astar() {
+        // Walk on an open 10 x 10 grid with the manhattan distance heuristic.
+        let path = astar(
+            [(0i32, 0i32)],
+            |&(x, y)| {
+                [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                    .into_iter()
+                    .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y))
+                    .map(|p| (p, 1))
+            },
+            |(x, y)| (9 - x + 9 - y) as usize,
+            |p| *p == (9, 9),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.states.len(), 19);
+        assert!(path
+            .states
+            .windows(2)
+            .all(|w| (w[0].0 - w[1].0).abs() + (w[0].1 - w[1].1).abs() == 1));
+        // The same walk with the states numbered, around a wall at x = 5.
+        let path = astar_dense(
+            [(0i32, 0i32)],
+            100,
+            |&(x, y)| (y * 10 + x) as usize,
+            |&(x, y)| {
+                [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                    .into_iter()
+                    .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                    .filter(|&(x, y)| x != 5 || y == 9)
+                    .map(|p| (p, 1))
+            },
+            |(x, y)| (9 - x + 9 - y) as usize,
+            |p| *p == (9, 0),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 27);
+        assert_eq!(path.states.last(), Some(&(9, 0)));
+    }
+
+    #[test]
+    fn t_bfs() {
+        let path = bfs([b'a'], |s| edges(s).map(|(n, _)| n), |s| *s == b'e').unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states.len(), 4);
+        assert_eq!(path.states.first(), Some(&b'a'));
+        assert_eq!(path.states.last(), Some(&b'e'));
+    }
+}
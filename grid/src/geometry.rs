@@ -0,0 +1,251 @@
+//! Directions, points and offsets on a plane, for puzzles that walk around
+//! a map.
+//!
+//! Coordinates follow the rows and columns of a grid: `x` grows to the east
+//! and `y` grows to the south, so north is up when the map is printed.
+
+use crate::NEIGHBOURS_4;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+use Direction::*;
+
+impl Direction {
+    /// Every direction, clockwise from north, in the order of
+    /// `NEIGHBOURS_4`.
+    pub const ALL: [Direction; 4] = [North, East, South, West];
+
+    /// Direction written as a compass point, `N`, `E`, `S` or `W`, or as one
+    /// of `U`, `R`, `D` and `L`.
+    pub fn from_letter(c: char) -> Option<Direction> {
+        match c {
+            'N' | 'U' => Some(North),
+            'E' | 'R' => Some(East),
+            'S' | 'D' => Some(South),
+            'W' | 'L' => Some(West),
+            _ => None,
+        }
+    }
+
+    /// Direction drawn as one of the arrows `^`, `>`, `v` and `<`.
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(North),
+            '>' => Some(East),
+            'v' => Some(South),
+            '<' => Some(West),
+            _ => None,
+        }
+    }
+
+    /// Position of the direction in `ALL`, for tables indexed by direction.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self.index() + 2) % 4]
+    }
+
+    /// Offset of one step in this direction as (row, column), as taken by
+    /// `Grid::step`.
+    pub fn offset(self) -> (isize, isize) {
+        NEIGHBOURS_4[self.index()]
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Offset between two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+impl<T> Vector<T> {
+    pub const fn new(x: T, y: T) -> Vector<T> {
+        Vector { x, y }
+    }
+}
+
+impl<T: Copy + Ord + From<i8> + Add<Output = T> + Neg<Output = T>> Vector<T> {
+    /// Number of steps along the axes to cover the offset.
+    pub fn manhattan(self) -> T {
+        self.x.max(-self.x) + self.y.max(-self.y)
+    }
+
+    /// Number of steps to cover the offset when diagonal steps are allowed.
+    pub fn chebyshev(self) -> T {
+        self.x.max(-self.x).max(self.y.max(-self.y))
+    }
+
+    /// The offset with both coordinates clamped to -1, 0 or 1, which is a
+    /// single step, possibly diagonal, towards it.
+    pub fn signum(self) -> Vector<T> {
+        let one = T::from(1);
+        Vector::new(self.x.clamp(-one, one), self.y.clamp(-one, one))
+    }
+}
+
+impl<T: From<i8>> From<Direction> for Vector<T> {
+    fn from(dir: Direction) -> Vector<T> {
+        let (y, x) = match dir {
+            North => (-1, 0),
+            East => (0, 1),
+            South => (1, 0),
+            West => (0, -1),
+        };
+        Vector::new(T::from(x), T::from(y))
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, v: Vector<T>) -> Point<T> {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, v: Vector<T>) -> Point<T> {
+        Point::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: Point<T>) -> Vector<T> {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vector<T>> for Point<T> {
+    fn add_assign(&mut self, v: Vector<T>) {
+        self.x += v.x;
+        self.y += v.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vector<T>> for Point<T> {
+    fn sub_assign(&mut self, v: Vector<T>) {
+        self.x -= v.x;
+        self.y -= v.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, other: Vector<T>) -> Vector<T> {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, other: Vector<T>) -> Vector<T> {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, scale: T) -> Vector<T> {
+        Vector::new(self.x * scale, self.y * scale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_direction() {
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(West.turn_right(), North);
+        assert_eq!(East.reverse(), West);
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            let v = Vector::<isize>::from(dir);
+            assert_eq!(dir.offset(), (v.y, v.x));
+        }
+        assert_eq!(
+            "URDL"
+                .chars()
+                .map(Direction::from_letter)
+                .collect::<Vec<_>>(),
+            "NESW"
+                .chars()
+                .map(Direction::from_letter)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "^>v<x"
+                .chars()
+                .map(Direction::from_arrow)
+                .collect::<Vec<_>>(),
+            [Some(North), Some(East), Some(South), Some(West), None]
+        );
+        assert_eq!(Direction::from_letter('X'), None);
+    }
+
+    #[test]
+    fn t_arithmetic() {
+        let p = Point::new(3, -2);
+        let v = Vector::<i32>::from(North) * 4 + Vector::from(East);
+        assert_eq!(v, Vector::new(1, -4));
+        assert_eq!(p + v, Point::new(4, -6));
+        assert_eq!(p + v - v, p);
+        assert_eq!((p + v) - p, v);
+        assert_eq!(-v, Vector::new(-1, 4));
+        assert_eq!(v.manhattan(), 5);
+        assert_eq!(v.chebyshev(), 4);
+        assert_eq!(v.signum(), Vector::new(1, -1));
+        let mut q = p;
+        q += v;
+        q -= Vector::new(1, 1);
+        assert_eq!(q, Point::new(3, -7));
+    }
+}
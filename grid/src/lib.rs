@@ -0,0 +1,437 @@
+mod geometry;
+mod random;
+
+pub use geometry::{Direction, Point, Vector};
+pub use random::random_loop;
+
+use common::{combinator::PResult, ParseError};
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+/// Offsets of the 4 orthogonal neighbours as (row, column), in the order
+/// north, east, south, west.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of all 8 neighbours as (row, column), clockwise starting from
+/// north.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// Rectangular grid of cells stored in row major order. Cells can be
+/// addressed either by their position in that order, or by (row, column).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid with `rows` x `cols` cells, all set to `val`.
+    pub fn new(rows: usize, cols: usize, val: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![val; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Grid from cells in row major order. Panics if the number of cells is
+    /// not a multiple of `cols`.
+    pub fn from_vec(cells: Vec<T>, cols: usize) -> Grid<T> {
+        assert!(cols > 0, "A grid must have at least one column");
+        assert_eq!(cells.len() % cols, 0, "Expecting a rectangular grid");
+        Grid {
+            rows: cells.len() / cols,
+            cells,
+            cols,
+        }
+    }
+
+    /// Parses a grid with one line of text per row, converting every
+    /// character with `f`. Surrounding whitespace is ignored. Fails on
+    /// characters that `f` rejects, in which case `expected` describes the
+    /// valid ones, and on lines that are not all of the same length.
+    pub fn parse(
+        input: &str,
+        expected: &'static str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let input = input.trim();
+        let mut cols = None;
+        let mut rows = 0usize;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            let line = line.trim_end();
+            let mut count = 0usize;
+            for (i, c) in line.char_indices() {
+                if cols == Some(count) {
+                    return Err(ParseError::at(&line[i..], "the end of the row"));
+                }
+                cells.push(f(c).ok_or_else(|| ParseError::at(&line[i..], expected))?);
+                count += 1;
+            }
+            match cols {
+                Some(cols) if cols != count => {
+                    return Err(ParseError::after(line, format!("{cols} cells in the row")));
+                }
+                _ => cols = Some(count),
+            }
+            rows += 1;
+        }
+        Ok(Grid {
+            cells,
+            rows,
+            cols: cols.unwrap_or(0),
+        })
+    }
+
+    /// Parser for a grid in the middle of an input, made of the lines up to
+    /// a blank line or the end of the text, for use with the parsers of
+    /// `common::combinator`. The characters are converted as by `parse`.
+    pub fn parser<'a>(
+        expected: &'static str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> impl Fn(&'a str) -> PResult<'a, Grid<T>> {
+        move |input: &'a str| {
+            let end = input.find("\n\n").unwrap_or(input.len());
+            if input[..end].trim().is_empty() {
+                return Err(ParseError::at(input, expected));
+            }
+            let grid = Grid::parse(&input[..end], expected, &f)?;
+            Ok((grid, &input[end..]))
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Total number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// All cells in row major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Position of the cell at (row, column).
+    pub fn pos(&self, row: usize, col: usize) -> usize {
+        row * self.cols + col
+    }
+
+    /// (row, column) of the cell at `pos`.
+    pub fn coords(&self, pos: usize) -> (usize, usize) {
+        (pos / self.cols, pos % self.cols)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.cells.get(self.pos(row, col))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            let pos = self.pos(row, col);
+            self.cells.get_mut(pos)
+        } else {
+            None
+        }
+    }
+
+    /// Position of the first cell that satisfies `pred`.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.cells.iter().position(pred)
+    }
+
+    /// Position reached by moving `(drow, dcol)` from `pos`, or `None` if
+    /// that is outside the grid.
+    pub fn step(&self, pos: usize, (drow, dcol): (isize, isize)) -> Option<usize> {
+        let (row, col) = self.coords(pos);
+        let row = row.checked_add_signed(drow)?;
+        let col = col.checked_add_signed(dcol)?;
+        if row < self.rows && col < self.cols {
+            Some(self.pos(row, col))
+        } else {
+            None
+        }
+    }
+
+    /// Orthogonal neighbours of `pos` that are inside the grid, in the order
+    /// of `NEIGHBOURS_4`.
+    pub fn neighbours_4(&self, pos: usize) -> impl Iterator<Item = usize> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&offset| self.step(pos, offset))
+    }
+
+    /// All neighbours of `pos`, including the diagonal ones, that are inside
+    /// the grid, in the order of `NEIGHBOURS_8`.
+    pub fn neighbours_8(&self, pos: usize) -> impl Iterator<Item = usize> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&offset| self.step(pos, offset))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[(row * self.cols)..((row + 1) * self.cols)]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(col < self.cols, "Out of bounds");
+        self.cells[col..].iter().step_by(self.cols)
+    }
+
+    /// Every row from top to bottom, none if the grid has no columns.
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    /// Every column from left to right.
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.cols).map(move |col| self.column(col))
+    }
+
+    /// Grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Grid flipped about its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.iter_cols().flatten().cloned().collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Grid rotated 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self
+                .iter_cols()
+                .flat_map(|col| col.rev())
+                .cloned()
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Grid rotated 90 degrees counter-clockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: (0..self.cols)
+                .rev()
+                .flat_map(|col| self.column(col))
+                .cloned()
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: usize) -> &T {
+        &self.cells[pos]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, pos: usize) -> &mut T {
+        &mut self.cells[pos]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.rows && col < self.cols, "Out of bounds");
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < self.rows && col < self.cols, "Out of bounds");
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+/// Writes one line per row, with no separators between the cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_parse() {
+        let grid = Grid::parse(EXAMPLE, "a letter", Some).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (3, 4));
+        assert_eq!(grid[(1, 2)], 'g');
+        assert_eq!(grid[grid.pos(2, 3)], 'l');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.coords(6), (1, 2));
+        assert_eq!(grid.to_string(), EXAMPLE.trim());
+    }
+
+    #[test]
+    fn t_parse_errors() {
+        let input = "abc\nde\n";
+        let err = Grid::parse(input, "a letter", Some)
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected 3 cells in the row"
+        );
+        let input = "abc\ndefg\n";
+        let err = Grid::parse(input, "a letter", Some)
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected the end of the row"
+        );
+        let input = "abc\nd.f\n";
+        let err = Grid::parse(input, "a letter", |c| c.is_alphabetic().then_some(c))
+            .unwrap_err()
+            .locate(input);
+        assert_eq!(err.to_string(), "line 2, column 2: expected a letter");
+    }
+
+    #[test]
+    fn t_neighbours() {
+        let grid = Grid::parse(EXAMPLE, "a letter", Some).unwrap();
+        assert_eq!(grid.neighbours_4(0).collect::<Vec<_>>(), [1, 4]);
+        assert_eq!(grid.neighbours_4(5).collect::<Vec<_>>(), [1, 6, 9, 4]);
+        assert_eq!(grid.neighbours_8(11).collect::<Vec<_>>(), [7, 10, 6]);
+        assert_eq!(grid.step(3, (0, 1)), None);
+        assert_eq!(grid.step(3, (1, -1)), Some(6));
+    }
+
+    #[test]
+    fn t_rows_cols() {
+        let grid = Grid::parse(EXAMPLE, "a letter", Some).unwrap();
+        assert_eq!(grid.row(1), ['e', 'f', 'g', 'h']);
+        assert_eq!(grid.column(2).collect::<String>(), "cgk");
+        assert_eq!(
+            grid.iter_cols()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["aei", "bfj", "cgk", "dhl"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn t_column_out_of_bounds() {
+        let grid = Grid::parse(EXAMPLE, "a letter", Some).unwrap();
+        let _ = grid.column(grid.cols());
+    }
+
+    #[test]
+    fn t_empty() {
+        let grid = Grid::parse("", "a letter", Some).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (0, 0));
+        assert_eq!(grid.iter_rows().count(), 0);
+        assert_eq!(grid.iter_cols().count(), 0);
+        assert_eq!(grid.to_string(), "");
+        assert_eq!(grid.transpose(), grid);
+    }
+
+    #[test]
+    fn t_transform() {
+        let grid = Grid::parse(EXAMPLE, "a letter", Some).unwrap();
+        assert_eq!(grid.transpose().to_string(), "aei\nbfj\ncgk\ndhl");
+        assert_eq!(grid.rotate_cw().to_string(), "iea\njfb\nkgc\nlhd");
+        assert_eq!(grid.rotate_ccw().to_string(), "dhl\ncgk\nbfj\naei");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn t_parser() {
+        use common::combinator::{parse_all, sections};
+        let input = "ab\ncd\n\nxyz\n";
+        let grids = parse_all(sections(Grid::parser("a letter", Some)), input).unwrap();
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[0].to_string(), "ab\ncd");
+        assert_eq!(grids[1].to_string(), "xyz");
+        let input = "ab\ncd\n\nx.z\n";
+        let err = parse_all(
+            sections(Grid::parser("a letter", |c| c.is_alphabetic().then_some(c))),
+            input,
+        )
+        .unwrap_err()
+        .locate(input);
+        assert_eq!(err.to_string(), "line 4, column 2: expected a letter");
+    }
+
+    const EXAMPLE: &str = "
+abcd
+efgh
+ijkl
+";
+}
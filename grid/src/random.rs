@@ -0,0 +1,106 @@
+//! Random shapes on a grid, for generating puzzle inputs.
+
+use crate::{Direction, Grid, NEIGHBOURS_4, NEIGHBOURS_8};
+use common::random::Rng;
+
+/// Whether the cell at `pos` can be added to the region marked in `inside`
+/// without making a hole in it, or making two of its cells touch only at
+/// the corners.
+fn can_grow(inside: &Grid<bool>, pos: usize) -> bool {
+    let ring = NEIGHBOURS_8.map(|offset| inside.step(pos, offset).is_some_and(|n| inside[n]));
+    // The neighbours in the region must be in one run around the cell.
+    let runs = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+    // The odd neighbours are the diagonal ones.
+    let corners = (1..8)
+        .step_by(2)
+        .all(|i| !ring[i] || ring[i - 1] || ring[(i + 1) % 8]);
+    runs == 1 && corners
+}
+
+/// A random loop that never touches itself, going clockwise around a region
+/// of cells of a `rows` x `cols` grid. The loop runs along the edges between
+/// the cells, from corner to corner, so it is returned as the corner it
+/// starts at, as (row, column) among the `rows + 1` x `cols + 1` corners,
+/// and the direction of every step from there.
+pub fn random_loop(rng: &mut Rng, rows: usize, cols: usize) -> ((usize, usize), Vec<Direction>) {
+    assert!(rows > 0 && cols > 0, "The grid must not be empty");
+    let mut inside = Grid::new(rows, cols, false);
+    let first = rng.range(0..inside.len());
+    inside[first] = true;
+    let mut region = vec![first];
+    let target = rng.range(inside.len() / 4..=inside.len() / 2).max(1);
+    for _ in 0..target * 20 {
+        if region.len() >= target {
+            break;
+        }
+        let Some(pos) = inside.step(*rng.pick(&region), *rng.pick(&NEIGHBOURS_4)) else {
+            continue;
+        };
+        if !inside[pos] && can_grow(&inside, pos) {
+            inside[pos] = true;
+            region.push(pos);
+        }
+    }
+    let at = |row: usize, col: usize, drow: isize, dcol: isize| {
+        let (row, col) = (row as isize + drow, col as isize + dcol);
+        (0..rows as isize).contains(&row)
+            && (0..cols as isize).contains(&col)
+            && inside[(row as usize, col as usize)]
+    };
+    // The first cell of the region has nothing above it or to its left, so
+    // its top left corner is on the loop.
+    let start = inside.coords(inside.position(|&c| c).unwrap());
+    let (mut row, mut col) = start;
+    let mut steps = Vec::new();
+    loop {
+        // Keep the region on the right of every step.
+        let dir = if at(row, col, 0, 0) && !at(row, col, -1, 0) {
+            Direction::East
+        } else if at(row, col, 0, -1) && !at(row, col, 0, 0) {
+            Direction::South
+        } else if at(row, col, -1, -1) && !at(row, col, 0, -1) {
+            Direction::West
+        } else {
+            Direction::North
+        };
+        steps.push(dir);
+        let (drow, dcol) = dir.offset();
+        (row, col) = (row.wrapping_add_signed(drow), col.wrapping_add_signed(dcol));
+        if (row, col) == start {
+            return (start, steps);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t_random_loop() {
+        for seed in 0..50 {
+            let mut rng = Rng::new(seed);
+            let (rows, cols) = (rng.range(1..12), rng.range(1..12));
+            let (start, steps) = random_loop(&mut rng, rows, cols);
+            let mut seen = Grid::new(rows + 1, cols + 1, false);
+            let mut pos = seen.pos(start.0, start.1);
+            for dir in &steps {
+                assert!(!seen[pos], "The loop touches itself");
+                seen[pos] = true;
+                pos = seen.step(pos, dir.offset()).unwrap();
+            }
+            assert_eq!(seen.coords(pos), start);
+            // Going clockwise, there are 4 more right turns than left ones.
+            let turns: isize = steps
+                .iter()
+                .zip(steps.iter().cycle().skip(1))
+                .map(|(&a, &b)| match b {
+                    _ if b == a.turn_right() => 1,
+                    _ if b == a.turn_left() => -1,
+                    _ => 0,
+                })
+                .sum();
+            assert_eq!(turns, 4);
+        }
+    }
+}